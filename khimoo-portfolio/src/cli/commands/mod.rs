@@ -1,8 +1,10 @@
 //! CLI commands module
 //!
 //! This module contains individual command implementations for
-//! article processing, link validation, and other CLI operations.
+//! article processing, link validation, prerendering, and other CLI operations.
 
+#[cfg(feature = "cli-tools")]
+pub mod prerender;
 #[cfg(feature = "cli-tools")]
 pub mod process_articles;
 #[cfg(feature = "cli-tools")]
@@ -10,6 +12,8 @@ pub mod validate_links;
 
 // Re-export command implementations
 #[cfg(feature = "cli-tools")]
+pub use prerender::{PrerenderArgs, PrerenderCommand};
+#[cfg(feature = "cli-tools")]
 pub use process_articles::{
     ArticlesData, ProcessArticlesArgs, ProcessArticlesCommand, ProcessedArticle,
 };
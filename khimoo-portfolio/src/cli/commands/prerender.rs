@@ -0,0 +1,338 @@
+use anyhow::{Context, Result};
+use chrono::DateTime;
+use clap::Parser;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use crate::cli::commands::process_articles::{ArticlesData, ProcessedArticle};
+use crate::config::AppConfig;
+use crate::config_loader::{get_default_articles_dir, get_deployment_config};
+use crate::core::articles::front_matter::strip_front_matter;
+use crate::core::articles::links::link_base;
+use crate::core::articles::tags::{tag_ancestors, tag_matches, validate_tag};
+use crate::core::render::MarkdownRenderer;
+use crate::core::site::prerender::{article_body, listing_body};
+use crate::core::site::redirect::spa_redirect_page;
+use crate::core::site::sitemap::{latest_date, robots_txt, sitemap_xml, SitemapUrl};
+use crate::core::site::{ListingItem, PageHead, PageTemplate, PreloadedArticle};
+
+/// CLI arguments for the prerender command
+#[derive(Parser, Debug, Clone)]
+#[command(name = "prerender")]
+#[command(about = "Write static HTML for every route, 404.html, sitemap.xml and robots.txt")]
+pub struct PrerenderArgs {
+    /// Built site containing the app's index.html (trunk output)
+    #[arg(long, default_value = "dist")]
+    pub dist_dir: PathBuf,
+
+    /// Directory containing articles.json written by process-articles
+    #[arg(long, default_value = "data")]
+    pub data_dir: PathBuf,
+
+    /// Directory containing markdown articles
+    #[arg(short, long)]
+    pub articles_dir: Option<PathBuf>,
+
+    /// Path the site is served under; defaults to `[deployment] github_pages_path`
+    #[arg(long)]
+    pub base_path: Option<String>,
+
+    /// Enable verbose output
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+/// Command implementation for prerendering routes to static HTML
+pub struct PrerenderCommand;
+
+impl PrerenderCommand {
+    pub fn new() -> Result<Self> {
+        Ok(Self)
+    }
+
+    pub fn execute(&self, args: PrerenderArgs) -> Result<()> {
+        let articles_dir = args
+            .articles_dir
+            .clone()
+            .unwrap_or_else(get_default_articles_dir);
+        let base_path = args
+            .base_path
+            .clone()
+            .unwrap_or_else(|| get_deployment_config().0);
+        let config = AppConfig::with_base_path(base_path.trim_end_matches('/'));
+
+        let index_path = args.dist_dir.join("index.html");
+        let template = std::fs::read_to_string(&index_path).with_context(|| {
+            format!(
+                "Failed to read {} (run trunk build first)",
+                index_path.display()
+            )
+        })?;
+        let template = PageTemplate::new(template);
+        // The home page is written back over the template, so a second run would repeat its head
+        if template.is_rendered() {
+            anyhow::bail!(
+                "{} is already prerendered (run trunk build first)",
+                index_path.display()
+            );
+        }
+
+        let data_path = args.data_dir.join("articles.json");
+        let data: ArticlesData = serde_json::from_str(
+            &std::fs::read_to_string(&data_path)
+                .with_context(|| format!("Failed to read {}", data_path.display()))?,
+        )
+        .context("Failed to parse articles.json")?;
+
+        // Start from scratch so removed articles and tags leave no stale pages behind
+        for route_dir in ["article", "tag"] {
+            let dir = args.dist_dir.join(route_dir);
+            if dir.exists() {
+                std::fs::remove_dir_all(&dir)
+                    .with_context(|| format!("Failed to clear {}", dir.display()))?;
+            }
+        }
+
+        for article in &data.articles {
+            if args.verbose {
+                println!("Prerendering: /article/{}", article.slug);
+            }
+            self.prerender_article(&config, &template, &articles_dir, &args.dist_dir, article)?;
+        }
+
+        let listed: Vec<&ProcessedArticle> = data
+            .articles
+            .iter()
+            .filter(|article| article.metadata.is_public())
+            .collect();
+        // The home graph is drawn by the app; only its head (site feeds and link previews) is static
+        std::fs::write(
+            &index_path,
+            template.render_head(&PageHead::site(&config.site)),
+        )
+        .with_context(|| format!("Failed to write {}", index_path.display()))?;
+        self.prerender_index(&config, &template, &args.dist_dir, &listed)?;
+        let tag_count = self.prerender_tags(&config, &template, &args.dist_dir, &data, &listed)?;
+
+        // Every other path (aliases, history pages, the graph) goes through the app via 404.html
+        let not_found_path = args.dist_dir.join("404.html");
+        std::fs::write(
+            &not_found_path,
+            spa_redirect_page(&config.base_path, &config.site.name),
+        )
+        .context("Failed to write 404.html")?;
+
+        let sitemap_count = self.write_sitemap(&config, &args.dist_dir, &listed)?;
+
+        println!(
+            "🗂️  Prerendered {} articles, {} tags and the article index into {}",
+            data.articles.len(),
+            tag_count,
+            args.dist_dir.display()
+        );
+        println!("🗺️  Listed {sitemap_count} pages in sitemap.xml");
+        Ok(())
+    }
+
+    fn prerender_article(
+        &self,
+        config: &AppConfig,
+        template: &PageTemplate,
+        articles_dir: &Path,
+        dist_dir: &Path,
+        article: &ProcessedArticle,
+    ) -> Result<()> {
+        let source_path = articles_dir.join(&article.file_path);
+        let source = std::fs::read_to_string(&source_path)
+            .with_context(|| format!("Failed to read {}", source_path.display()))?;
+        let content = strip_front_matter(&source);
+
+        let rendered = MarkdownRenderer::new(config)
+            .with_references(&article.references)
+            .with_link_targets(link_base(&article.file_path), &article.link_targets)
+            .render(content);
+        let path = format!("/article/{}", article.slug);
+        let head = PageHead::article(
+            &config.site,
+            &path,
+            &article.title,
+            &article.metadata,
+            article.summary.as_deref(),
+            article.og_image.as_deref(),
+        );
+        let body = article_body(config, &article.title, &article.metadata, &rendered);
+        let preload = serde_json::to_string(&PreloadedArticle {
+            article,
+            content: content.to_string(),
+        })?;
+
+        write_page(
+            dist_dir,
+            &path,
+            &template.render(&head, &body, Some(&preload)),
+        )
+    }
+
+    fn prerender_index(
+        &self,
+        config: &AppConfig,
+        template: &PageTemplate,
+        dist_dir: &Path,
+        listed: &[&ProcessedArticle],
+    ) -> Result<()> {
+        // Newest first like the app; undated articles last
+        let mut articles = listed.to_vec();
+        articles.sort_by_key(|article| {
+            std::cmp::Reverse(
+                article
+                    .metadata
+                    .created_at
+                    .as_deref()
+                    .and_then(|value| DateTime::parse_from_rfc3339(value).ok()),
+            )
+        });
+
+        let head = PageHead::article_index(&config.site);
+        let body = listing_body(&head.headline, None, &listing_items(config, &articles));
+        write_page(dist_dir, "/article", &template.render(&head, &body, None))
+    }
+
+    /// Pages for every registered tag and every tag (or parent tag) used by a listed article
+    fn prerender_tags(
+        &self,
+        config: &AppConfig,
+        template: &PageTemplate,
+        dist_dir: &Path,
+        data: &ArticlesData,
+        listed: &[&ProcessedArticle],
+    ) -> Result<usize> {
+        let tags: BTreeSet<&str> = data
+            .tags
+            .iter()
+            .map(|(tag, _)| tag)
+            .chain(
+                listed
+                    .iter()
+                    .flat_map(|article| &article.metadata.tags)
+                    .flat_map(|tag| tag_ancestors(tag)),
+            )
+            .collect();
+
+        let mut written = 0;
+        for tag in &tags {
+            // process-articles rejects these; an older articles.json may still carry them
+            if let Err(e) = validate_tag(tag) {
+                eprintln!("Warning: skipped the page of #{tag}: {e}");
+                continue;
+            }
+            let articles: Vec<&ProcessedArticle> = listed
+                .iter()
+                .copied()
+                .filter(|article| article.metadata.tags.iter().any(|t| tag_matches(t, tag)))
+                .collect();
+            let info = data.tags.get(tag);
+            let head = PageHead::tag(
+                &config.site,
+                tag,
+                data.tags.display_name(tag),
+                info.and_then(|info| info.description.as_deref()),
+                articles.len(),
+            );
+            let body = listing_body(
+                &head.headline,
+                head.description.as_deref(),
+                &listing_items(config, &articles),
+            );
+            write_page(
+                dist_dir,
+                &format!("/tag/{tag}"),
+                &template.render(&head, &body, None),
+            )?;
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// `sitemap.xml` (home, index, indexable articles and their tags) and `robots.txt`
+    fn write_sitemap(
+        &self,
+        config: &AppConfig,
+        dist_dir: &Path,
+        listed: &[&ProcessedArticle],
+    ) -> Result<usize> {
+        let indexable: Vec<&ProcessedArticle> = listed
+            .iter()
+            .copied()
+            .filter(|article| article.metadata.is_indexable())
+            .collect();
+        let latest = latest_date(indexable.iter().map(|article| lastmod(article)));
+
+        let mut urls = vec![
+            SitemapUrl::new("/", latest),
+            SitemapUrl::new("/article", latest),
+        ];
+        urls.extend(indexable.iter().map(|article| {
+            SitemapUrl::new(format!("/article/{}", article.slug), lastmod(article))
+        }));
+
+        let tags: BTreeSet<&str> = indexable
+            .iter()
+            .flat_map(|article| &article.metadata.tags)
+            .flat_map(|tag| tag_ancestors(tag))
+            .collect();
+        urls.extend(tags.into_iter().map(|tag| {
+            let tagged = indexable
+                .iter()
+                .filter(|article| article.metadata.tags.iter().any(|t| tag_matches(t, tag)));
+            SitemapUrl::new(
+                format!("/tag/{tag}"),
+                latest_date(tagged.map(|article| lastmod(article))),
+            )
+        }));
+
+        std::fs::write(
+            dist_dir.join("sitemap.xml"),
+            sitemap_xml(&config.site, &urls),
+        )
+        .context("Failed to write sitemap.xml")?;
+        std::fs::write(
+            dist_dir.join("robots.txt"),
+            robots_txt(&config.site, &config.base_path),
+        )
+        .context("Failed to write robots.txt")?;
+
+        Ok(urls.len())
+    }
+}
+
+/// `updated_at`, else `created_at`
+fn lastmod(article: &ProcessedArticle) -> Option<&str> {
+    let metadata = &article.metadata;
+    metadata
+        .updated_at
+        .as_deref()
+        .or(metadata.created_at.as_deref())
+}
+
+fn listing_items<'a>(
+    config: &AppConfig,
+    articles: &[&'a ProcessedArticle],
+) -> Vec<ListingItem<'a>> {
+    articles
+        .iter()
+        .map(|article| ListingItem {
+            href: config.get_url(&format!("article/{}", article.slug)),
+            title: &article.title,
+            summary: article.summary.as_deref(),
+            created_at: article.metadata.created_at.as_deref(),
+        })
+        .collect()
+}
+
+/// Write `html` as `{route}/index.html` so the route is served without a 404 fallback
+fn write_page(dist_dir: &Path, route: &str, html: &str) -> Result<()> {
+    let dir = dist_dir.join(route.trim_start_matches('/'));
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    std::fs::write(dir.join("index.html"), html)
+        .with_context(|| format!("Failed to write {}/index.html", dir.display()))
+}
@@ -1,17 +1,41 @@
 use anyhow::{Context, Result};
 use chrono::Utc;
 use clap::Parser;
-use pulldown_cmark::{Event, Parser as MarkdownParser};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-use crate::config_loader::{get_default_articles_dir, get_images_dir};
-use crate::core::articles::links::{ExtractedLink, ProcessedArticleRef};
-use crate::core::articles::metadata::ArticleMetadata;
-use crate::core::articles::processor::ArticleProcessor;
+use crate::config::{get_config, AppConfig};
+use crate::config_loader::{
+    get_bibliography_config, get_default_articles_dir, get_feed_config, get_history_config,
+    get_images_dir, get_related_config, get_summary_config, load_configured_bibliography,
+    load_configured_og_renderer, load_configured_tag_registry,
+};
+use crate::core::articles::bibliography::{Bibliography, CitationStyle, Reference};
+use crate::core::articles::front_matter::strip_front_matter;
+use crate::core::articles::history::{apply_file_dates, FileHistory};
+use crate::core::articles::links::{
+    link_base, ExtractedLink, LinkResolver, LinkType, ProcessedArticleRef,
+};
+use crate::core::articles::metadata::{ArticleMetadata, ArticleStatus, Visibility};
+use crate::core::articles::processor::{ArticleProcessor, ProcessingDiagnostics};
+use crate::core::articles::revisions::ArticleRevisions;
+use crate::core::articles::schema::front_matter_schema;
+use crate::core::articles::sections::{build_sections, Section};
+use crate::core::articles::slugs::SlugIndex;
+use crate::core::articles::stats::ArticleStats;
+use crate::core::articles::summary::article_summary;
+use crate::core::articles::tag_registry::TagRegistry;
+use crate::core::articles::tags::{tag_ancestors, tag_matches, validate_tag};
+use crate::core::media::cover::{cover_source, is_cover_variant, CoverImage, IMAGES_URL_PREFIX};
 #[cfg(feature = "cli-tools")]
 use crate::core::media::image_optimizer::ImageOptimizer;
+#[cfg(feature = "cli-tools")]
+use crate::core::media::og_image::OgImageRenderer;
+use crate::core::render::MarkdownRenderer;
+use crate::core::search::{RelatedArticle, SearchIndex, SimilarityIndex};
+use crate::core::site::feed::{absolutize_urls, tag_feed_stem, Feed, FeedContent, FeedEntry};
 
 /// CLI arguments for the process articles command
 #[derive(Parser, Debug, Clone)]
@@ -37,6 +61,18 @@ pub struct ProcessArticlesArgs {
     /// Optimize images during processing
     #[arg(long)]
     pub optimize_images: bool,
+
+    /// Include drafts and scheduled or expired articles (local preview)
+    #[arg(long)]
+    pub include_drafts: bool,
+
+    /// Always keep front-matter dates; git history then only fills missing ones
+    #[arg(long)]
+    pub front_matter_dates: bool,
+
+    /// Fail instead of skipping articles with errors, and treat warnings as errors (CI)
+    #[arg(long)]
+    pub strict: bool,
 }
 
 /// Processed article data structure
@@ -45,11 +81,33 @@ pub struct ProcessedArticle {
     pub slug: String,
     pub title: String,
     pub metadata: ArticleMetadata,
+    /// Path relative to the articles directory, with `/` separators
     pub file_path: String,
     pub summary: Option<String>,
+    /// Length, block counts and reading time of the body
+    #[serde(default)]
+    pub stats: ArticleStats,
     pub outbound_links: Vec<ExtractedLink>,
     pub inbound_links: Vec<ExtractedLink>,
+    /// Canonical slug of each internal link, keyed by the path as written in the article
+    #[serde(default)]
+    pub link_targets: BTreeMap<String, String>,
     pub processed_at: String,
+    /// Works cited in the article, in citation order
+    #[serde(default)]
+    pub references: Vec<Reference>,
+    /// Number of commits in the exported revision history
+    #[serde(default)]
+    pub revision_count: usize,
+    /// Card and hero sizes of the front-matter `cover`
+    #[serde(default)]
+    pub cover: Option<CoverImage>,
+    /// Link-preview image: the cover's hero size, or one drawn from the title and tags
+    #[serde(default)]
+    pub og_image: Option<String>,
+    /// Articles listed in `related_articles`, then the most similar ones by content and tags
+    #[serde(default)]
+    pub related: Vec<RelatedArticle>,
 }
 
 impl ProcessedArticle {
@@ -58,6 +116,8 @@ impl ProcessedArticle {
         article_ref: ProcessedArticleRef,
         file_path: String,
         summary: Option<String>,
+        stats: ArticleStats,
+        references: Vec<Reference>,
     ) -> Self {
         use chrono::Utc;
 
@@ -67,9 +127,16 @@ impl ProcessedArticle {
             metadata: article_ref.metadata,
             file_path,
             summary,
+            stats,
             outbound_links: article_ref.outbound_links,
             inbound_links: article_ref.inbound_links,
+            link_targets: BTreeMap::new(),
             processed_at: Utc::now().to_rfc3339(),
+            references,
+            revision_count: 0,
+            cover: None,
+            og_image: None,
+            related: Vec::new(),
         }
     }
 }
@@ -81,6 +148,22 @@ pub struct ArticlesData {
     pub generated_at: String,
     pub total_count: usize,
     pub home_articles: Vec<String>,
+    /// Article directories, sorted by path
+    #[serde(default)]
+    pub sections: Vec<Section>,
+    /// Registered tags with display names, descriptions and colors
+    #[serde(default)]
+    pub tags: TagRegistry,
+}
+
+/// `path` relative to `articles_dir` with `/` separators, as served under `articles/`
+fn relative_file_path(articles_dir: &Path, path: &Path) -> String {
+    path.strip_prefix(articles_dir)
+        .unwrap_or(path)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
 }
 
 /// Command implementation for processing articles
@@ -120,21 +203,79 @@ impl ProcessArticlesCommand {
         // Create output directory if it doesn't exist
         std::fs::create_dir_all(&args.output_dir).context("Failed to create output directory")?;
 
-        // Process articles
-        let articles = self.process_articles(&articles_dir, &args)?;
+        // Load the bibliography used to resolve [@key] citations
+        let bibliography = load_configured_bibliography()?;
+        if args.verbose {
+            if let Some(bibliography) = &bibliography {
+                println!("📚 Loaded {} bibliography entries", bibliography.len());
+            }
+        }
+
+        // Load the tag registry that article tags are normalised against
+        let tag_registry = load_configured_tag_registry()?;
+        if args.verbose && !tag_registry.is_empty() {
+            println!("🏷️  Loaded {} registered tags", tag_registry.len());
+        }
+
+        // Start from an empty history directory so unpublished articles leave nothing behind
+        let history_dir = args.output_dir.join("history");
+        if history_dir.exists() {
+            std::fs::remove_dir_all(&history_dir)
+                .context("Failed to clear revision history directory")?;
+        }
+        std::fs::create_dir_all(&history_dir)
+            .context("Failed to create revision history directory")?;
+
+        // Generated OG images are redrawn every run, like the history
+        let og_dir = args.output_dir.join("og");
+        if og_dir.exists() {
+            std::fs::remove_dir_all(&og_dir).context("Failed to clear OG image directory")?;
+        }
+        let og_renderer = load_configured_og_renderer()?;
+
+        // Process articles; broken ones are skipped unless --strict
+        let mut diagnostics = ProcessingDiagnostics::default();
+        let mut articles = self.process_articles(
+            &articles_dir,
+            &args,
+            bibliography.as_ref(),
+            &mut diagnostics,
+        )?;
+        self.normalize_tags(&mut articles, &tag_registry, &mut diagnostics);
+        #[cfg(feature = "cli-tools")]
+        self.prepare_images(
+            &mut articles,
+            &tag_registry,
+            og_renderer.as_ref(),
+            &args,
+            &mut diagnostics,
+        )?;
+
+        let related_count = self.relate_articles(&mut articles, &articles_dir)?;
+        if args.verbose {
+            println!("🔗 Found related articles for {related_count} articles");
+        }
 
         // Create articles data structure
         let home_articles = articles
             .iter()
-            .filter(|a| a.metadata.home_display)
+            .filter(|a| a.metadata.home_display && a.metadata.is_listed())
             .map(|a| a.slug.clone())
             .collect();
 
+        let sections = build_sections(
+            articles
+                .iter()
+                .map(|a| (a.file_path.as_str(), a.slug.as_str(), a.title.as_str())),
+        );
+
         let articles_data = ArticlesData {
             total_count: articles.len(),
             articles,
             generated_at: Utc::now().to_rfc3339(),
             home_articles,
+            sections,
+            tags: tag_registry,
         };
 
         // Write JSON output
@@ -142,6 +283,30 @@ impl ProcessArticlesCommand {
         let json = serde_json::to_string_pretty(&articles_data)?;
         std::fs::write(&output_path, json).context("Failed to write articles.json")?;
 
+        // Publish the front-matter schema next to the data for editors to validate against
+        let schema_path = args.output_dir.join("front-matter.schema.json");
+        let schema = serde_json::to_string_pretty(&front_matter_schema())?;
+        std::fs::write(&schema_path, schema).context("Failed to write front-matter.schema.json")?;
+
+        // Atom and JSON feeds for the site and for every tag
+        let feed_count = self.write_feeds(
+            &articles_data,
+            &articles_dir,
+            &args.output_dir,
+            &mut diagnostics,
+        )?;
+        if args.verbose {
+            println!("📡 Wrote {feed_count} feeds");
+        }
+        // Skipped tag feeds are warnings too, so --strict fails only once they are known
+        diagnostics.finish(args.strict)?;
+
+        // Full-text index for the search page
+        let indexed = self.write_search_index(&articles_data, &articles_dir, &args.output_dir)?;
+        if args.verbose {
+            println!("🔎 Indexed {indexed} articles for search");
+        }
+
         if args.verbose {
             println!("✅ Processed {} articles", articles_data.total_count);
             println!("📄 Output written to: {}", output_path.display());
@@ -154,8 +319,25 @@ impl ProcessArticlesCommand {
         &self,
         articles_dir: &Path,
         args: &ProcessArticlesArgs,
+        bibliography: Option<&Bibliography>,
+        diagnostics: &mut ProcessingDiagnostics,
     ) -> Result<Vec<ProcessedArticle>> {
         let mut articles = Vec::new();
+        let citation_style = get_bibliography_config().style;
+        let summary_config = get_summary_config();
+        let export_history = get_history_config().export;
+        // Publication windows are checked against the build clock (CI rebuilds daily)
+        let now = Utc::now();
+        let mut hidden_count = 0;
+
+        // Missing dates come from the content repository's history (or mtime without one)
+        let history = FileHistory::discover(articles_dir);
+        if args.verbose {
+            match history.repo_root() {
+                Some(root) => println!("🕰️  Reading article dates from git: {}", root.display()),
+                None => println!("🕰️  No git repository found, using file modification times"),
+            }
+        }
 
         // Find all markdown files
         for entry in WalkDir::new(articles_dir)
@@ -169,22 +351,71 @@ impl ProcessArticlesCommand {
                     println!("Processing: {}", path.display());
                 }
 
-                let content = std::fs::read_to_string(path)
-                    .with_context(|| format!("Failed to read file: {}", path.display()))?;
+                let content = match std::fs::read_to_string(path) {
+                    Ok(content) => content,
+                    Err(e) => {
+                        diagnostics.error(path, format!("Failed to read file: {e}"));
+                        continue;
+                    }
+                };
+                let processed = self
+                    .processor
+                    .process_article_in(articles_dir, path, &content);
+                let Some(mut processed_ref) = diagnostics.check(path, processed) else {
+                    continue;
+                };
 
-                let content_only = self.parse_content_only(&content);
-                let summary = self.extract_summary_from_content(&content_only);
-                let processed_ref = self.processor.process_article(path, &content)?;
-                let file_path = path.to_string_lossy().to_string();
-                let processed = ProcessedArticle::from_ref_and_file_path(
+                if let Some(dates) = history.dates_for(path) {
+                    apply_file_dates(&mut processed_ref.metadata, &dates, args.front_matter_dates);
+                }
+
+                let visibility = processed_ref.metadata.visibility_at(now);
+                if visibility == Visibility::Hidden && !args.include_drafts {
+                    if args.verbose {
+                        println!("⏭️  Skipping unpublished: {}", path.display());
+                    }
+                    hidden_count += 1;
+                    continue;
+                }
+
+                let body = strip_front_matter(&content);
+                let summary = article_summary(
+                    processed_ref.metadata.description.as_deref(),
+                    body,
+                    &summary_config,
+                );
+                let stats = ArticleStats::from_markdown(body);
+                let references = self.resolve_references(
+                    &processed_ref,
+                    path,
+                    bibliography,
+                    citation_style,
+                    diagnostics,
+                );
+                let file_path = relative_file_path(articles_dir, path);
+                let mut processed = ProcessedArticle::from_ref_and_file_path(
                     processed_ref,
                     file_path,
-                    Some(summary),
+                    summary,
+                    stats,
+                    references,
                 );
+                if export_history {
+                    processed.revision_count =
+                        self.export_revisions(&history, path, &processed.slug, args)?;
+                }
                 articles.push(processed);
             }
         }
 
+        self.canonicalize_slugs(&mut articles)?;
+
+        if hidden_count > 0 {
+            println!(
+                "🙈 Excluded {hidden_count} draft, scheduled or expired articles (use --include-drafts to preview)"
+            );
+        }
+
         // Optimize images if requested
         #[cfg(feature = "cli-tools")]
         if args.optimize_images {
@@ -196,84 +427,431 @@ impl ProcessArticlesCommand {
         Ok(articles)
     }
 
-    fn extract_summary_from_content(&self, content: &str) -> String {
-        let plain_text = self.extract_plain_text_from_markdown(content);
-        let content = plain_text.trim();
-
-        if let Some(first_paragraph) = content.split("\n\n").next() {
-            let cleaned = first_paragraph
-                .lines()
-                .map(|line| line.trim())
-                .filter(|line| !line.is_empty())
-                .collect::<Vec<_>>()
-                .join(" ");
+    /// Fail on slugs or aliases claimed twice, then point alias links at canonical slugs
+    fn canonicalize_slugs(&self, articles: &mut [ProcessedArticle]) -> Result<()> {
+        let (index, conflicts) = SlugIndex::build(
+            articles
+                .iter()
+                .map(|a| (a.slug.as_str(), &a.metadata.aliases)),
+        );
+
+        if !conflicts.is_empty() {
+            let details: Vec<String> = conflicts
+                .iter()
+                .map(|conflict| {
+                    let files: Vec<&str> = articles
+                        .iter()
+                        .filter(|a| {
+                            a.slug == conflict.slug || a.metadata.aliases.contains(&conflict.slug)
+                        })
+                        .map(|a| a.file_path.as_str())
+                        .collect();
+                    format!("  {conflict} ({})", files.join(", "))
+                })
+                .collect();
+            anyhow::bail!(
+                "Several articles claim the same slug or alias:\n{}",
+                details.join("\n")
+            );
+        }
 
-            if !cleaned.is_empty() {
-                return self.truncate_string_safely(&cleaned, 150);
+        // Links resolve from the linking article's directory, as in `validate-links`
+        let resolver = LinkResolver::new(index);
+        for article in articles.iter_mut() {
+            let base = link_base(&article.file_path);
+            article.link_targets = resolver.link_targets(base, &article.outbound_links);
+            for link in &mut article.outbound_links {
+                if link.link_type != LinkType::MarkdownLink {
+                    continue;
+                }
+                if let Some(target) = resolver.resolve(base, &link.target_slug) {
+                    link.target_slug = target.to_string();
+                }
+            }
+            for related in &mut article.metadata.related_articles {
+                if let Some(target) = resolver.resolve_slug(related) {
+                    *related = target.to_string();
+                }
             }
         }
 
-        let cleaned = content
-            .lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.is_empty())
-            .collect::<Vec<_>>()
-            .join(" ");
+        Ok(())
+    }
+
+    /// Write `feed.xml`/`feed.json` and `feeds/{tag}.xml`/`.json`, returning the feed count
+    fn write_feeds(
+        &self,
+        articles_data: &ArticlesData,
+        articles_dir: &Path,
+        output_dir: &Path,
+        diagnostics: &mut ProcessingDiagnostics,
+    ) -> Result<usize> {
+        let config = get_feed_config();
+        let site = &get_config().site;
+
+        // Tags can disappear between runs, like history and OG images
+        let tag_feeds_dir = output_dir.join("feeds");
+        if tag_feeds_dir.exists() {
+            std::fs::remove_dir_all(&tag_feeds_dir)
+                .context("Failed to clear tag feed directory")?;
+        }
 
-        if cleaned.is_empty() {
-            "記事の概要はありません。".to_string()
-        } else {
-            self.truncate_string_safely(&cleaned, 150)
+        // Feed readers show content outside the site, so render without a base path and
+        // make every link absolute
+        let render_config = AppConfig::with_base_path("");
+        let listed: Vec<&ProcessedArticle> = articles_data
+            .articles
+            .iter()
+            .filter(|a| a.metadata.is_public())
+            .collect();
+        let mut entries = Vec::with_capacity(listed.len());
+        for article in &listed {
+            let content_html = match config.content {
+                FeedContent::Summary => None,
+                FeedContent::Full => {
+                    let source_path = articles_dir.join(&article.file_path);
+                    let source = std::fs::read_to_string(&source_path)
+                        .with_context(|| format!("Failed to read {}", source_path.display()))?;
+                    let html = MarkdownRenderer::new(&render_config)
+                        .with_references(&article.references)
+                        .with_link_targets(link_base(&article.file_path), &article.link_targets)
+                        .render(strip_front_matter(&source));
+                    Some(absolutize_urls(&html, site))
+                }
+            };
+            entries.push(FeedEntry {
+                title: article.title.clone(),
+                path: format!("/article/{}", article.slug),
+                summary: article.summary.clone(),
+                content_html,
+                published: article.metadata.created_at.clone(),
+                updated: article.metadata.updated_at.clone(),
+                tags: article.metadata.tags.clone(),
+                image: article.og_image.clone(),
+            });
         }
-    }
 
-    fn truncate_string_safely(&self, text: &str, max_len: usize) -> String {
-        if text.len() <= max_len {
-            return text.to_string();
+        let mut feeds = vec![Feed::new(
+            site,
+            site.name.clone(),
+            "/",
+            "feed",
+            entries.clone(),
+            config.limit,
+        )];
+        let tags: BTreeSet<&str> = entries
+            .iter()
+            .flat_map(|entry| &entry.tags)
+            .flat_map(|tag| tag_ancestors(tag))
+            .collect();
+        for tag in tags {
+            // Tags become file paths below data/feeds
+            if let Err(e) = validate_tag(tag) {
+                let tagged_file = listed
+                    .iter()
+                    .find(|a| a.metadata.tags.iter().any(|t| tag_matches(t, tag)))
+                    .map_or(articles_dir, |a| Path::new(&a.file_path));
+                diagnostics.warning(tagged_file, format!("skipped the feed of #{tag}: {e}"));
+                continue;
+            }
+            let tagged = entries
+                .iter()
+                .filter(|entry| entry.tags.iter().any(|t| tag_matches(t, tag)))
+                .cloned()
+                .collect();
+            feeds.push(Feed::new(
+                site,
+                format!("{} #{}", site.name, articles_data.tags.display_name(tag)),
+                format!("/tag/{tag}"),
+                tag_feed_stem(tag),
+                tagged,
+                config.limit,
+            ));
         }
 
-        let mut truncate_at = max_len.saturating_sub(3);
-        while truncate_at > 0 && !text.is_char_boundary(truncate_at) {
-            truncate_at -= 1;
+        for feed in &feeds {
+            let atom_path = output_dir.join(feed.atom_path().trim_start_matches("/data/"));
+            let json_path = output_dir.join(feed.json_path().trim_start_matches("/data/"));
+            if let Some(parent) = atom_path.parent() {
+                std::fs::create_dir_all(parent).context("Failed to create feed directory")?;
+            }
+            std::fs::write(&atom_path, feed.atom(&articles_data.generated_at))
+                .with_context(|| format!("Failed to write {}", atom_path.display()))?;
+            std::fs::write(&json_path, serde_json::to_string_pretty(&feed.json())?)
+                .with_context(|| format!("Failed to write {}", json_path.display()))?;
         }
 
-        format!("{}...", &text[..truncate_at])
+        Ok(feeds.len())
     }
 
-    fn extract_plain_text_from_markdown(&self, content: &str) -> String {
-        let parser = MarkdownParser::new(content);
-        let mut plain_text = String::new();
+    /// Fill in related articles, returning how many articles have any
+    ///
+    /// Hand-picked `related_articles` come first; the most similar listed articles follow.
+    fn relate_articles(
+        &self,
+        articles: &mut [ProcessedArticle],
+        articles_dir: &Path,
+    ) -> Result<usize> {
+        let config = get_related_config();
+        let candidates: Vec<usize> = articles
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.metadata.is_public())
+            .map(|(position, _)| position)
+            .collect();
 
-        for event in parser {
-            if let Event::Text(text) = event {
-                plain_text.push_str(&text);
+        let mut index = SimilarityIndex::new();
+        for &position in &candidates {
+            let article = &articles[position];
+            let source_path = articles_dir.join(&article.file_path);
+            let source = std::fs::read_to_string(&source_path)
+                .with_context(|| format!("Failed to read {}", source_path.display()))?;
+            index.add_article(
+                &article.title,
+                &article.metadata.tags,
+                strip_front_matter(&source),
+            );
+        }
+
+        let entry = |article: &ProcessedArticle, score: Option<f32>| RelatedArticle {
+            slug: article.slug.clone(),
+            title: article.title.clone(),
+            summary: article.summary.clone(),
+            score,
+        };
+        let mut related: Vec<Vec<RelatedArticle>> = articles
+            .iter()
+            .map(|article| {
+                article
+                    .metadata
+                    .related_articles
+                    .iter()
+                    .filter_map(|slug| articles.iter().find(|a| &a.slug == slug))
+                    // Hand-picked articles are held to the same visibility as similar ones
+                    .filter(|a| a.metadata.is_public())
+                    .map(|a| entry(a, None))
+                    .collect()
+            })
+            .collect();
+        for (position, neighbours) in index.neighbours(&config).into_iter().enumerate() {
+            let list = &mut related[candidates[position]];
+            for (other, score) in neighbours {
+                let other = &articles[candidates[other]];
+                if list.iter().all(|r| r.slug != other.slug) {
+                    list.push(entry(other, Some(score)));
+                }
             }
         }
 
-        plain_text
+        let count = related.iter().filter(|list| !list.is_empty()).count();
+        for (article, list) in articles.iter_mut().zip(related) {
+            article.related = list;
+        }
+        Ok(count)
+    }
+
+    /// Write `search.json` over the listed articles, returning how many were indexed
+    fn write_search_index(
+        &self,
+        articles_data: &ArticlesData,
+        articles_dir: &Path,
+        output_dir: &Path,
+    ) -> Result<usize> {
+        let mut index = SearchIndex::new();
+        for article in articles_data
+            .articles
+            .iter()
+            .filter(|a| a.metadata.is_public())
+        {
+            let source_path = articles_dir.join(&article.file_path);
+            let source = std::fs::read_to_string(&source_path)
+                .with_context(|| format!("Failed to read {}", source_path.display()))?;
+            index.add_article(
+                &article.slug,
+                &article.title,
+                &article.metadata.tags,
+                strip_front_matter(&source),
+            );
+        }
+
+        // Compact, as the page downloads it whole
+        let search_path = output_dir.join("search.json");
+        std::fs::write(&search_path, serde_json::to_string(&index)?)
+            .context("Failed to write search.json")?;
+        Ok(index.documents.len())
     }
 
-    fn parse_content_only(&self, content: &str) -> String {
-        let content = content.trim();
+    /// Rewrite tags and aliases to their registered spelling, warning about unregistered ones
+    fn normalize_tags(
+        &self,
+        articles: &mut [ProcessedArticle],
+        registry: &TagRegistry,
+        diagnostics: &mut ProcessingDiagnostics,
+    ) {
+        if registry.is_empty() {
+            return;
+        }
 
-        if content.starts_with("---") {
-            let lines: Vec<&str> = content.lines().collect();
+        for article in articles.iter_mut() {
+            let (tags, unknown) = registry.normalize_tags(&article.metadata.tags);
+            for tag in unknown {
+                diagnostics.warning(
+                    Path::new(&article.file_path),
+                    format!("unregistered tag #{tag}"),
+                );
+            }
+            article.metadata.tags = tags;
 
-            let mut end_index = None;
-            for (i, line) in lines.iter().enumerate().skip(1) {
-                if line.trim() == "---" {
-                    end_index = Some(i);
-                    break;
+            if let Some(hub_tag) = &article.metadata.hub_tag {
+                if let Some(canonical) = registry.normalize(hub_tag) {
+                    article.metadata.hub_tag = Some(canonical);
                 }
             }
+        }
+    }
 
-            if let Some(end_idx) = end_index {
-                let remaining_lines = &lines[end_idx + 1..];
-                return remaining_lines.join("\n").trim_start().to_string();
+    /// Cut covers into card and hero sizes, and draw OG images for articles without a cover
+    #[cfg(feature = "cli-tools")]
+    fn prepare_images(
+        &self,
+        articles: &mut [ProcessedArticle],
+        registry: &TagRegistry,
+        og_renderer: Option<&OgImageRenderer>,
+        args: &ProcessArticlesArgs,
+        diagnostics: &mut ProcessingDiagnostics,
+    ) -> Result<()> {
+        let images_dir = get_images_dir();
+
+        for article in articles.iter_mut() {
+            let path = Path::new(&article.file_path);
+            let cover = article.metadata.cover.as_deref();
+            if let (Some(cover), Some(optimizer)) = (cover, &self.image_optimizer) {
+                match cover_source(cover) {
+                    Some(source) => match optimizer.optimize_cover(&images_dir, source) {
+                        Ok(_) => article.cover = Some(CoverImage::for_source(source)),
+                        Err(e) => diagnostics.warning(path, format!("cover skipped: {e:#}")),
+                    },
+                    None => diagnostics.warning(
+                        path,
+                        format!("cover `{cover}` must be an image under {IMAGES_URL_PREFIX}"),
+                    ),
+                }
             }
+
+            article.og_image = match (&article.cover, og_renderer) {
+                (Some(cover), _) => Some(cover.hero.clone()),
+                (None, Some(renderer)) => {
+                    // Nested slugs become nested directories
+                    let relative = format!("og/{}.png", article.slug);
+                    let og_path = args.output_dir.join(&relative);
+                    if let Some(parent) = og_path.parent() {
+                        std::fs::create_dir_all(parent)
+                            .with_context(|| format!("Failed to create {}", parent.display()))?;
+                    }
+
+                    let tags: Vec<String> = article
+                        .metadata
+                        .tags
+                        .iter()
+                        .map(|tag| registry.display_name(tag).to_string())
+                        .collect();
+                    let accent = article
+                        .metadata
+                        .tags
+                        .first()
+                        .and_then(|tag| registry.get(tag))
+                        .and_then(|info| info.color.as_deref());
+                    renderer
+                        .render(&article.title, &tags, accent)
+                        .save(&og_path)
+                        .with_context(|| format!("Failed to write {}", og_path.display()))?;
+                    Some(format!("/data/{relative}"))
+                }
+                (None, None) => None,
+            };
         }
 
-        content.to_string()
+        if args.verbose {
+            let covers = articles.iter().filter(|a| a.cover.is_some()).count();
+            let generated = articles
+                .iter()
+                .filter(|a| a.cover.is_none() && a.og_image.is_some())
+                .count();
+            println!("🖼️  {covers} covers, {generated} generated OG images");
+        }
+        Ok(())
+    }
+
+    /// Write the article's commit history to `history/{slug}.json`, returning the commit count
+    ///
+    /// The history starts at the first commit where the article was not a draft.
+    fn export_revisions(
+        &self,
+        history: &FileHistory,
+        path: &Path,
+        slug: &str,
+        args: &ProcessArticlesArgs,
+    ) -> Result<usize> {
+        let extractor = self.processor.metadata_extractor();
+        let revisions = history.published_revisions_for(path, |content| {
+            extractor
+                .extract_frontmatter(content)
+                .is_ok_and(|(metadata, _)| metadata.status != ArticleStatus::Draft)
+        });
+        if revisions.is_empty() {
+            return Ok(0);
+        }
+
+        let count = revisions.len();
+        // Nested slugs become nested directories
+        let history_path = args.output_dir.join(ArticleRevisions::data_path(slug));
+        if let Some(parent) = history_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let json = serde_json::to_string(&ArticleRevisions {
+            slug: slug.to_string(),
+            revisions,
+        })?;
+        std::fs::write(&history_path, json)
+            .with_context(|| format!("Failed to write {}", history_path.display()))?;
+
+        if args.verbose {
+            println!("📜 {count} revisions written to {}", history_path.display());
+        }
+        Ok(count)
+    }
+
+    fn resolve_references(
+        &self,
+        article: &ProcessedArticleRef,
+        path: &Path,
+        bibliography: Option<&Bibliography>,
+        style: CitationStyle,
+        diagnostics: &mut ProcessingDiagnostics,
+    ) -> Vec<Reference> {
+        if article.citation_keys.is_empty() {
+            return Vec::new();
+        }
+
+        let Some(bibliography) = bibliography else {
+            diagnostics.warning(
+                path,
+                format!(
+                    "cites {} works but no bibliography could be loaded",
+                    article.citation_keys.len()
+                ),
+            );
+            return Vec::new();
+        };
+
+        for key in &article.citation_keys {
+            if !bibliography.contains(key) {
+                diagnostics.warning(path, format!("cites unknown key @{key}"));
+            }
+        }
+
+        bibliography.references_for(&article.citation_keys, style)
     }
 
     #[cfg(feature = "cli-tools")]
@@ -292,9 +870,7 @@ impl ProcessArticlesCommand {
         // First, clean up any previously optimized images to avoid recursive optimization
         let cleaned_count = optimizer.cleanup_optimized_images(&images_dir)?;
         if args.verbose && cleaned_count > 0 {
-            println!(
-                "🧹 Cleaned up {cleaned_count} previously optimized images"
-            );
+            println!("🧹 Cleaned up {cleaned_count} previously optimized images");
         }
 
         for entry in WalkDir::new(&images_dir)
@@ -304,13 +880,14 @@ impl ProcessArticlesCommand {
         {
             let path = entry.path();
 
-            // Skip already optimized images
+            // Skip already optimized images and cover variants
             if let Some(file_name) = path.file_name() {
                 let file_name_str = file_name.to_string_lossy();
                 if file_name_str.contains("_small")
                     || file_name_str.contains("_medium")
                     || file_name_str.contains("_large")
                     || file_name_str.contains("_tiny")
+                    || is_cover_variant(&file_name_str)
                 {
                     if args.verbose {
                         println!("⏭️  Skipping already optimized: {}", path.display());
@@ -3,9 +3,9 @@ use clap::Parser;
 use std::path::PathBuf;
 use walkdir::WalkDir;
 
-use crate::config_loader::get_default_articles_dir;
+use crate::config_loader::{get_default_articles_dir, load_configured_bibliography};
 use crate::core::articles::links::{LinkValidator, ProcessedArticleRef};
-use crate::core::articles::processor::ArticleProcessor;
+use crate::core::articles::processor::{ArticleProcessor, ProcessingDiagnostics};
 
 /// CLI arguments for the validate links command
 #[derive(Parser, Debug, Clone)]
@@ -19,6 +19,10 @@ pub struct ValidateLinksArgs {
     /// Enable verbose output
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Fail on articles that cannot be processed and on front-matter warnings (CI)
+    #[arg(long)]
+    pub strict: bool,
 }
 
 /// Command implementation for validating links
@@ -46,11 +50,16 @@ impl ValidateLinksCommand {
             );
         }
 
-        // Process articles and extract links
-        let processed_articles = self.process_articles(&articles_dir, &args)?;
+        // Process articles and extract links; broken ones are skipped unless --strict
+        let mut diagnostics = ProcessingDiagnostics::default();
+        let processed_articles = self.process_articles(&articles_dir, &args, &mut diagnostics)?;
+        diagnostics.finish(args.strict)?;
 
-        // Create validator with processed articles
-        let validator = LinkValidator::new(&processed_articles);
+        // Create validator with processed articles and the bibliography, if configured
+        let mut validator = LinkValidator::new(&processed_articles);
+        if let Some(bibliography) = load_configured_bibliography()? {
+            validator = validator.with_bibliography(&bibliography);
+        }
 
         // Validate links
         let validation_results = validator.validate_all()?;
@@ -69,11 +78,23 @@ impl ValidateLinksCommand {
             validation_results.summary.total_links
         );
 
-        if validation_results.summary.broken_links > 0 {
+        let has_problems = validation_results.summary.broken_links > 0
+            || validation_results.summary.unknown_citations > 0
+            || validation_results.summary.duplicate_slugs > 0;
+
+        if has_problems {
             println!(
                 "   ❌ Broken links: {}",
                 validation_results.summary.broken_links
             );
+            println!(
+                "   📖 Unknown citations: {}",
+                validation_results.summary.unknown_citations
+            );
+            println!(
+                "   🪪 Duplicate slugs: {}",
+                validation_results.summary.duplicate_slugs
+            );
             println!();
             println!("❌ Errors:");
             for (i, error) in validation_results.errors.iter().enumerate() {
@@ -96,6 +117,12 @@ impl ValidateLinksCommand {
                     crate::core::articles::links::ValidationErrorType::OrphanedArticle => {
                         "🏝️  Orphaned Article"
                     }
+                    crate::core::articles::links::ValidationErrorType::UnknownCitation => {
+                        "📖 Unknown Citation"
+                    }
+                    crate::core::articles::links::ValidationErrorType::DuplicateSlug => {
+                        "🪪 Duplicate Slug"
+                    }
                 };
 
                 let mut formatted = format!(
@@ -130,6 +157,7 @@ impl ValidateLinksCommand {
         &self,
         articles_dir: &std::path::Path,
         args: &ValidateLinksArgs,
+        diagnostics: &mut ProcessingDiagnostics,
     ) -> Result<Vec<ProcessedArticleRef>> {
         let mut processed_articles = Vec::new();
 
@@ -145,9 +173,15 @@ impl ValidateLinksCommand {
                     println!("Processing: {}", path.display());
                 }
 
-                let content = std::fs::read_to_string(path)?;
-                let processed = self.processor.process_article(path, &content)?;
-                processed_articles.push(processed);
+                let processed = std::fs::read_to_string(path)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|content| {
+                        self.processor
+                            .process_article_in(articles_dir, path, &content)
+                    });
+                if let Some(processed) = diagnostics.check(path, processed) {
+                    processed_articles.push(processed);
+                }
             }
         }
 
@@ -2,7 +2,8 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 
 use crate::cli::commands::{
-    ProcessArticlesArgs, ProcessArticlesCommand, ValidateLinksArgs, ValidateLinksCommand,
+    PrerenderArgs, PrerenderCommand, ProcessArticlesArgs, ProcessArticlesCommand,
+    ValidateLinksArgs, ValidateLinksCommand,
 };
 
 /// CLI for khimoo-portfolio tools
@@ -20,6 +21,8 @@ pub enum Commands {
     ProcessArticles(ProcessArticlesArgs),
     /// Validate links in markdown articles
     ValidateLinks(ValidateLinksArgs),
+    /// Write static HTML pages, sitemap and robots.txt for crawlers into the built site
+    Prerender(PrerenderArgs),
 }
 
 impl Cli {
@@ -33,6 +36,10 @@ impl Cli {
                 let command = ValidateLinksCommand::new()?;
                 command.execute(args)
             }
+            Commands::Prerender(args) => {
+                let command = PrerenderCommand::new()?;
+                command.execute(args)
+            }
         }
     }
 }
@@ -2,6 +2,13 @@ use serde::{Deserialize, Serialize};
 
 use std::sync::OnceLock;
 
+use crate::core::articles::bibliography::{BibliographyConfig, CitationStyle};
+use crate::core::render::SanitizerPolicy;
+use crate::core::site::SiteConfig;
+
+/// project.toml embedded at build time so the WASM app sees the same settings as the CLI
+pub const PROJECT_TOML: &str = include_str!("../../project.toml");
+
 /// Node configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NodeConfig {
@@ -111,12 +118,20 @@ pub struct AppConfig {
     pub articles_path: String,
     pub assets_path: String,
     pub node_config: NodeConfig,
+    pub sanitizer_policy: SanitizerPolicy,
+    pub citation_style: CitationStyle,
+    pub site: SiteConfig,
 }
 
 impl AppConfig {
     /// Create configuration based on current environment
     pub fn new() -> Self {
-        let base_path = Self::detect_base_path();
+        Self::with_base_path(Self::detect_base_path())
+    }
+
+    /// Create configuration for a known base path (prerendering for a deployment)
+    pub fn with_base_path(base_path: impl Into<String>) -> Self {
+        let base_path = base_path.into();
 
         Self {
             data_path: format!("{base_path}/data"),
@@ -124,6 +139,9 @@ impl AppConfig {
             assets_path: format!("{base_path}/assets"),
             base_path,
             node_config: NodeConfig::default(),
+            sanitizer_policy: SanitizerPolicy::from_project_toml(PROJECT_TOML),
+            citation_style: BibliographyConfig::from_project_toml(PROJECT_TOML).style,
+            site: SiteConfig::from_project_toml(PROJECT_TOML),
         }
     }
 
@@ -161,7 +179,7 @@ impl AppConfig {
             local_dev_path
         }
 
-        // WebAssembly fallback - use hardcoded values
+        // WebAssembly: read [deployment] from the embedded project.toml
         #[cfg(target_arch = "wasm32")]
         {
             // Check if we're in debug mode (local development)
@@ -169,20 +187,22 @@ impl AppConfig {
                 return String::new(); // Empty string for root path in dev
             }
 
+            let github_pages_path = github_pages_path_from_toml(PROJECT_TOML);
+
             // Check window location for production
             if let Some(window) = web_sys::window() {
                 // Check hostname for GitHub Pages
                 if let Ok(hostname) = window.location().hostname() {
                     if hostname.contains("github.io") {
-                        return "/portfolio-page".to_string();
+                        return github_pages_path;
                     }
                 }
 
                 if let Ok(pathname) = window.location().pathname() {
-                    if pathname.starts_with("/portfolio-page/")
-                        || pathname.contains("/portfolio-page")
+                    if pathname.starts_with(&format!("{github_pages_path}/"))
+                        || pathname.contains(&github_pages_path)
                     {
-                        return "/portfolio-page".to_string();
+                        return github_pages_path;
                     }
                 }
             }
@@ -207,23 +227,11 @@ impl AppConfig {
         self.get_url(&format!("data/{filename}"))
     }
 
-    /// Get article file URL
+    /// Get article file URL from a path relative to the articles directory
     pub fn article_url(&self, filepath: &str) -> String {
-        // Remove any leading path components and keep only the filename
+        // Keeps section directories ("notes/topology/index.md"); trunk copies the tree as-is
         let clean_path = filepath.trim_start_matches('/');
-
-        // Extract just the filename from paths like "../content/articles/about-khimoo.md"
-        let filename = if let Some(filename) = clean_path.split('/').next_back() {
-            filename
-        } else {
-            clean_path
-        };
-
-        if self.base_path.is_empty() {
-            format!("/articles/{filename}")
-        } else {
-            format!("{}/articles/{}", self.base_path, filename)
-        }
+        self.get_url(&format!("articles/{clean_path}"))
     }
 }
 
@@ -233,6 +241,20 @@ impl Default for AppConfig {
     }
 }
 
+/// `[deployment] github_pages_path` without a trailing slash
+pub fn github_pages_path_from_toml(content: &str) -> String {
+    toml::from_str::<toml::Value>(content)
+        .ok()
+        .and_then(|config| {
+            config
+                .get("deployment")?
+                .get("github_pages_path")?
+                .as_str()
+                .map(|path| path.trim_end_matches('/').to_string())
+        })
+        .unwrap_or_else(|| "/portfolio-page".to_string())
+}
+
 /// Global configuration instance
 static CONFIG: OnceLock<AppConfig> = OnceLock::new();
 
@@ -1,8 +1,22 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+#[cfg(feature = "cli-tools")]
+use crate::core::articles::bibliography::{Bibliography, BibliographyConfig};
+#[cfg(feature = "cli-tools")]
+use crate::core::articles::revisions::HistoryConfig;
+#[cfg(feature = "cli-tools")]
+use crate::core::articles::summary::SummaryConfig;
+#[cfg(feature = "cli-tools")]
+use crate::core::articles::tag_registry::{TagRegistry, TagsConfig};
 #[cfg(feature = "cli-tools")]
 use crate::core::media::image_optimizer::ImageOptimizationConfig;
+#[cfg(feature = "cli-tools")]
+use crate::core::media::og_image::{OgImageConfig, OgImageRenderer};
+#[cfg(feature = "cli-tools")]
+use crate::core::search::RelatedConfig;
+#[cfg(feature = "cli-tools")]
+use crate::core::site::FeedConfig;
 
 /// Load configuration from project.toml
 pub fn load_project_config() -> Result<HashMap<String, String>, Box<dyn std::error::Error>> {
@@ -116,6 +130,153 @@ pub fn get_images_dir() -> PathBuf {
     }
 }
 
+/// Get bibliography configuration from project.toml
+#[cfg(feature = "cli-tools")]
+pub fn get_bibliography_config() -> BibliographyConfig {
+    match load_full_config() {
+        Ok(config) => config
+            .get("bibliography")
+            .cloned()
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default(),
+        Err(_) => BibliographyConfig::default(),
+    }
+}
+
+/// Get the BibTeX file path from configuration, if one is configured
+#[cfg(feature = "cli-tools")]
+pub fn get_bibliography_path() -> Option<PathBuf> {
+    get_bibliography_config()
+        .file
+        .map(|file| PathBuf::from(format!("../{file}")))
+}
+
+/// Load the configured bibliography; a missing file only warns so articles without citations still build
+#[cfg(feature = "cli-tools")]
+pub fn load_configured_bibliography() -> anyhow::Result<Option<Bibliography>> {
+    let Some(path) = get_bibliography_path() else {
+        return Ok(None);
+    };
+
+    if !path.exists() {
+        eprintln!("Warning: bibliography not found: {}", path.display());
+        return Ok(None);
+    }
+
+    Bibliography::load(&path).map(Some)
+}
+
+/// Get summary configuration from project.toml
+#[cfg(feature = "cli-tools")]
+pub fn get_summary_config() -> SummaryConfig {
+    match load_full_config() {
+        Ok(config) => config
+            .get("summary")
+            .cloned()
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default(),
+        Err(_) => SummaryConfig::default(),
+    }
+}
+
+/// Get feed configuration from project.toml
+#[cfg(feature = "cli-tools")]
+pub fn get_feed_config() -> FeedConfig {
+    match load_full_config() {
+        Ok(config) => config
+            .get("feed")
+            .cloned()
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default(),
+        Err(_) => FeedConfig::default(),
+    }
+}
+
+/// Get related-article configuration from project.toml
+#[cfg(feature = "cli-tools")]
+pub fn get_related_config() -> RelatedConfig {
+    match load_full_config() {
+        Ok(config) => config
+            .get("related")
+            .cloned()
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default(),
+        Err(_) => RelatedConfig::default(),
+    }
+}
+
+/// Get revision-history export configuration from project.toml
+#[cfg(feature = "cli-tools")]
+pub fn get_history_config() -> HistoryConfig {
+    match load_full_config() {
+        Ok(config) => config
+            .get("history")
+            .cloned()
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default(),
+        Err(_) => HistoryConfig::default(),
+    }
+}
+
+/// Get the tag registry file path from configuration, if one is configured
+#[cfg(feature = "cli-tools")]
+pub fn get_tag_registry_path() -> Option<PathBuf> {
+    let config: TagsConfig = match load_full_config() {
+        Ok(config) => config
+            .get("tags")
+            .cloned()
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default(),
+        Err(_) => TagsConfig::default(),
+    };
+    config.file.map(|file| PathBuf::from(format!("../{file}")))
+}
+
+/// Load the configured tag registry; without one, tags are used as written
+#[cfg(feature = "cli-tools")]
+pub fn load_configured_tag_registry() -> anyhow::Result<TagRegistry> {
+    let Some(path) = get_tag_registry_path() else {
+        return Ok(TagRegistry::default());
+    };
+
+    if !path.exists() {
+        eprintln!("Warning: tag registry not found: {}", path.display());
+        return Ok(TagRegistry::default());
+    }
+
+    TagRegistry::load(&path)
+}
+
+/// Get Open Graph image configuration from project.toml
+#[cfg(feature = "cli-tools")]
+pub fn get_og_image_config() -> OgImageConfig {
+    match load_full_config() {
+        Ok(config) => config
+            .get("og_image")
+            .cloned()
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default(),
+        Err(_) => OgImageConfig::default(),
+    }
+}
+
+/// Load the renderer for generated OG images; without a font, articles only get cover images
+#[cfg(feature = "cli-tools")]
+pub fn load_configured_og_renderer() -> anyhow::Result<Option<OgImageRenderer>> {
+    let config = get_og_image_config();
+    let Some(font) = &config.font else {
+        return Ok(None);
+    };
+
+    let path = PathBuf::from(format!("../{font}"));
+    if !path.exists() {
+        eprintln!("Warning: OG image font not found: {}", path.display());
+        return Ok(None);
+    }
+
+    OgImageRenderer::load(&path, &config).map(Some)
+}
+
 /// Get deployment configuration from project.toml
 pub fn get_deployment_config() -> (String, String) {
     #[cfg(feature = "cli-tools")]
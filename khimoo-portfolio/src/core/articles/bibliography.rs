@@ -0,0 +1,608 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// How citations and reference labels are rendered
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum CitationStyle {
+    /// `[1, p. 12]`, numbered by first citation in each article
+    #[default]
+    Numeric,
+    /// `(Munkres 2000, p. 12)`
+    AuthorYear,
+}
+
+/// `[bibliography]` section of project.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct BibliographyConfig {
+    /// BibTeX file relative to the project root
+    #[serde(default)]
+    pub file: Option<String>,
+    #[serde(default)]
+    pub style: CitationStyle,
+}
+
+impl BibliographyConfig {
+    /// Read the `[bibliography]` section of project.toml, falling back to no bibliography
+    pub fn from_project_toml(content: &str) -> Self {
+        toml::from_str::<toml::Value>(content)
+            .ok()
+            .and_then(|config| config.get("bibliography").cloned())
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Errors raised while parsing a BibTeX file
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum BibtexError {
+    #[error("Unterminated entry starting at line {0}")]
+    Unterminated(usize),
+    #[error("Malformed entry at line {line}: {message}")]
+    Malformed { line: usize, message: String },
+}
+
+/// Single BibTeX entry with lower-cased field names and LaTeX markup removed
+#[derive(Debug, Clone, PartialEq)]
+pub struct BibEntry {
+    pub key: String,
+    pub entry_type: String,
+    pub fields: HashMap<String, String>,
+}
+
+impl BibEntry {
+    /// Get a field value by (lower-case) name
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .get(name)
+            .map(|value| value.as_str())
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Family names of the authors, or editors when there is no author
+    pub fn family_names(&self) -> Vec<String> {
+        let names = self.field("author").or_else(|| self.field("editor"));
+        names
+            .map(|names| {
+                split_names(names)
+                    .iter()
+                    .map(|name| family_name(name))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Short `Author Year` label used by the author-year style
+    pub fn author_year_label(&self) -> String {
+        let families = self.family_names();
+        let authors = match families.as_slice() {
+            [] => self.field("title").unwrap_or(&self.key).to_string(),
+            [one] => one.clone(),
+            [first, second] => format!("{first} and {second}"),
+            [first, ..] => format!("{first} et al."),
+        };
+        match self.field("year") {
+            Some(year) => format!("{authors} {year}"),
+            None => format!("{authors} n.d."),
+        }
+    }
+
+    /// Full reference text: `Authors (Year). Title. Container, pages.`
+    pub fn format_reference(&self) -> String {
+        let mut parts = Vec::new();
+
+        let authors = self
+            .field("author")
+            .or_else(|| self.field("editor"))
+            .map(|names| split_names(names).join(", "));
+        let year = self.field("year").unwrap_or("n.d.");
+        match authors {
+            Some(authors) => parts.push(format!("{authors} ({year})")),
+            None => parts.push(format!("({year})")),
+        }
+
+        if let Some(title) = self.field("title") {
+            parts.push(title.to_string());
+        }
+
+        let container = ["journal", "booktitle", "publisher", "school", "institution"]
+            .iter()
+            .find_map(|name| self.field(name));
+        let mut details = Vec::new();
+        if let Some(container) = container {
+            details.push(container.to_string());
+        }
+        if let Some(volume) = self.field("volume") {
+            match self.field("number") {
+                Some(number) => details.push(format!("{volume}({number})")),
+                None => details.push(volume.to_string()),
+            }
+        }
+        if let Some(pages) = self.field("pages") {
+            details.push(format!("pp. {pages}"));
+        }
+        if !details.is_empty() {
+            parts.push(details.join(", "));
+        }
+
+        format!("{}.", parts.join(". "))
+    }
+
+    /// Link for the reference: DOI when available, otherwise the `url` field
+    pub fn link(&self) -> Option<String> {
+        if let Some(doi) = self.field("doi") {
+            let doi = doi.trim_start_matches("https://doi.org/");
+            return Some(format!("https://doi.org/{doi}"));
+        }
+        self.field("url").map(|url| url.to_string())
+    }
+}
+
+/// Reference cited by an article, resolved at build time and stored in articles.json
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Reference {
+    pub key: String,
+    /// Label used in citations: `1` or `Munkres 2000`
+    pub label: String,
+    /// Full reference text shown in the references section
+    pub text: String,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// Parsed BibTeX database
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bibliography {
+    entries: HashMap<String, BibEntry>,
+}
+
+impl Bibliography {
+    /// Read and parse a BibTeX file
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read bibliography: {}", path.display()))?;
+        Self::parse(&source)
+            .with_context(|| format!("Failed to parse bibliography: {}", path.display()))
+    }
+
+    /// Parse BibTeX source; `@string` macros are expanded and `@comment`/`@preamble` skipped
+    pub fn parse(source: &str) -> Result<Self, BibtexError> {
+        let mut parser = BibtexParser::new(source);
+        let mut entries = HashMap::new();
+        let mut strings = HashMap::new();
+
+        while let Some(entry_type) = parser.next_entry_type() {
+            let entry_type = entry_type.to_lowercase();
+            let line = parser.line();
+            match entry_type.as_str() {
+                "comment" | "preamble" => parser.skip_group(line)?,
+                "string" => {
+                    let (name, value) = parser.string_definition(&strings, line)?;
+                    strings.insert(name, value);
+                }
+                _ => {
+                    let (key, fields) = parser.entry_body(&strings, line)?;
+                    entries.insert(
+                        key.clone(),
+                        BibEntry {
+                            key,
+                            entry_type,
+                            fields,
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Look up an entry by citation key
+    pub fn get(&self, key: &str) -> Option<&BibEntry> {
+        self.entries.get(key)
+    }
+
+    /// Check whether a citation key exists
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Iterate over all citation keys
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(|key| key.as_str())
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the bibliography has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Build the reference list for keys in citation order, skipping unknown keys
+    pub fn references_for(&self, keys: &[String], style: CitationStyle) -> Vec<Reference> {
+        keys.iter()
+            .filter_map(|key| self.get(key))
+            .enumerate()
+            .map(|(index, entry)| Reference {
+                key: entry.key.clone(),
+                label: match style {
+                    CitationStyle::Numeric => (index + 1).to_string(),
+                    CitationStyle::AuthorYear => entry.author_year_label(),
+                },
+                text: entry.format_reference(),
+                url: entry.link(),
+            })
+            .collect()
+    }
+}
+
+/// Split a BibTeX name list on top-level ` and `
+fn split_names(names: &str) -> Vec<String> {
+    names
+        .split(" and ")
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Family name from `Family, Given` or `Given Family`
+fn family_name(name: &str) -> String {
+    match name.split_once(',') {
+        Some((family, _)) => family.trim().to_string(),
+        None => name.split_whitespace().last().unwrap_or(name).to_string(),
+    }
+}
+
+/// Remove braces and common LaTeX escapes from a field value
+fn clean_latex(value: &str) -> String {
+    let mut cleaned = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' => {}
+            '\\' => match chars.peek() {
+                Some(&next) if "&%$#_{}".contains(next) => {
+                    cleaned.push(next);
+                    chars.next();
+                }
+                _ => {}
+            },
+            '~' => cleaned.push(' '),
+            _ => cleaned.push(c),
+        }
+    }
+
+    let cleaned = cleaned.replace("---", "—").replace("--", "–");
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Minimal recursive-descent reader for BibTeX source
+struct BibtexParser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> BibtexParser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    fn line(&self) -> usize {
+        self.source[..self.pos].matches('\n').count() + 1
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Advance to the next `@type` and return the type; text between entries is a comment
+    fn next_entry_type(&mut self) -> Option<&'a str> {
+        let offset = self.source[self.pos..].find('@')?;
+        self.pos += offset + 1;
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric()) {
+            self.bump();
+        }
+        Some(&self.source[start..self.pos])
+    }
+
+    fn expect_open(&mut self, line: usize) -> Result<char, BibtexError> {
+        self.skip_whitespace();
+        match self.bump() {
+            Some('{') => Ok('}'),
+            Some('(') => Ok(')'),
+            _ => Err(BibtexError::Malformed {
+                line,
+                message: "expected '{' or '('".to_string(),
+            }),
+        }
+    }
+
+    fn skip_group(&mut self, line: usize) -> Result<(), BibtexError> {
+        self.expect_open(line)?;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.bump() {
+                Some('{') | Some('(') => depth += 1,
+                Some('}') | Some(')') => depth -= 1,
+                Some(_) => {}
+                None => return Err(BibtexError::Unterminated(line)),
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && !"=,{}()\"#".contains(c))
+        {
+            self.bump();
+        }
+        &self.source[start..self.pos]
+    }
+
+    fn string_definition(
+        &mut self,
+        strings: &HashMap<String, String>,
+        line: usize,
+    ) -> Result<(String, String), BibtexError> {
+        let close = self.expect_open(line)?;
+        let name = self.identifier().to_lowercase();
+        self.skip_whitespace();
+        if self.bump() != Some('=') {
+            return Err(BibtexError::Malformed {
+                line,
+                message: format!("expected '=' after @string {name}"),
+            });
+        }
+        let value = self.value(strings, line)?;
+        self.skip_whitespace();
+        if self.bump() != Some(close) {
+            return Err(BibtexError::Unterminated(line));
+        }
+        Ok((name, value))
+    }
+
+    fn entry_body(
+        &mut self,
+        strings: &HashMap<String, String>,
+        line: usize,
+    ) -> Result<(String, HashMap<String, String>), BibtexError> {
+        let close = self.expect_open(line)?;
+        let key = self.identifier().to_string();
+        if key.is_empty() {
+            return Err(BibtexError::Malformed {
+                line,
+                message: "missing citation key".to_string(),
+            });
+        }
+
+        let mut fields = HashMap::new();
+        loop {
+            self.skip_whitespace();
+            match self.bump() {
+                Some(',') => {}
+                Some(c) if c == close => break,
+                Some(_) => {
+                    return Err(BibtexError::Malformed {
+                        line,
+                        message: format!("expected ',' in entry {key}"),
+                    })
+                }
+                None => return Err(BibtexError::Unterminated(line)),
+            }
+
+            self.skip_whitespace();
+            if self.peek() == Some(close) {
+                self.bump();
+                break;
+            }
+
+            let name = self.identifier().to_lowercase();
+            self.skip_whitespace();
+            if name.is_empty() || self.bump() != Some('=') {
+                return Err(BibtexError::Malformed {
+                    line,
+                    message: format!("expected 'field = value' in entry {key}"),
+                });
+            }
+            let value = self.value(strings, line)?;
+            fields.insert(name, clean_latex(&value));
+        }
+
+        Ok((key, fields))
+    }
+
+    /// Read a value: `{...}`, `"..."`, a number or a macro, joined with `#`
+    fn value(
+        &mut self,
+        strings: &HashMap<String, String>,
+        line: usize,
+    ) -> Result<String, BibtexError> {
+        let mut value = String::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('{') => {
+                    self.bump();
+                    value.push_str(&self.delimited('}', line)?);
+                }
+                Some('"') => {
+                    self.bump();
+                    value.push_str(&self.delimited('"', line)?);
+                }
+                Some(_) => {
+                    let word = self.identifier();
+                    if word.is_empty() {
+                        return Err(BibtexError::Malformed {
+                            line,
+                            message: "expected a field value".to_string(),
+                        });
+                    }
+                    match strings.get(&word.to_lowercase()) {
+                        Some(expanded) => value.push_str(expanded),
+                        None => value.push_str(word),
+                    }
+                }
+                None => return Err(BibtexError::Unterminated(line)),
+            }
+
+            self.skip_whitespace();
+            if self.peek() == Some('#') {
+                self.bump();
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Read until the matching terminator, keeping nested braces
+    fn delimited(&mut self, terminator: char, line: usize) -> Result<String, BibtexError> {
+        let start = self.pos;
+        let mut depth = 0;
+        loop {
+            match self.bump() {
+                Some('{') => depth += 1,
+                Some('}') if depth > 0 => depth -= 1,
+                Some(c) if c == terminator && depth == 0 => {
+                    return Ok(self.source[start..self.pos - c.len_utf8()].to_string());
+                }
+                Some(_) => {}
+                None => return Err(BibtexError::Unterminated(line)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+@string{ams = "American Mathematical Society"}
+
+Free text between entries is ignored.
+
+@book{munkres2000,
+  author    = {Munkres, James R.},
+  title     = {Topology},
+  edition   = {2},
+  publisher = {Prentice Hall},
+  year      = 2000
+}
+
+@article{eilenberg1945,
+  author  = "Eilenberg, Samuel and Mac Lane, Saunders",
+  title   = "General Theory of Natural Equivalences",
+  journal = "Transactions of the " # ams,
+  volume  = {58},
+  number  = {2},
+  pages   = {231--294},
+  year    = {1945},
+  doi     = {10.2307/1990284},
+}
+
+@comment{ @book{ignored, title = {Nope} } }
+"#;
+
+    #[test]
+    fn test_parse_bibtex_entries() {
+        let bibliography = Bibliography::parse(SAMPLE).unwrap();
+        assert_eq!(bibliography.len(), 2);
+        assert!(!bibliography.contains("ignored"));
+
+        let book = bibliography.get("munkres2000").unwrap();
+        assert_eq!(book.entry_type, "book");
+        assert_eq!(book.field("year"), Some("2000"));
+        assert_eq!(book.family_names(), vec!["Munkres"]);
+
+        let article = bibliography.get("eilenberg1945").unwrap();
+        assert_eq!(
+            article.field("journal"),
+            Some("Transactions of the American Mathematical Society")
+        );
+        assert_eq!(article.field("pages"), Some("231–294"));
+    }
+
+    #[test]
+    fn test_format_reference() {
+        let bibliography = Bibliography::parse(SAMPLE).unwrap();
+        let article = bibliography.get("eilenberg1945").unwrap();
+
+        assert_eq!(
+            article.format_reference(),
+            "Eilenberg, Samuel, Mac Lane, Saunders (1945). General Theory of Natural Equivalences. \
+             Transactions of the American Mathematical Society, 58(2), pp. 231–294."
+        );
+        assert_eq!(article.author_year_label(), "Eilenberg and Mac Lane 1945");
+        assert_eq!(
+            article.link().as_deref(),
+            Some("https://doi.org/10.2307/1990284")
+        );
+    }
+
+    #[test]
+    fn test_references_for_keys_in_citation_order() {
+        let bibliography = Bibliography::parse(SAMPLE).unwrap();
+        let keys = vec![
+            "eilenberg1945".to_string(),
+            "missing".to_string(),
+            "munkres2000".to_string(),
+        ];
+
+        let numeric = bibliography.references_for(&keys, CitationStyle::Numeric);
+        assert_eq!(numeric.len(), 2);
+        assert_eq!(numeric[0].label, "1");
+        assert_eq!(numeric[1].key, "munkres2000");
+        assert_eq!(numeric[1].label, "2");
+
+        let author_year = bibliography.references_for(&keys, CitationStyle::AuthorYear);
+        assert_eq!(author_year[1].label, "Munkres 2000");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Bibliography::parse("@book{key, title = {Open"),
+            Err(BibtexError::Unterminated(1))
+        );
+        assert!(matches!(
+            Bibliography::parse("@book{, title = {x}}"),
+            Err(BibtexError::Malformed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_config_from_project_toml() {
+        let config = BibliographyConfig::from_project_toml(
+            "[bibliography]\nfile = \"content/references.bib\"\nstyle = \"author-year\"\n",
+        );
+        assert_eq!(config.file.as_deref(), Some("content/references.bib"));
+        assert_eq!(config.style, CitationStyle::AuthorYear);
+
+        let missing = BibliographyConfig::from_project_toml("[paths]\n");
+        assert_eq!(missing, BibliographyConfig::default());
+    }
+}
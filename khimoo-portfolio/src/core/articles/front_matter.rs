@@ -0,0 +1,199 @@
+/// Syntax of an article's front-matter block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrontMatterFormat {
+    /// Between `---` lines (Obsidian, Jekyll)
+    Yaml,
+    /// Between `+++` lines (Hugo, Zola)
+    Toml,
+    /// A leading `{ ... }` object (Hugo)
+    Json,
+}
+
+/// Front-matter block split off the start of an article
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrontMatter<'a> {
+    pub format: FrontMatterFormat,
+    /// Text inside the delimiters (the whole object for JSON)
+    pub source: &'a str,
+    /// Markdown after the block, without leading blank lines
+    pub body: &'a str,
+}
+
+/// Errors raised while parsing a front-matter block
+#[derive(Debug, thiserror::Error)]
+pub enum FrontMatterError {
+    #[error("Invalid YAML front matter: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    #[error("Invalid TOML front matter: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("Invalid JSON front matter: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl<'a> FrontMatter<'a> {
+    /// Split the front matter off an article, detecting its format from the opening delimiter
+    ///
+    /// Without a complete block (no closing delimiter, or JSON that is not an object) the
+    /// article is treated as having no front matter.
+    pub fn split(content: &'a str) -> Option<Self> {
+        let content = content.trim_start_matches('\u{feff}');
+        let first_line = content.lines().next()?.trim_end();
+
+        match first_line {
+            "---" => Self::split_delimited(content, "---", FrontMatterFormat::Yaml),
+            "+++" => Self::split_delimited(content, "+++", FrontMatterFormat::Toml),
+            _ if content.starts_with('{') => Self::split_json(content),
+            _ => None,
+        }
+    }
+
+    fn split_delimited(
+        content: &'a str,
+        delimiter: &str,
+        format: FrontMatterFormat,
+    ) -> Option<Self> {
+        let after_open = &content[content.find('\n')? + 1..];
+
+        let mut offset = 0;
+        for line in after_open.split_inclusive('\n') {
+            if line.trim_end() == delimiter {
+                return Some(Self {
+                    format,
+                    source: &after_open[..offset],
+                    body: trim_leading_blank_lines(&after_open[offset + line.len()..]),
+                });
+            }
+            offset += line.len();
+        }
+        None
+    }
+
+    fn split_json(content: &'a str) -> Option<Self> {
+        let mut objects =
+            serde_json::Deserializer::from_str(content).into_iter::<serde_json::Value>();
+        if !matches!(objects.next(), Some(Ok(serde_json::Value::Object(_)))) {
+            return None;
+        }
+
+        let end = objects.byte_offset();
+        let rest = &content[end..];
+        // The object must end its line so `{.class}` attribute syntax is never taken for JSON
+        let rest_of_line = rest.split('\n').next().unwrap_or(rest);
+        if !rest_of_line.trim().is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            format: FrontMatterFormat::Json,
+            source: &content[..end],
+            body: trim_leading_blank_lines(rest),
+        })
+    }
+
+    /// Parse the block into a YAML value, so every format goes through the same deserialisation
+    pub fn parse(&self) -> Result<serde_yaml::Value, FrontMatterError> {
+        Ok(match self.format {
+            // An empty block is an empty mapping rather than null
+            FrontMatterFormat::Yaml if self.source.trim().is_empty() => {
+                serde_yaml::Value::Mapping(Default::default())
+            }
+            FrontMatterFormat::Yaml => serde_yaml::from_str(self.source)?,
+            FrontMatterFormat::Toml => toml_to_yaml(toml::from_str(self.source)?),
+            FrontMatterFormat::Json => {
+                serde_yaml::to_value(serde_json::from_str::<serde_json::Value>(self.source)?)?
+            }
+        })
+    }
+}
+
+/// Markdown of an article without its front-matter block
+pub fn strip_front_matter(content: &str) -> &str {
+    match FrontMatter::split(content) {
+        Some(front_matter) => front_matter.body,
+        None => content,
+    }
+}
+
+fn trim_leading_blank_lines(text: &str) -> &str {
+    let mut rest = text;
+    while let Some(index) = rest.find('\n') {
+        if !rest[..index].trim().is_empty() {
+            break;
+        }
+        rest = &rest[index + 1..];
+    }
+    if rest.trim().is_empty() {
+        ""
+    } else {
+        rest
+    }
+}
+
+/// TOML dates become strings, since `ArticleMetadata` reads dates as text
+fn toml_to_yaml(value: toml::Value) -> serde_yaml::Value {
+    use serde_yaml::Value as Yaml;
+
+    match value {
+        toml::Value::String(text) => Yaml::String(text),
+        toml::Value::Integer(number) => Yaml::Number(number.into()),
+        toml::Value::Float(number) => Yaml::Number(number.into()),
+        toml::Value::Boolean(flag) => Yaml::Bool(flag),
+        toml::Value::Datetime(datetime) => Yaml::String(datetime.to_string()),
+        toml::Value::Array(items) => Yaml::Sequence(items.into_iter().map(toml_to_yaml).collect()),
+        toml::Value::Table(table) => Yaml::Mapping(
+            table
+                .into_iter()
+                .map(|(key, value)| (Yaml::String(key), toml_to_yaml(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_detects_format() {
+        let yaml = FrontMatter::split("---\ntitle: A\n---\n\n# Body\n").unwrap();
+        assert_eq!(yaml.format, FrontMatterFormat::Yaml);
+        assert_eq!(yaml.source, "title: A\n");
+        assert_eq!(yaml.body, "# Body\n");
+
+        let toml = FrontMatter::split("+++\r\ntitle = \"A\"\r\n+++\r\nBody").unwrap();
+        assert_eq!(toml.format, FrontMatterFormat::Toml);
+        assert_eq!(toml.body, "Body");
+
+        let json = FrontMatter::split("{\n  \"title\": \"A\"\n}\nBody\n").unwrap();
+        assert_eq!(json.format, FrontMatterFormat::Json);
+        assert_eq!(json.body, "Body\n");
+    }
+
+    #[test]
+    fn test_no_front_matter() {
+        assert!(FrontMatter::split("# Title\n\n---\n").is_none());
+        assert!(FrontMatter::split("---\ntitle: unterminated\n").is_none());
+        assert!(FrontMatter::split("{.lead} Not JSON\n").is_none());
+        assert_eq!(strip_front_matter("# Title\n"), "# Title\n");
+        assert_eq!(strip_front_matter("---\n---\nBody"), "Body");
+    }
+
+    #[test]
+    fn test_parse_formats_alike() {
+        let expected: serde_yaml::Value =
+            serde_yaml::from_str("title: A\ntags: [rust]\nimportance: 4\ncreated_at: '2024-05-01'")
+                .unwrap();
+
+        let yaml = "---\ntitle: A\ntags: [rust]\nimportance: 4\ncreated_at: '2024-05-01'\n---\n";
+        let toml =
+            "+++\ntitle = \"A\"\ntags = [\"rust\"]\nimportance = 4\ncreated_at = 2024-05-01\n+++\n";
+        let json = "{\"title\": \"A\", \"tags\": [\"rust\"], \"importance\": 4, \"created_at\": \"2024-05-01\"}\n";
+        for content in [yaml, toml, json] {
+            let parsed = FrontMatter::split(content).unwrap().parse().unwrap();
+            assert_eq!(parsed, expected, "{content}");
+        }
+
+        let broken = FrontMatter::split("+++\ntitle = \n+++\n").unwrap();
+        assert!(matches!(broken.parse(), Err(FrontMatterError::Toml(_))));
+    }
+}
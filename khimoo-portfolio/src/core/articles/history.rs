@@ -0,0 +1,477 @@
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::metadata::ArticleMetadata;
+use super::revisions::{Revision, MAX_DIFF_BYTES};
+
+/// Separates commits and header fields in `git log` output
+const RECORD_SEPARATOR: char = '\u{1e}';
+const FIELD_SEPARATOR: char = '\u{1f}';
+
+/// Where derived article dates came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateSource {
+    /// First and last commit touching the file, following renames
+    Git,
+    /// File modification time, used when the file has no git history
+    Filesystem,
+}
+
+/// Creation and last-update times derived for an article file
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDates {
+    pub created: DateTime<FixedOffset>,
+    pub updated: DateTime<FixedOffset>,
+    pub source: DateSource,
+}
+
+/// Looks up article dates in the content repository's git history
+pub struct FileHistory {
+    repo_root: Option<PathBuf>,
+}
+
+impl FileHistory {
+    /// Find the git repository containing `dir`; history lookups are skipped when there is none
+    pub fn discover(dir: &Path) -> Self {
+        let repo_root = Command::new("git")
+            .arg("rev-parse")
+            .arg("--show-toplevel")
+            .current_dir(dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()));
+
+        Self { repo_root }
+    }
+
+    /// Repository root, if the articles live in a git repository
+    pub fn repo_root(&self) -> Option<&Path> {
+        self.repo_root.as_deref()
+    }
+
+    /// Dates for a file from git history, falling back to its modification time
+    pub fn dates_for(&self, path: &Path) -> Option<FileDates> {
+        self.git_dates(path).or_else(|| filesystem_dates(path))
+    }
+
+    fn git_dates(&self, path: &Path) -> Option<FileDates> {
+        self.repo_root.as_ref()?;
+        let dir = path.parent()?;
+        let file_name = path.file_name()?;
+
+        let output = Command::new("git")
+            .args(["log", "--follow", "--format=%aI", "--"])
+            .arg(file_name)
+            .current_dir(dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+
+        let (created, updated) = parse_git_log(&String::from_utf8_lossy(&output.stdout))?;
+        Some(FileDates {
+            created,
+            updated,
+            source: DateSource::Git,
+        })
+    }
+
+    /// Commits touching a file with their diffs, newest first, following renames
+    ///
+    /// Empty when the file is not tracked or there is no repository.
+    pub fn revisions_for(&self, path: &Path) -> Vec<Revision> {
+        let Some(dir) = path.parent().filter(|_| self.repo_root.is_some()) else {
+            return Vec::new();
+        };
+        let Some(file_name) = path.file_name() else {
+            return Vec::new();
+        };
+
+        Command::new("git")
+            .args([
+                "-c",
+                "core.quotePath=false",
+                "log",
+                "--follow",
+                "--patch",
+                "--no-color",
+                "--no-ext-diff",
+                "--format=%x1e%H%x1f%h%x1f%aI%x1f%s",
+                "--",
+            ])
+            .arg(file_name)
+            .current_dir(dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| parse_git_revisions(&String::from_utf8_lossy(&output.stdout)))
+            .unwrap_or_default()
+    }
+
+    /// Commits from the first one where the article could be published, newest first
+    ///
+    /// Earlier commits would expose draft text, so they are left out and the oldest commit
+    /// kept shows the file as it was then, as if newly added.
+    pub fn published_revisions_for(
+        &self,
+        path: &Path,
+        is_publishable: impl Fn(&str) -> bool,
+    ) -> Vec<Revision> {
+        let mut revisions = self.revisions_for(path);
+        let first = revisions.iter().rposition(|revision| {
+            self.content_at(revision)
+                .is_some_and(|(_, content)| is_publishable(&content))
+        });
+        let Some(first) = first else {
+            return Vec::new();
+        };
+
+        revisions.truncate(first + 1);
+        if let Some((path, content)) = self.content_at(&revisions[first]) {
+            let (diff, diff_truncated) =
+                truncate_diff(&added_file_diff(&path, &content), MAX_DIFF_BYTES);
+            revisions[first].diff = diff;
+            revisions[first].diff_truncated = diff_truncated;
+        }
+        revisions
+    }
+
+    /// Repository path and content of the article file as of a revision
+    fn content_at(&self, revision: &Revision) -> Option<(String, String)> {
+        let root = self.repo_root.as_ref()?;
+        let path = diff_new_path(&revision.diff)?;
+        let output = Command::new("git")
+            .arg("show")
+            .arg(format!("{}:{path}", revision.hash))
+            .current_dir(root)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+        Some((
+            path.to_string(),
+            String::from_utf8_lossy(&output.stdout).into_owned(),
+        ))
+    }
+}
+
+/// Path (from the repository root) of the file after a commit, from its diff header
+fn diff_new_path(diff: &str) -> Option<&str> {
+    diff.lines()
+        .take_while(|line| !line.starts_with("@@"))
+        .find_map(|line| {
+            // Pure renames have no `+++` line
+            line.strip_prefix("+++ b/")
+                .or_else(|| line.strip_prefix("rename to "))
+        })
+}
+
+/// Unified diff adding `content` as a new file at `path`
+fn added_file_diff(path: &str, content: &str) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut diff = format!(
+        "diff --git a/{path} b/{path}\nnew file mode 100644\n--- /dev/null\n+++ b/{path}\n@@ -0,0 +1,{} @@",
+        lines.len()
+    );
+    for line in lines {
+        diff.push_str("\n+");
+        diff.push_str(line);
+    }
+    diff
+}
+
+/// Parse `git log --patch` output written with the record/field separator format
+fn parse_git_revisions(output: &str) -> Vec<Revision> {
+    output
+        .split(RECORD_SEPARATOR)
+        .filter_map(|record| {
+            let (header, diff) = record.split_once('\n').unwrap_or((record, ""));
+            let mut fields = header.split(FIELD_SEPARATOR);
+            let hash = fields.next()?.trim();
+            let short_hash = fields.next()?;
+            let date = fields.next()?;
+            let message = fields.next().unwrap_or_default();
+            if hash.is_empty() {
+                return None;
+            }
+
+            let (diff, diff_truncated) = truncate_diff(diff.trim_matches('\n'), MAX_DIFF_BYTES);
+            Some(Revision {
+                hash: hash.to_string(),
+                short_hash: short_hash.to_string(),
+                date: date.to_string(),
+                message: message.to_string(),
+                diff,
+                diff_truncated,
+            })
+        })
+        .collect()
+}
+
+/// Cut a diff at the last whole line that fits in `max_bytes`
+fn truncate_diff(diff: &str, max_bytes: usize) -> (String, bool) {
+    if diff.len() <= max_bytes {
+        return (diff.to_string(), false);
+    }
+
+    let mut end = max_bytes;
+    while !diff.is_char_boundary(end) {
+        end -= 1;
+    }
+    let end = diff[..end].rfind('\n').unwrap_or(end);
+    (diff[..end].to_string(), true)
+}
+
+/// Oldest and newest dates from `git log --format=%aI` output (newest first)
+fn parse_git_log(output: &str) -> Option<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    let dates: Vec<_> = output
+        .lines()
+        .filter_map(|line| DateTime::parse_from_rfc3339(line.trim()).ok())
+        .collect();
+
+    let updated = *dates.first()?;
+    let created = *dates.last()?;
+    Some((created, updated))
+}
+
+fn filesystem_dates(path: &Path) -> Option<FileDates> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let updated: DateTime<FixedOffset> =
+        DateTime::<chrono::Utc>::from(metadata.modified().ok()?).into();
+    // Birth time is not available on every filesystem
+    let created = metadata
+        .created()
+        .ok()
+        .map(|created| DateTime::<chrono::Utc>::from(created).into())
+        .unwrap_or(updated);
+
+    Some(FileDates {
+        created,
+        updated,
+        source: DateSource::Filesystem,
+    })
+}
+
+/// Fill missing `created_at`/`updated_at` from derived dates
+///
+/// A git-derived `updated_at` also replaces an older front-matter value, since authors rarely
+/// bump it by hand; `prefer_front_matter` keeps whatever the front matter says.
+/// Filesystem times only ever fill gaps, because a fresh clone resets them.
+pub fn apply_file_dates(
+    metadata: &mut ArticleMetadata,
+    dates: &FileDates,
+    prefer_front_matter: bool,
+) {
+    let format = |date: &DateTime<FixedOffset>| date.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let parse = |value: &Option<String>| {
+        value
+            .as_deref()
+            .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+    };
+
+    if metadata.created_at.is_none() {
+        metadata.created_at = Some(format(&dates.created));
+    }
+
+    let override_updated = !prefer_front_matter
+        && dates.source == DateSource::Git
+        && parse(&metadata.updated_at).is_some_and(|updated_at| updated_at < dates.updated);
+    if metadata.updated_at.is_none() || override_updated {
+        metadata.updated_at = Some(format(&dates.updated));
+    }
+
+    // Never report an update before creation
+    if let (Some(created_at), Some(updated_at)) =
+        (parse(&metadata.created_at), parse(&metadata.updated_at))
+    {
+        if updated_at < created_at {
+            metadata.updated_at = metadata.created_at.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    fn git_dates() -> FileDates {
+        FileDates {
+            created: date("2024-01-10T12:00:00+09:00"),
+            updated: date("2024-03-05T08:30:00+09:00"),
+            source: DateSource::Git,
+        }
+    }
+
+    #[test]
+    fn test_parse_git_log() {
+        let output =
+            "2024-03-05T08:30:00+09:00\n2024-02-01T10:00:00+09:00\n2024-01-10T12:00:00+09:00\n";
+        let (created, updated) = parse_git_log(output).unwrap();
+        assert_eq!(created, date("2024-01-10T12:00:00+09:00"));
+        assert_eq!(updated, date("2024-03-05T08:30:00+09:00"));
+
+        assert!(parse_git_log("").is_none());
+    }
+
+    #[test]
+    fn test_parse_git_revisions() {
+        let output = "\u{1e}a1b2c3d4\u{1f}a1b2c3d\u{1f}2024-02-01T00:00:00+09:00\u{1f}Fix typo\n\n\
+                      diff --git a/note.md b/note.md\n@@ -1 +1 @@\n-teh\n+the\n\
+                      \u{1e}e5f6a7b8\u{1f}e5f6a7b\u{1f}2024-01-10T12:00:00+09:00\u{1f}\n";
+        let revisions = parse_git_revisions(output);
+
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].short_hash, "a1b2c3d");
+        assert_eq!(revisions[0].message, "Fix typo");
+        assert_eq!(
+            revisions[0].diff,
+            "diff --git a/note.md b/note.md\n@@ -1 +1 @@\n-teh\n+the"
+        );
+        assert_eq!(revisions[1].date, "2024-01-10T12:00:00+09:00");
+        assert_eq!(revisions[1].message, "");
+        assert!(revisions[1].diff.is_empty());
+
+        assert!(parse_git_revisions("").is_empty());
+    }
+
+    #[test]
+    fn test_diff_paths() {
+        let diff = "diff --git a/old.md b/notes/位相.md\nrename from old.md\n\
+                    --- a/old.md\n+++ b/notes/位相.md\n@@ -1 +1 @@\n-a\n++++ b/not-a-header";
+        assert_eq!(diff_new_path(diff), Some("notes/位相.md"));
+        assert_eq!(
+            diff_new_path("diff --git a/a.md b/b.md\nrename from a.md\nrename to b.md"),
+            Some("b.md")
+        );
+        assert_eq!(diff_new_path(""), None);
+
+        assert_eq!(
+            added_file_diff("note.md", "---\ntitle: A\n---\nBody\n"),
+            "diff --git a/note.md b/note.md\nnew file mode 100644\n--- /dev/null\n+++ b/note.md\n\
+             @@ -0,0 +1,4 @@\n+---\n+title: A\n+---\n+Body"
+        );
+    }
+
+    #[test]
+    fn test_truncate_diff_keeps_whole_lines() {
+        assert_eq!(truncate_diff("+a\n+b", 10), ("+a\n+b".to_string(), false));
+        assert_eq!(
+            truncate_diff("+abc\n+déf\n+ghi", 9),
+            ("+abc".to_string(), true)
+        );
+    }
+
+    #[test]
+    fn test_apply_fills_missing_dates() {
+        let mut metadata = ArticleMetadata::default();
+        apply_file_dates(&mut metadata, &git_dates(), false);
+
+        assert_eq!(
+            metadata.created_at.as_deref(),
+            Some("2024-01-10T12:00:00+09:00")
+        );
+        assert_eq!(
+            metadata.updated_at.as_deref(),
+            Some("2024-03-05T08:30:00+09:00")
+        );
+    }
+
+    #[test]
+    fn test_apply_respects_front_matter_precedence() {
+        let mut metadata = ArticleMetadata {
+            created_at: Some("2023-12-24T00:00:00+09:00".to_string()),
+            updated_at: Some("2024-01-20T00:00:00+09:00".to_string()),
+            ..ArticleMetadata::default()
+        };
+
+        let mut preferred = metadata.clone();
+        apply_file_dates(&mut preferred, &git_dates(), true);
+        assert_eq!(preferred, metadata);
+
+        // By default a newer commit wins over a stale updated_at
+        apply_file_dates(&mut metadata, &git_dates(), false);
+        assert_eq!(
+            metadata.created_at.as_deref(),
+            Some("2023-12-24T00:00:00+09:00")
+        );
+        assert_eq!(
+            metadata.updated_at.as_deref(),
+            Some("2024-03-05T08:30:00+09:00")
+        );
+    }
+
+    #[test]
+    fn test_filesystem_dates_only_fill_gaps() {
+        let mut metadata = ArticleMetadata {
+            updated_at: Some("2024-01-20T00:00:00+09:00".to_string()),
+            ..ArticleMetadata::default()
+        };
+        let dates = FileDates {
+            source: DateSource::Filesystem,
+            ..git_dates()
+        };
+
+        apply_file_dates(&mut metadata, &dates, false);
+        assert_eq!(
+            metadata.updated_at.as_deref(),
+            Some("2024-01-20T00:00:00+09:00")
+        );
+        assert_eq!(
+            metadata.created_at.as_deref(),
+            Some("2024-01-10T12:00:00+09:00")
+        );
+    }
+
+    #[test]
+    fn test_git_history_follows_renames() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str], date: &str| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .env("GIT_AUTHOR_DATE", date)
+                .env("GIT_COMMITTER_DATE", date)
+                .env("GIT_AUTHOR_NAME", "test")
+                .env("GIT_AUTHOR_EMAIL", "test@example.com")
+                .env("GIT_COMMITTER_NAME", "test")
+                .env("GIT_COMMITTER_EMAIL", "test@example.com")
+                .output()
+                .map(|output| output.status.success())
+                .unwrap_or(false)
+        };
+
+        // Skip when git is not installed
+        if !git(&["init", "-q"], "2024-01-01T00:00:00Z") {
+            return;
+        }
+        std::fs::write(
+            dir.path().join("old.md"),
+            "# Note\n\nfirst draft of the note\n",
+        )
+        .unwrap();
+        git(&["add", "."], "2024-01-10T12:00:00+09:00");
+        git(&["commit", "-q", "-m", "add"], "2024-01-10T12:00:00+09:00");
+        git(&["mv", "old.md", "new.md"], "2024-02-01T00:00:00+09:00");
+        git(
+            &["commit", "-q", "-m", "rename"],
+            "2024-02-01T00:00:00+09:00",
+        );
+
+        let history = FileHistory::discover(dir.path());
+        assert!(history.repo_root().is_some());
+
+        let dates = history.dates_for(&dir.path().join("new.md")).unwrap();
+        assert_eq!(dates.source, DateSource::Git);
+        assert_eq!(dates.created, date("2024-01-10T12:00:00+09:00"));
+        assert_eq!(dates.updated, date("2024-02-01T00:00:00+09:00"));
+
+        let revisions = history.revisions_for(&dir.path().join("new.md"));
+        let messages: Vec<_> = revisions.iter().map(|r| r.message.as_str()).collect();
+        assert_eq!(messages, ["rename", "add"]);
+        assert!(revisions[1].diff.contains("+first draft of the note"));
+    }
+}
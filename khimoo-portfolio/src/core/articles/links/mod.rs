@@ -1,8 +1,10 @@
 pub mod extractor;
+pub mod resolver;
 pub mod validator;
 
 // Re-export types
 pub use extractor::{ExtractedLink, LinkExtractor, LinkType};
+pub use resolver::{article_link_path, join_link_path, link_base, LinkResolver};
 pub use validator::{
     LinkValidator, ProcessedArticleRef, ValidationError, ValidationErrorType, ValidationReport,
     ValidationSummary,
@@ -0,0 +1,198 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::extractor::{ExtractedLink, LinkType};
+use crate::core::articles::slugs::SlugIndex;
+
+/// Path part of a link to another article, as written (`./compactness.md` in
+/// `./compactness.md#proof`); `None` for external, absolute, in-page and `scheme:` links
+pub fn article_link_path(destination: &str) -> Option<&str> {
+    let destination = destination.trim();
+    let is_other =
+        destination.is_empty() || destination.starts_with(['#', '/']) || destination.contains(':');
+    if is_other {
+        return None;
+    }
+    let path = destination.split('#').next().unwrap_or(destination);
+    (!path.is_empty()).then_some(path)
+}
+
+/// Directory that relative links in an article are resolved from: the directory of its
+/// file below the articles directory (`notes/topology` for `notes/topology/compactness.md`)
+pub fn link_base(relative_file_path: &str) -> &str {
+    relative_file_path
+        .rsplit_once('/')
+        .map_or("", |(directory, _)| directory)
+}
+
+/// Join a link path onto `base`, dropping `.md` and resolving `.` and `..`
+///
+/// `None` when the path climbs above the articles directory.
+pub fn join_link_path(base: &str, path: &str) -> Option<String> {
+    let path = path.strip_suffix(".md").unwrap_or(path);
+    let mut segments: Vec<&str> = base.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+    (!segments.is_empty()).then(|| segments.join("/"))
+}
+
+/// Resolves link destinations written in articles to canonical slugs
+///
+/// A path is tried relative to the linking article's directory first, then from the
+/// articles directory (`notes/topology/compactness` works anywhere), and a bare name last
+/// matches the one article whose slug or alias ends in it. Paths starting with `./` or
+/// `../` are only resolved relative to the article.
+#[derive(Debug, Clone, Default)]
+pub struct LinkResolver {
+    index: SlugIndex,
+    /// Last slug segment → canonical slug, or `None` when several articles share it
+    stems: HashMap<String, Option<String>>,
+}
+
+impl LinkResolver {
+    pub fn new(index: SlugIndex) -> Self {
+        let mut stems: HashMap<String, Option<String>> = HashMap::new();
+        for (name, slug) in index.names() {
+            let stem = name.rsplit('/').next().unwrap_or(name).to_string();
+            stems
+                .entry(stem)
+                .and_modify(|existing| {
+                    if existing.as_deref() != Some(slug) {
+                        *existing = None;
+                    }
+                })
+                .or_insert_with(|| Some(slug.to_string()));
+        }
+        Self { index, stems }
+    }
+
+    /// Canonical slug for a slug or alias named from the articles directory
+    pub fn resolve_slug(&self, slug: &str) -> Option<&str> {
+        self.index.resolve(slug)
+    }
+
+    /// Canonical slug a link destination in an article under `base` points to
+    pub fn resolve(&self, base: &str, destination: &str) -> Option<&str> {
+        let path = article_link_path(destination)?;
+        let relative = join_link_path(base, path).and_then(|joined| self.index.resolve(&joined));
+        if relative.is_some() || path.starts_with("./") || path.starts_with("../") {
+            return relative;
+        }
+
+        let path = path.strip_suffix(".md").unwrap_or(path);
+        self.index.resolve(path).or_else(|| {
+            self.stems
+                .get(path)
+                .and_then(|slug| slug.as_deref())
+                .filter(|_| !path.contains('/'))
+        })
+    }
+
+    /// Resolved targets of an article's internal links, keyed by the path as written
+    pub fn link_targets(&self, base: &str, links: &[ExtractedLink]) -> BTreeMap<String, String> {
+        links
+            .iter()
+            .filter(|link| link.link_type == LinkType::MarkdownLink)
+            .filter_map(|link| {
+                let path = article_link_path(&link.target_slug)?;
+                let slug = self.resolve(base, &link.target_slug)?;
+                Some((path.to_string(), slug.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver() -> LinkResolver {
+        let none = Vec::new();
+        let aliases = vec!["位相空間".to_string()];
+        let (index, _) = SlugIndex::build([
+            ("notes/topology", &none),
+            ("notes/topology/compactness", &aliases),
+            ("notes/rust/ownership", &none),
+            ("notes/foo", &none),
+            ("about", &none),
+            ("notes/rust/index-notes", &none),
+            ("drafts/index-notes", &none),
+        ]);
+        LinkResolver::new(index)
+    }
+
+    #[test]
+    fn test_resolves_sibling_links() {
+        let resolver = resolver();
+        let base = link_base("notes/topology/other.md");
+        assert_eq!(base, "notes/topology");
+        assert_eq!(
+            resolver.resolve(base, "compactness"),
+            Some("notes/topology/compactness")
+        );
+        assert_eq!(
+            resolver.resolve(base, "./compactness.md#proof"),
+            Some("notes/topology/compactness")
+        );
+        // index.md links from its own directory
+        assert_eq!(
+            resolver.resolve(link_base("notes/topology/index.md"), "compactness"),
+            Some("notes/topology/compactness")
+        );
+    }
+
+    #[test]
+    fn test_resolves_parent_links() {
+        let resolver = resolver();
+        let base = "notes/topology";
+        assert_eq!(resolver.resolve(base, "../foo"), Some("notes/foo"));
+        assert_eq!(
+            resolver.resolve(base, "../rust/ownership.md"),
+            Some("notes/rust/ownership")
+        );
+        assert_eq!(resolver.resolve(base, "../../../about"), None);
+        // Explicitly relative paths do not fall back to the root
+        assert_eq!(resolver.resolve("notes", "./about"), None);
+    }
+
+    #[test]
+    fn test_falls_back_to_root_and_unique_stem() {
+        let resolver = resolver();
+        let base = "notes/rust";
+        assert_eq!(
+            resolver.resolve(base, "notes/topology/compactness"),
+            Some("notes/topology/compactness")
+        );
+        assert_eq!(resolver.resolve(base, "about"), Some("about"));
+        assert_eq!(
+            resolver.resolve("", "compactness"),
+            Some("notes/topology/compactness")
+        );
+        assert_eq!(
+            resolver.resolve("", "位相空間"),
+            Some("notes/topology/compactness")
+        );
+        // Two articles end in `index-notes`; only the sibling one is found
+        assert_eq!(resolver.resolve("", "index-notes"), None);
+        assert_eq!(
+            resolver.resolve(base, "index-notes"),
+            Some("notes/rust/index-notes")
+        );
+        assert_eq!(resolver.resolve(base, "https://example.com"), None);
+        assert_eq!(resolver.resolve(base, "/article/about"), None);
+    }
+
+    #[test]
+    fn test_join_link_path() {
+        assert_eq!(join_link_path("a/b", "../c.md").as_deref(), Some("a/c"));
+        assert_eq!(join_link_path("", "./x").as_deref(), Some("x"));
+        assert_eq!(join_link_path("a", "../.."), None);
+        assert_eq!(join_link_path("a", ".."), None);
+    }
+}
@@ -2,8 +2,11 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
-use super::ExtractedLink;
+use super::{ExtractedLink, LinkResolver, LinkType};
+use crate::core::articles::bibliography::Bibliography;
 use crate::core::articles::metadata::ArticleMetadata;
+use crate::core::articles::schema::UnknownKey;
+use crate::core::articles::slugs::{SlugConflict, SlugIndex};
 
 /// Validation error types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -14,6 +17,8 @@ pub enum ValidationErrorType {
     InvalidMetadata,
     CircularReference,
     OrphanedArticle,
+    UnknownCitation,
+    DuplicateSlug,
 }
 
 /// Represents a validation error
@@ -34,6 +39,10 @@ pub struct ValidationSummary {
     pub total_links: usize,
     pub broken_links: usize,
     pub invalid_references: usize,
+    #[serde(default)]
+    pub unknown_citations: usize,
+    #[serde(default)]
+    pub duplicate_slugs: usize,
 }
 
 /// Complete validation report
@@ -53,19 +62,38 @@ pub struct ProcessedArticleRef {
     pub outbound_links: Vec<ExtractedLink>,
     pub inbound_links: Vec<ExtractedLink>,
     pub file_path: String,
+    /// Directory below the articles directory that relative links start from
+    pub link_base: String,
+    /// `[@key]` citation keys in order of first appearance
+    pub citation_keys: Vec<String>,
+    /// Front-matter keys that no field reads
+    pub unknown_keys: Vec<UnknownKey>,
 }
 
 /// Link validation system
 /// Provides centralized validation of internal links
 pub struct LinkValidator {
     existing_articles: HashSet<String>,
+    resolver: LinkResolver,
     article_map: HashMap<String, ProcessedArticleRef>,
+    known_citations: Option<HashSet<String>>,
+    slug_conflicts: Vec<SlugConflict>,
 }
 
 impl LinkValidator {
     /// Create a new link validator with article data
     pub fn new(articles: &[ProcessedArticleRef]) -> Self {
-        let existing_articles: HashSet<String> = articles.iter().map(|a| a.slug.clone()).collect();
+        // Aliases are valid link targets too
+        let existing_articles: HashSet<String> = articles
+            .iter()
+            .flat_map(|a| std::iter::once(&a.slug).chain(&a.metadata.aliases))
+            .cloned()
+            .collect();
+        let (index, slug_conflicts) = SlugIndex::build(
+            articles
+                .iter()
+                .map(|a| (a.slug.as_str(), &a.metadata.aliases)),
+        );
 
         let article_map: HashMap<String, ProcessedArticleRef> = articles
             .iter()
@@ -74,16 +102,25 @@ impl LinkValidator {
 
         Self {
             existing_articles,
+            resolver: LinkResolver::new(index),
             article_map,
+            known_citations: None,
+            slug_conflicts,
         }
     }
 
-    /// Validate all internal links across articles
+    /// Check `[@key]` citations against the keys of the configured bibliography
+    pub fn with_bibliography(mut self, bibliography: &Bibliography) -> Self {
+        self.known_citations = Some(bibliography.keys().map(|key| key.to_string()).collect());
+        self
+    }
+
+    /// Validate internal links written from the articles directory
     pub fn validate_internal_links(&self, links: &[ExtractedLink]) -> Vec<ValidationError> {
         let mut errors = Vec::new();
 
         for link in links {
-            if !self.existing_articles.contains(&link.target_slug) {
+            if self.resolver.resolve("", &link.target_slug).is_none() {
                 errors.push(ValidationError {
                     error_type: ValidationErrorType::BrokenLink,
                     source_article: "unknown".to_string(), // Will be set by caller
@@ -108,6 +145,18 @@ impl LinkValidator {
             errors.extend(article_errors);
         }
 
+        // Two articles claiming one URL means one of them is unreachable
+        for conflict in &self.slug_conflicts {
+            errors.push(ValidationError {
+                error_type: ValidationErrorType::DuplicateSlug,
+                source_article: conflict.claimed_by.join(", "),
+                target_reference: conflict.slug.clone(),
+                context: Some("slug or alias claimed by several articles".to_string()),
+                line_number: None,
+                suggestion: Some("set a distinct `slug:` or remove the alias".to_string()),
+            });
+        }
+
         // Generate summary statistics
         let summary = self.generate_summary(&errors);
 
@@ -122,9 +171,14 @@ impl LinkValidator {
     fn validate_article(&self, article: &ProcessedArticleRef) -> Result<Vec<ValidationError>> {
         let mut errors = Vec::new();
 
-        // Validate outbound links
+        // Validate outbound links the way the page will resolve them
         for link in &article.outbound_links {
-            if !self.existing_articles.contains(&link.target_slug) {
+            let is_broken = link.link_type == LinkType::MarkdownLink
+                && self
+                    .resolver
+                    .resolve(&article.link_base, &link.target_slug)
+                    .is_none();
+            if is_broken {
                 errors.push(ValidationError {
                     error_type: ValidationErrorType::BrokenLink,
                     source_article: article.slug.clone(),
@@ -150,6 +204,29 @@ impl LinkValidator {
             }
         }
 
+        // Validate citations against the bibliography
+        for key in &article.citation_keys {
+            let known = self
+                .known_citations
+                .as_ref()
+                .is_some_and(|keys| keys.contains(key));
+            if !known {
+                let context = if self.known_citations.is_some() {
+                    "not found in bibliography"
+                } else {
+                    "no bibliography configured in project.toml"
+                };
+                errors.push(ValidationError {
+                    error_type: ValidationErrorType::UnknownCitation,
+                    source_article: article.slug.clone(),
+                    target_reference: format!("@{key}"),
+                    context: Some(context.to_string()),
+                    line_number: None,
+                    suggestion: None,
+                });
+            }
+        }
+
         Ok(errors)
     }
 
@@ -172,11 +249,23 @@ impl LinkValidator {
             .filter(|e| matches!(e.error_type, ValidationErrorType::InvalidRelatedArticle))
             .count();
 
+        let unknown_citations = errors
+            .iter()
+            .filter(|e| matches!(e.error_type, ValidationErrorType::UnknownCitation))
+            .count();
+
+        let duplicate_slugs = errors
+            .iter()
+            .filter(|e| matches!(e.error_type, ValidationErrorType::DuplicateSlug))
+            .count();
+
         ValidationSummary {
             total_articles,
             total_links,
             broken_links,
             invalid_references,
+            unknown_citations,
+            duplicate_slugs,
         }
     }
 
@@ -204,6 +293,9 @@ mod tests {
             outbound_links: Vec::new(),
             inbound_links: Vec::new(),
             file_path: format!("{slug}.md"),
+            link_base: super::super::link_base(slug).to_string(),
+            citation_keys: Vec::new(),
+            unknown_keys: Vec::new(),
         }
     }
 
@@ -245,4 +337,56 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert_eq!(errors[0].target_reference, "missing-article");
     }
+
+    #[test]
+    fn test_validate_unknown_citations() {
+        let mut article = create_test_article("topology", "Topology");
+        article.citation_keys = vec!["munkres2000".to_string(), "missing".to_string()];
+        let bibliography =
+            Bibliography::parse("@book{munkres2000, title = {Topology}, year = 2000}").unwrap();
+
+        let report = LinkValidator::new(&[article.clone()])
+            .with_bibliography(&bibliography)
+            .validate_all()
+            .unwrap();
+        assert_eq!(report.summary.unknown_citations, 1);
+        assert_eq!(
+            report.errors[0].error_type,
+            ValidationErrorType::UnknownCitation
+        );
+        assert_eq!(report.errors[0].target_reference, "@missing");
+
+        // Without a bibliography every citation is unresolved
+        let report = LinkValidator::new(&[article]).validate_all().unwrap();
+        assert_eq!(report.summary.unknown_citations, 2);
+    }
+
+    #[test]
+    fn test_aliases_and_duplicate_slugs() {
+        let mut renamed = create_test_article("new-name", "Renamed");
+        renamed.metadata.aliases = vec!["old-name".to_string()];
+        let mut linking = create_test_article("linking", "Linking");
+        linking.outbound_links = vec![ExtractedLink {
+            target_slug: "old-name".to_string(),
+            link_type: super::super::extractor::LinkType::MarkdownLink,
+            original_text: "[old](old-name)".to_string(),
+            display_text: Some("old".to_string()),
+        }];
+
+        let report = LinkValidator::new(&[renamed.clone(), linking.clone()])
+            .validate_all()
+            .unwrap();
+        assert_eq!(report.summary.broken_links, 0);
+        assert_eq!(report.summary.duplicate_slugs, 0);
+
+        let clash = create_test_article("old-name", "Clash");
+        let report = LinkValidator::new(&[renamed, linking, clash])
+            .validate_all()
+            .unwrap();
+        assert_eq!(report.summary.duplicate_slugs, 1);
+        let error = &report.errors[0];
+        assert_eq!(error.error_type, ValidationErrorType::DuplicateSlug);
+        assert_eq!(error.target_reference, "old-name");
+        assert_eq!(error.source_article, "new-name, old-name");
+    }
 }
@@ -1,12 +1,47 @@
 use anyhow::{Context, Result};
-use chrono::DateTime;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use pulldown_cmark::Parser;
 use serde::{Deserialize, Serialize};
-use yaml_front_matter::{Document, YamlFrontMatter};
+
+use super::front_matter::FrontMatter;
+use super::schema::{unknown_keys, UnknownKey};
+use super::slugs::{validate_slug, validate_slug_path};
+use super::tags::{find_hashtags, validate_tag};
+use crate::core::render::citations::for_each_text_run;
+use crate::core::render::markdown_options;
+
+/// Publication status set with `status:` in front matter
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ArticleStatus {
+    /// Work in progress, never published
+    Draft,
+    #[default]
+    Published,
+    /// Reachable by URL but left out of the index, tag views and the home graph
+    Unlisted,
+}
+
+/// Where an article appears on the built site at a given time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Listed,
+    Unlisted,
+    /// Draft, scheduled for later or expired; excluded from the build
+    Hidden,
+}
 
 /// Article metadata structure with default values
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ArticleMetadata {
     pub title: String,
+    /// URL slug overriding the file name
+    pub slug: Option<String>,
+    /// Former slugs and alternative link targets that redirect to this article
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Summary for listings and link previews, instead of the first paragraph
+    pub description: Option<String>,
     #[serde(default)]
     pub home_display: bool,
     #[serde(default = "default_importance")]
@@ -18,14 +53,71 @@ pub struct ArticleMetadata {
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
     pub author_image: Option<String>,
+    /// Cover image (`/articles/img/...`), cut into card and hero sizes by `process-articles`
+    pub cover: Option<String>,
     /// Link to a specific tag view instead of article page
     pub hub_tag: Option<String>,
+    #[serde(default)]
+    pub status: ArticleStatus,
+    /// Article stays hidden until this time (RFC 3339)
+    pub publish_at: Option<String>,
+    /// Article is hidden again from this time (RFC 3339)
+    pub expires_at: Option<String>,
+    /// Keep the article out of the sitemap and ask search engines not to index it
+    #[serde(default)]
+    pub noindex: bool,
+}
+
+impl ArticleMetadata {
+    /// Resolve status and the publish window against the build clock (dates already normalised)
+    pub fn visibility_at(&self, now: DateTime<Utc>) -> Visibility {
+        if self.status == ArticleStatus::Draft {
+            return Visibility::Hidden;
+        }
+
+        let parse = |value: &Option<String>| {
+            value
+                .as_deref()
+                .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+                .map(|date| date.with_timezone(&Utc))
+        };
+        if parse(&self.publish_at).is_some_and(|publish_at| now < publish_at) {
+            return Visibility::Hidden;
+        }
+        if parse(&self.expires_at).is_some_and(|expires_at| now >= expires_at) {
+            return Visibility::Hidden;
+        }
+
+        match self.status {
+            ArticleStatus::Unlisted => Visibility::Unlisted,
+            _ => Visibility::Listed,
+        }
+    }
+
+    /// Whether the article belongs in listings (index, tag views, home graph)
+    pub fn is_listed(&self) -> bool {
+        self.status != ArticleStatus::Unlisted
+    }
+
+    /// Whether the article has an entry of its own in listings, feeds, search and related
+    /// articles: listed, and not standing in for a tag view
+    pub fn is_public(&self) -> bool {
+        self.hub_tag.is_none() && self.is_listed()
+    }
+
+    /// Whether search engines should index the article: listed and not opted out
+    pub fn is_indexable(&self) -> bool {
+        self.is_listed() && !self.noindex
+    }
 }
 
 impl Default for ArticleMetadata {
     fn default() -> Self {
         Self {
             title: "Untitled".to_string(),
+            slug: None,
+            aliases: Vec::new(),
+            description: None,
             home_display: false,
             importance: default_importance(),
             related_articles: Vec::new(),
@@ -33,7 +125,12 @@ impl Default for ArticleMetadata {
             created_at: None,
             updated_at: None,
             author_image: None,
+            cover: None,
             hub_tag: None,
+            status: ArticleStatus::default(),
+            publish_at: None,
+            expires_at: None,
+            noindex: false,
         }
     }
 }
@@ -42,37 +139,115 @@ fn default_importance() -> u8 {
     3
 }
 
+/// Naive datetime layouts accepted in front matter besides RFC 3339
+/// (`YYYY-MM-DD` and what Obsidian's date and date-time properties and templates produce)
+const NAIVE_DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+];
+
+/// Parse a front-matter date leniently; dates without an offset are taken in `offset`
+pub fn parse_front_matter_date(value: &str, offset: FixedOffset) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(date);
+    }
+
+    let naive = NAIVE_DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })?;
+
+    naive.and_local_timezone(offset).single()
+}
+
+/// Calendar date (`YYYY-MM-DD`) of a normalised timestamp, in the offset it was written with
+pub fn display_date(value: &str) -> Option<String> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// Offset for front-matter dates written without one, from `[dates] utc_offset` in project.toml
+fn configured_utc_offset() -> FixedOffset {
+    toml::from_str::<toml::Value>(crate::config::PROJECT_TOML)
+        .ok()
+        .and_then(|config| {
+            config
+                .get("dates")?
+                .get("utc_offset")?
+                .as_str()
+                .and_then(|offset| offset.parse::<FixedOffset>().ok())
+        })
+        .unwrap_or_else(|| FixedOffset::east_opt(0).expect("zero offset is valid"))
+}
+
 /// Metadata extractor for article processing
-pub struct MetadataExtractor;
+pub struct MetadataExtractor {
+    utc_offset: FixedOffset,
+}
 
 impl MetadataExtractor {
     /// Create a new metadata extractor
     pub fn new() -> Self {
-        Self
+        Self::with_utc_offset(configured_utc_offset())
     }
 
-    /// Parse front matter from markdown content using yaml-front-matter library
-    /// Returns (metadata, remaining_content)
-    pub fn extract_frontmatter(&self, content: &str) -> Result<(ArticleMetadata, String)> {
-        // Try to parse with yaml-front-matter
-        match YamlFrontMatter::parse(content) {
-            Ok(Document {
-                metadata,
-                content: markdown_content,
-            }) => {
-                // Parse metadata into ArticleMetadata struct
-                let metadata: ArticleMetadata = serde_yaml::from_value(metadata)
-                    .context("Failed to deserialize front matter metadata")?;
-
-                Ok((metadata, markdown_content))
-            }
-            Err(_) => {
-                // No front matter found, return default metadata and full content
-                Ok((ArticleMetadata::default(), content.to_string()))
+    /// Create a metadata extractor that reads offset-less dates in the given offset
+    pub fn with_utc_offset(utc_offset: FixedOffset) -> Self {
+        Self { utc_offset }
+    }
+
+    /// Rewrite every recognised date field as RFC 3339; unrecognised values are left for validation
+    pub fn normalize_dates(&self, metadata: &mut ArticleMetadata) {
+        for field in [
+            &mut metadata.created_at,
+            &mut metadata.updated_at,
+            &mut metadata.publish_at,
+            &mut metadata.expires_at,
+        ] {
+            if let Some(value) = field.as_mut() {
+                if let Some(date) = parse_front_matter_date(value, self.utc_offset) {
+                    *value = date.to_rfc3339_opts(SecondsFormat::Secs, true);
+                }
             }
         }
     }
 
+    /// Parse YAML (`---`), TOML (`+++`) or JSON front matter from markdown content
+    /// Returns (metadata, remaining_content)
+    pub fn extract_frontmatter(&self, content: &str) -> Result<(ArticleMetadata, String)> {
+        self.extract_frontmatter_checked(content)
+            .map(|(metadata, markdown_content, _)| (metadata, markdown_content))
+    }
+
+    /// Parse front matter like [`Self::extract_frontmatter`], also returning keys no field reads
+    pub fn extract_frontmatter_checked(
+        &self,
+        content: &str,
+    ) -> Result<(ArticleMetadata, String, Vec<UnknownKey>)> {
+        let Some(front_matter) = FrontMatter::split(content) else {
+            // No front matter found, return default metadata and full content
+            return Ok((ArticleMetadata::default(), content.to_string(), Vec::new()));
+        };
+
+        // Every format is read into a YAML value, then into ArticleMetadata
+        let metadata = front_matter.parse()?;
+        let unknown = unknown_keys(&metadata);
+        let mut metadata: ArticleMetadata = serde_yaml::from_value(metadata)
+            .context("Failed to deserialize front matter metadata")?;
+        self.normalize_dates(&mut metadata);
+
+        Ok((metadata, front_matter.body.to_string(), unknown))
+    }
+
     /// Extract title from markdown content (first H1 heading if no frontmatter title)
     pub fn extract_title(&self, content: &str) -> Option<String> {
         // Look for first H1 heading
@@ -85,19 +260,16 @@ impl MetadataExtractor {
         None
     }
 
-    /// Extract tags from content (hashtags or frontmatter)
+    /// Extract inline `#tags` from markdown body text (code is skipped), lowercased and sorted
     pub fn extract_tags(&self, content: &str) -> Vec<String> {
         let mut tags = Vec::new();
-
-        // Extract hashtags from content
-        for word in content.split_whitespace() {
-            if word.starts_with('#') && word.len() > 1 {
-                let tag = word[1..].trim_end_matches(|c: char| !c.is_alphanumeric());
-                if !tag.is_empty() {
-                    tags.push(tag.to_lowercase());
-                }
-            }
-        }
+        for_each_text_run(Parser::new_ext(content, markdown_options()), |text| {
+            tags.extend(
+                find_hashtags(text)
+                    .into_iter()
+                    .map(|tag| tag.to_lowercase()),
+            );
+        });
 
         tags.sort();
         tags.dedup();
@@ -138,15 +310,42 @@ impl MetadataExtractor {
             return Err(anyhow::anyhow!("Title cannot be empty"));
         }
 
-        // Validate datetime formats if present
-        if let Some(created_at) = &metadata.created_at {
-            DateTime::parse_from_rfc3339(created_at)
-                .context("Invalid created_at datetime format")?;
+        // Validate slug and aliases can be used in URLs
+        if let Some(slug) = &metadata.slug {
+            validate_slug(slug).map_err(|e| anyhow::anyhow!("Invalid slug: {e}"))?;
+        }
+        for alias in &metadata.aliases {
+            validate_slug_path(alias).map_err(|e| anyhow::anyhow!("Invalid alias: {e}"))?;
         }
 
-        if let Some(updated_at) = &metadata.updated_at {
-            DateTime::parse_from_rfc3339(updated_at)
-                .context("Invalid updated_at datetime format")?;
+        // Tags name tag pages and feed files
+        for tag in metadata.tags.iter().chain(&metadata.hub_tag) {
+            validate_tag(tag).map_err(|e| anyhow::anyhow!("Invalid tag: {e}"))?;
+        }
+
+        // Validate datetime formats if present
+        let parse = |name: &str, value: &Option<String>| -> Result<Option<DateTime<FixedOffset>>> {
+            match value {
+                Some(value) => parse_front_matter_date(value, self.utc_offset)
+                    .map(Some)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Invalid {name} datetime format: {value:?} (expected YYYY-MM-DD, YYYY-MM-DDTHH:mm or RFC 3339)"
+                        )
+                    }),
+                None => Ok(None),
+            }
+        };
+
+        parse("created_at", &metadata.created_at)?;
+        parse("updated_at", &metadata.updated_at)?;
+        let publish_at = parse("publish_at", &metadata.publish_at)?;
+        let expires_at = parse("expires_at", &metadata.expires_at)?;
+
+        if let (Some(publish_at), Some(expires_at)) = (publish_at, expires_at) {
+            if expires_at <= publish_at {
+                return Err(anyhow::anyhow!("expires_at must be later than publish_at"));
+            }
         }
 
         Ok(())
@@ -195,6 +394,24 @@ tags: ["rust", "web"]
         assert_eq!(result.1, "# Just content");
     }
 
+    #[test]
+    fn test_extract_toml_frontmatter() {
+        let extractor =
+            MetadataExtractor::with_utc_offset(FixedOffset::east_opt(9 * 3600).unwrap());
+        let content = "+++\ntitle = \"Hugo note\"\ntags = [\"rust\"]\ncreated_at = 2024-05-01\ndate = 2024-05-01\n+++\n\nBody\n";
+
+        let (metadata, body, unknown) = extractor.extract_frontmatter_checked(content).unwrap();
+        assert_eq!(metadata.title, "Hugo note");
+        assert_eq!(metadata.tags, vec!["rust".to_string()]);
+        assert_eq!(
+            metadata.created_at.as_deref(),
+            Some("2024-05-01T00:00:00+09:00")
+        );
+        assert_eq!(body, "Body\n");
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(unknown[0].key, "date");
+    }
+
     #[test]
     fn test_extract_title() {
         let extractor = MetadataExtractor::new();
@@ -211,6 +428,128 @@ tags: ["rust", "web"]
 
         let tags = extractor.extract_tags(content);
         assert_eq!(tags, vec!["programming", "rust", "webdev"]);
+
+        // Nested tags keep their hierarchy; headings, code and fragments are not tags
+        let content =
+            "# Title\n\nSee #Math/Topology and [top](#title).\n\n```\n#include <x>\n```\n";
+        assert_eq!(extractor.extract_tags(content), vec!["math/topology"]);
+    }
+
+    #[test]
+    fn test_extract_publication_fields() {
+        let extractor = MetadataExtractor::new();
+        let content = r#"---
+title: "Scheduled"
+status: unlisted
+publish_at: "2026-01-01T09:00:00+09:00"
+---
+body"#;
+
+        let (metadata, _) = extractor.extract_frontmatter(content).unwrap();
+        assert_eq!(metadata.status, ArticleStatus::Unlisted);
+        assert_eq!(
+            metadata.publish_at.as_deref(),
+            Some("2026-01-01T09:00:00+09:00")
+        );
+
+        let (metadata, _) = extractor
+            .extract_frontmatter("---\ntitle: x\n---\n")
+            .unwrap();
+        assert_eq!(metadata.status, ArticleStatus::Published);
+    }
+
+    #[test]
+    fn test_parse_front_matter_date_formats() {
+        let jst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let parse = |value| {
+            parse_front_matter_date(value, jst)
+                .map(|date| date.to_rfc3339_opts(SecondsFormat::Secs, true))
+        };
+
+        assert_eq!(
+            parse("2024-05-01").as_deref(),
+            Some("2024-05-01T00:00:00+09:00")
+        );
+        assert_eq!(
+            parse("2024-05-01T14:30").as_deref(),
+            Some("2024-05-01T14:30:00+09:00")
+        );
+        assert_eq!(
+            parse("2024-05-01 14:30").as_deref(),
+            Some("2024-05-01T14:30:00+09:00")
+        );
+        assert_eq!(
+            parse("2024-05-01T14:30:15").as_deref(),
+            Some("2024-05-01T14:30:15+09:00")
+        );
+        assert_eq!(
+            parse("2024-05-01T05:30:00Z").as_deref(),
+            Some("2024-05-01T05:30:00Z")
+        );
+        assert_eq!(
+            parse("2024-05-01T05:30:00+02:00").as_deref(),
+            Some("2024-05-01T05:30:00+02:00")
+        );
+        assert_eq!(parse("May 1, 2024"), None);
+        assert_eq!(parse("2024-13-01"), None);
+    }
+
+    #[test]
+    fn test_extract_frontmatter_normalizes_dates() {
+        let extractor =
+            MetadataExtractor::with_utc_offset(FixedOffset::east_opt(9 * 3600).unwrap());
+        let content =
+            "---\ntitle: Dated\ncreated_at: 2024-05-01\nupdated_at: 2024-06-02T08:15\n---\nbody";
+
+        let (metadata, _) = extractor.extract_frontmatter(content).unwrap();
+        assert_eq!(
+            metadata.created_at.as_deref(),
+            Some("2024-05-01T00:00:00+09:00")
+        );
+        assert_eq!(
+            metadata.updated_at.as_deref(),
+            Some("2024-06-02T08:15:00+09:00")
+        );
+        assert!(extractor.validate_metadata(&metadata).is_ok());
+        assert_eq!(
+            display_date(metadata.created_at.as_deref().unwrap()).as_deref(),
+            Some("2024-05-01")
+        );
+    }
+
+    #[test]
+    fn test_visibility_at() {
+        let now = DateTime::parse_from_rfc3339("2026-06-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut metadata = ArticleMetadata::default();
+        assert_eq!(metadata.visibility_at(now), Visibility::Listed);
+
+        metadata.status = ArticleStatus::Draft;
+        assert_eq!(metadata.visibility_at(now), Visibility::Hidden);
+
+        metadata.status = ArticleStatus::Unlisted;
+        assert_eq!(metadata.visibility_at(now), Visibility::Unlisted);
+        assert!(!metadata.is_listed());
+        assert!(!metadata.is_public());
+
+        // Hub articles stand in for a tag view
+        metadata.status = ArticleStatus::Published;
+        assert!(metadata.is_public());
+        metadata.hub_tag = Some("rust".to_string());
+        assert!(metadata.is_listed() && !metadata.is_public());
+        metadata.hub_tag = None;
+
+        metadata.status = ArticleStatus::Published;
+        metadata.publish_at = Some("2026-06-02T00:00:00Z".to_string());
+        assert_eq!(metadata.visibility_at(now), Visibility::Hidden);
+
+        metadata.publish_at = Some("2026-05-31T00:00:00Z".to_string());
+        metadata.expires_at = Some("2026-06-01T00:00:00Z".to_string());
+        assert_eq!(metadata.visibility_at(now), Visibility::Hidden);
+
+        metadata.expires_at = Some("2026-12-31T00:00:00Z".to_string());
+        assert_eq!(metadata.visibility_at(now), Visibility::Listed);
     }
 
     #[test]
@@ -229,5 +568,42 @@ tags: ["rust", "web"]
         metadata.importance = 3;
         metadata.title = "".to_string();
         assert!(extractor.validate_metadata(&metadata).is_err());
+
+        // Dates must be in a recognised format
+        metadata.title = "Title".to_string();
+        metadata.created_at = Some("2024/05/01".to_string());
+        assert!(extractor.validate_metadata(&metadata).is_err());
+        metadata.created_at = Some("2024-05-01".to_string());
+        assert!(extractor.validate_metadata(&metadata).is_ok());
+
+        // Publish window must be well-formed and ordered
+        metadata.publish_at = Some("tomorrow".to_string());
+        assert!(extractor.validate_metadata(&metadata).is_err());
+
+        metadata.publish_at = Some("2026-06-02T00:00:00Z".to_string());
+        metadata.expires_at = Some("2026-06-01T00:00:00Z".to_string());
+        assert!(extractor.validate_metadata(&metadata).is_err());
+
+        // Slugs and aliases end up in URLs
+        metadata.publish_at = None;
+        metadata.expires_at = None;
+        metadata.slug = Some("my article".to_string());
+        assert!(extractor.validate_metadata(&metadata).is_err());
+        metadata.slug = Some("my-article".to_string());
+        metadata.aliases = vec!["old path".to_string()];
+        assert!(extractor.validate_metadata(&metadata).is_err());
+        metadata.aliases = vec!["notes/old-name".to_string()];
+        assert!(extractor.validate_metadata(&metadata).is_ok());
+        metadata.aliases = vec!["旧記事".to_string()];
+        assert!(extractor.validate_metadata(&metadata).is_ok());
+
+        // So do tags
+        metadata.tags = vec!["../../etc".to_string()];
+        assert!(extractor.validate_metadata(&metadata).is_err());
+        metadata.tags = vec!["#math/topology".to_string()];
+        metadata.hub_tag = Some("a?b".to_string());
+        assert!(extractor.validate_metadata(&metadata).is_err());
+        metadata.hub_tag = Some("math".to_string());
+        assert!(extractor.validate_metadata(&metadata).is_ok());
     }
 }
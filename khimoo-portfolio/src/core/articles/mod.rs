@@ -3,13 +3,38 @@
 //! This module contains core logic for article processing, metadata extraction,
 //! and content management functionality.
 
+pub mod bibliography;
+pub mod front_matter;
+#[cfg(feature = "cli-tools")]
+pub mod history;
 pub mod links;
 pub mod metadata;
 pub mod processor;
+pub mod revisions;
+pub mod schema;
+pub mod sections;
+pub mod slugs;
+pub mod stats;
+pub mod summary;
+pub mod tag_registry;
+pub mod tags;
 
 // Re-export main components
+pub use bibliography::{Bibliography, BibliographyConfig, CitationStyle, Reference};
+pub use front_matter::{strip_front_matter, FrontMatter, FrontMatterFormat};
 pub use links::{
     ExtractedLink, LinkExtractor, LinkType, LinkValidator, ProcessedArticleRef, ValidationReport,
 };
-pub use metadata::{ArticleMetadata, MetadataExtractor};
-pub use processor::{ArticleProcessor, ProcessingError};
+pub use metadata::{
+    display_date, parse_front_matter_date, ArticleMetadata, ArticleStatus, MetadataExtractor,
+    Visibility,
+};
+pub use processor::{ArticleDiagnostic, ArticleProcessor, ProcessingDiagnostics, ProcessingError};
+pub use revisions::{classify_diff_line, ArticleRevisions, DiffLineKind, Revision};
+pub use schema::{front_matter_schema, UnknownKey, FRONT_MATTER_FIELDS};
+pub use sections::{build_sections, Section};
+pub use slugs::{article_slug, validate_slug, SlugConflict, SlugIndex};
+pub use stats::ArticleStats;
+pub use summary::{article_summary, truncate_to_width, SummaryConfig, EXCERPT_MARKER};
+pub use tag_registry::{TagInfo, TagRegistry, TagsConfig};
+pub use tags::{build_tag_tree, merge_tags, tag_matches, TagNode};
@@ -1,8 +1,12 @@
 use anyhow::Result;
-use std::path::Path;
+use std::fmt;
+use std::path::{Path, PathBuf};
 
 use super::links::{LinkExtractor, ProcessedArticleRef};
 use super::metadata::MetadataExtractor;
+use super::slugs::article_slug;
+use super::tags::merge_tags;
+use crate::core::render::collect_citation_keys;
 
 /// High-level article processing functionality
 /// Provides UI-independent business logic for article processing
@@ -21,18 +25,55 @@ impl ArticleProcessor {
     }
 
     /// Process a single article file and return processed article reference
+    ///
+    /// The slug comes from the file name alone; use [`Self::process_article_in`] for nested files.
     pub fn process_article(&self, file_path: &Path, content: &str) -> Result<ProcessedArticleRef> {
+        let file_name = file_path.file_name().map(Path::new).unwrap_or(file_path);
+        self.process_article_with_slug_path(file_path, file_name, content)
+    }
+
+    /// Process an article below `articles_dir`, deriving a hierarchical slug from its directory
+    pub fn process_article_in(
+        &self,
+        articles_dir: &Path,
+        file_path: &Path,
+        content: &str,
+    ) -> Result<ProcessedArticleRef> {
+        let relative_path = file_path.strip_prefix(articles_dir).unwrap_or(file_path);
+        self.process_article_with_slug_path(file_path, relative_path, content)
+    }
+
+    fn process_article_with_slug_path(
+        &self,
+        file_path: &Path,
+        relative_path: &Path,
+        content: &str,
+    ) -> Result<ProcessedArticleRef> {
         // Parse front matter and content
-        let (metadata, markdown_content) = self.metadata_extractor.extract_frontmatter(content)?;
+        let (mut metadata, markdown_content, unknown_keys) = self
+            .metadata_extractor
+            .extract_frontmatter_checked(content)?;
 
         // Validate metadata
         self.metadata_extractor.validate_metadata(&metadata)?;
 
+        // Inline #tags count as tags too, after the front-matter ones
+        let inline_tags = self.metadata_extractor.extract_tags(&markdown_content);
+        metadata.tags = merge_tags(&metadata.tags, &inline_tags);
+
         // Extract links from content
         let outbound_links = self.link_extractor.extract_links(&markdown_content);
+        let citation_keys = collect_citation_keys(&markdown_content);
 
-        // Generate slug from file path
-        let slug = self.generate_slug_from_path(file_path);
+        // Directory path plus the front-matter slug or file name
+        let slug = article_slug(&metadata, relative_path);
+        let link_base = relative_path
+            .parent()
+            .into_iter()
+            .flat_map(Path::components)
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
 
         Ok(ProcessedArticleRef {
             slug,
@@ -41,47 +82,12 @@ impl ArticleProcessor {
             outbound_links,
             inbound_links: Vec::new(), // Will be populated later during validation
             file_path: file_path.to_string_lossy().to_string(),
+            link_base,
+            citation_keys,
+            unknown_keys,
         })
     }
 
-    /// Process multiple articles from a directory
-    pub fn process_all(&self, articles_dir: &Path) -> Result<Vec<ProcessedArticleRef>> {
-        let mut articles = Vec::new();
-
-        if !articles_dir.exists() {
-            return Err(anyhow::anyhow!(
-                "Articles directory not found: {articles_dir:?}"
-            ));
-        }
-
-        // Read all markdown files in the directory
-        for entry in std::fs::read_dir(articles_dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if path.is_file() && path.extension().is_some_and(|ext| ext == "md") {
-                let content = std::fs::read_to_string(&path)?;
-                match self.process_article(&path, &content) {
-                    Ok(article) => articles.push(article),
-                    Err(e) => {
-                        eprintln!("Warning: Failed to process {path:?}: {e}");
-                    }
-                }
-            }
-        }
-
-        Ok(articles)
-    }
-
-    /// Generate slug from file path
-    fn generate_slug_from_path(&self, file_path: &Path) -> String {
-        file_path
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("untitled")
-            .to_string()
-    }
-
     /// Get metadata extractor for direct access
     pub fn metadata_extractor(&self) -> &MetadataExtractor {
         &self.metadata_extractor
@@ -99,6 +105,95 @@ impl Default for ArticleProcessor {
     }
 }
 
+/// Problem with a single article
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArticleDiagnostic {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for ArticleDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+/// Errors and warnings collected over a run, shared by every command that processes articles
+///
+/// An article that fails is skipped and the run keeps going; [`Self::finish`] then decides
+/// whether the run as a whole fails.
+#[derive(Debug, Default)]
+pub struct ProcessingDiagnostics {
+    /// Articles that could not be processed and were skipped
+    pub errors: Vec<ArticleDiagnostic>,
+    /// Problems in articles that were still processed, such as unknown front-matter keys
+    pub warnings: Vec<ArticleDiagnostic>,
+}
+
+impl ProcessingDiagnostics {
+    /// Record why an article was skipped
+    pub fn error(&mut self, path: &Path, error: impl fmt::Display) {
+        let diagnostic = ArticleDiagnostic {
+            path: path.to_path_buf(),
+            message: format!("{error:#}"),
+        };
+        eprintln!("Error: {diagnostic}");
+        self.errors.push(diagnostic);
+    }
+
+    /// Record a problem in an article that was still processed
+    pub fn warning(&mut self, path: &Path, message: impl fmt::Display) {
+        let diagnostic = ArticleDiagnostic {
+            path: path.to_path_buf(),
+            message: message.to_string(),
+        };
+        eprintln!("Warning: {diagnostic}");
+        self.warnings.push(diagnostic);
+    }
+
+    /// Keep a processed article after recording its warnings, or record why it failed
+    pub fn check(
+        &mut self,
+        path: &Path,
+        result: Result<ProcessedArticleRef>,
+    ) -> Option<ProcessedArticleRef> {
+        match result {
+            Ok(article) => {
+                for key in &article.unknown_keys {
+                    self.warning(path, key);
+                }
+                Some(article)
+            }
+            Err(e) => {
+                self.error(path, e);
+                None
+            }
+        }
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty() && self.warnings.is_empty()
+    }
+
+    /// Summarise the run; in strict mode any error or warning fails it
+    pub fn finish(&self, strict: bool) -> Result<()> {
+        if self.is_clean() {
+            return Ok(());
+        }
+
+        let summary = format!(
+            "{} articles skipped with errors, {} warnings",
+            self.errors.len(),
+            self.warnings.len()
+        );
+        if strict {
+            anyhow::bail!("{summary} (--strict)");
+        }
+        eprintln!("⚠️  {summary}");
+        Ok(())
+    }
+}
+
 /// Error types for article processing
 #[derive(Debug, thiserror::Error)]
 pub enum ProcessingError {
@@ -140,10 +235,68 @@ This is a test article with [markdown link](other-article).
     }
 
     #[test]
-    fn test_generate_slug_from_path() {
+    fn test_slug_from_path_or_front_matter() {
         let processor = ArticleProcessor::new().unwrap();
         let path = PathBuf::from("/path/to/my-article.md");
-        let slug = processor.generate_slug_from_path(&path);
-        assert_eq!(slug, "my-article");
+        let result = processor
+            .process_article(&path, "---\ntitle: A\n---\nbody\n")
+            .unwrap();
+        assert_eq!(result.slug, "my-article");
+
+        let content = "---\ntitle: A\nslug: renamed\naliases: [my-article]\n---\nbody\n";
+        let result = processor.process_article(&path, content).unwrap();
+        assert_eq!(result.slug, "renamed");
+        assert_eq!(result.metadata.aliases, vec!["my-article".to_string()]);
+    }
+
+    #[test]
+    fn test_nested_articles_get_hierarchical_slugs() {
+        let processor = ArticleProcessor::new().unwrap();
+        let root = PathBuf::from("/content/articles");
+        let content = "---\ntitle: Index\n---\nbody\n";
+
+        let topology = processor
+            .process_article_in(&root, &root.join("notes/topology/index.md"), content)
+            .unwrap();
+        let rust = processor
+            .process_article_in(&root, &root.join("notes/rust/index.md"), content)
+            .unwrap();
+        assert_eq!(topology.slug, "notes/topology");
+        assert_eq!(rust.slug, "notes/rust");
+    }
+
+    #[test]
+    fn test_diagnostics_keep_going() {
+        let root = PathBuf::from("/articles");
+        let files = [
+            ("good.md", "---\ntitle: Good\n---\nbody\n"),
+            (
+                "notes/typo.md",
+                "---\ntitle: Typo\nhome_dispaly: true\n---\nbody\n",
+            ),
+            ("bad.md", "---\ntitle: Bad\nimportance: 9\n---\n"),
+        ];
+
+        let processor = ArticleProcessor::new().unwrap();
+        let mut diagnostics = ProcessingDiagnostics::default();
+        let slugs: Vec<String> = files
+            .iter()
+            .filter_map(|(name, content)| {
+                let path = root.join(name);
+                let result = processor.process_article_in(&root, &path, content);
+                diagnostics.check(&path, result)
+            })
+            .map(|article| article.slug)
+            .collect();
+
+        assert_eq!(slugs, ["good", "notes/typo"]);
+        assert_eq!(diagnostics.errors.len(), 1);
+        assert!(diagnostics.errors[0].path.ends_with("bad.md"));
+        assert_eq!(diagnostics.warnings.len(), 1);
+        assert!(diagnostics.warnings[0]
+            .message
+            .contains("did you mean `home_display`?"));
+        assert!(diagnostics.finish(false).is_ok());
+        assert!(diagnostics.finish(true).is_err());
     }
 }
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+/// Diffs larger than this are cut off in the exported history
+pub const MAX_DIFF_BYTES: usize = 64 * 1024;
+
+/// `[history]` section of project.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HistoryConfig {
+    /// Publish each article's commit history; commits before it was first publishable
+    /// (while `status: draft`) are always left out
+    pub export: bool,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self { export: true }
+    }
+}
+
+/// One commit touching an article file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Revision {
+    pub hash: String,
+    pub short_hash: String,
+    /// Author date, RFC 3339
+    pub date: String,
+    /// Commit subject line
+    pub message: String,
+    /// Unified diff of the article file in this commit
+    pub diff: String,
+    #[serde(default)]
+    pub diff_truncated: bool,
+}
+
+/// Revision history exported for a single article (newest first)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArticleRevisions {
+    pub slug: String,
+    pub revisions: Vec<Revision>,
+}
+
+impl ArticleRevisions {
+    /// Path of the exported history relative to the data directory
+    pub fn data_path(slug: &str) -> String {
+        format!("history/{slug}.json")
+    }
+}
+
+/// Kind of a line in a unified diff, used for highlighting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    /// `diff --git`, `index`, `---`/`+++` and rename lines
+    Header,
+    /// `@@ -a,b +c,d @@`
+    Hunk,
+    Added,
+    Removed,
+    Context,
+}
+
+/// Classify a single line of a unified diff
+pub fn classify_diff_line(line: &str) -> DiffLineKind {
+    const HEADER_PREFIXES: [&str; 10] = [
+        "diff --git",
+        "index ",
+        "--- ",
+        "+++ ",
+        "new file mode",
+        "deleted file mode",
+        "similarity index",
+        "rename from",
+        "rename to",
+        "\\ No newline",
+    ];
+
+    if HEADER_PREFIXES
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+    {
+        DiffLineKind::Header
+    } else if line.starts_with("@@") {
+        DiffLineKind::Hunk
+    } else if line.starts_with('+') {
+        DiffLineKind::Added
+    } else if line.starts_with('-') {
+        DiffLineKind::Removed
+    } else {
+        DiffLineKind::Context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_diff_lines() {
+        assert_eq!(
+            classify_diff_line("diff --git a/old.md b/new.md"),
+            DiffLineKind::Header
+        );
+        assert_eq!(classify_diff_line("--- a/note.md"), DiffLineKind::Header);
+        assert_eq!(classify_diff_line("+++ b/note.md"), DiffLineKind::Header);
+        assert_eq!(
+            classify_diff_line("rename from old.md"),
+            DiffLineKind::Header
+        );
+        assert_eq!(
+            classify_diff_line("@@ -1,3 +1,4 @@ # Note"),
+            DiffLineKind::Hunk
+        );
+        assert_eq!(classify_diff_line("+added line"), DiffLineKind::Added);
+        assert_eq!(classify_diff_line("-removed line"), DiffLineKind::Removed);
+        assert_eq!(classify_diff_line(" unchanged"), DiffLineKind::Context);
+        assert_eq!(classify_diff_line(""), DiffLineKind::Context);
+    }
+
+    #[test]
+    fn test_history_data_path() {
+        assert_eq!(
+            ArticleRevisions::data_path("my-note"),
+            "history/my-note.json"
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// Kind of value a front-matter key takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    String,
+    Boolean,
+    /// Whole number in an inclusive range
+    Integer {
+        min: u8,
+        max: u8,
+    },
+    StringList,
+    /// `YYYY-MM-DD`, `YYYY-MM-DDTHH:mm` or RFC 3339
+    Date,
+    OneOf(&'static [&'static str]),
+}
+
+/// A key accepted in article front matter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrontMatterField {
+    pub name: &'static str,
+    pub kind: FieldKind,
+    pub required: bool,
+    pub description: &'static str,
+}
+
+const fn field(name: &'static str, kind: FieldKind, description: &'static str) -> FrontMatterField {
+    FrontMatterField {
+        name,
+        kind,
+        required: false,
+        description,
+    }
+}
+
+/// Every key `ArticleMetadata` reads, in the order it declares them
+pub const FRONT_MATTER_FIELDS: &[FrontMatterField] = &[
+    FrontMatterField {
+        required: true,
+        ..field("title", FieldKind::String, "Article title")
+    },
+    field(
+        "slug",
+        FieldKind::String,
+        "URL slug replacing the file name",
+    ),
+    field(
+        "aliases",
+        FieldKind::StringList,
+        "Former slugs that redirect to this article",
+    ),
+    field(
+        "description",
+        FieldKind::String,
+        "Summary for listings and link previews",
+    ),
+    field(
+        "home_display",
+        FieldKind::Boolean,
+        "Show the article in the home graph",
+    ),
+    field(
+        "importance",
+        FieldKind::Integer { min: 1, max: 5 },
+        "Node size in the home graph (default 3)",
+    ),
+    field(
+        "related_articles",
+        FieldKind::StringList,
+        "Slugs of related articles",
+    ),
+    field("tags", FieldKind::StringList, "Tags, nested with `/`"),
+    field("created_at", FieldKind::Date, "Publication date"),
+    field("updated_at", FieldKind::Date, "Last update date"),
+    field(
+        "author_image",
+        FieldKind::String,
+        "Image shown next to the title",
+    ),
+    field(
+        "cover",
+        FieldKind::String,
+        "Cover image for the article page, cards and link previews",
+    ),
+    field(
+        "hub_tag",
+        FieldKind::String,
+        "Redirect the article to this tag's page",
+    ),
+    field(
+        "status",
+        FieldKind::OneOf(&["draft", "published", "unlisted"]),
+        "Publication status (default published)",
+    ),
+    field(
+        "publish_at",
+        FieldKind::Date,
+        "Hide the article until this time",
+    ),
+    field(
+        "expires_at",
+        FieldKind::Date,
+        "Hide the article again from this time",
+    ),
+    field(
+        "noindex",
+        FieldKind::Boolean,
+        "Leave the article out of the sitemap and search engines",
+    ),
+];
+
+/// Front-matter key that no field reads
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKey {
+    pub key: String,
+    /// Closest known key, when the unknown one looks like a typo
+    pub suggestion: Option<&'static str>,
+}
+
+impl fmt::Display for UnknownKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown front-matter key `{}`", self.key)?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, " (did you mean `{suggestion}`?)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Keys of a parsed front-matter mapping that no field reads, in document order
+pub fn unknown_keys(front_matter: &serde_yaml::Value) -> Vec<UnknownKey> {
+    let Some(mapping) = front_matter.as_mapping() else {
+        return Vec::new();
+    };
+
+    mapping
+        .keys()
+        .map(|key| match key.as_str() {
+            Some(key) => key.to_string(),
+            None => serde_yaml::to_string(key)
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        })
+        .filter(|key| !FRONT_MATTER_FIELDS.iter().any(|field| field.name == key))
+        .map(|key| UnknownKey {
+            suggestion: suggest_key(&key),
+            key,
+        })
+        .collect()
+}
+
+/// Known key within a couple of edits of `key` (`home_dispaly`, `tag`, `Created-At`)
+fn suggest_key(key: &str) -> Option<&'static str> {
+    let normalized = key.trim().to_lowercase().replace('-', "_");
+    let max_distance = if normalized.chars().count() <= 4 {
+        1
+    } else {
+        2
+    };
+
+    FRONT_MATTER_FIELDS
+        .iter()
+        .map(|field| (edit_distance(&normalized, field.name), field.name))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name)
+}
+
+/// Edit distance counting an adjacent transposition as one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
+/// JSON Schema for article front matter, for editors that validate YAML
+pub fn front_matter_schema() -> Value {
+    let mut properties = Map::new();
+    for field in FRONT_MATTER_FIELDS {
+        let mut property = match field.kind {
+            FieldKind::String => json!({ "type": "string" }),
+            FieldKind::Boolean => json!({ "type": "boolean" }),
+            FieldKind::Integer { min, max } => {
+                json!({ "type": "integer", "minimum": min, "maximum": max })
+            }
+            FieldKind::StringList => json!({ "type": "array", "items": { "type": "string" } }),
+            FieldKind::Date => json!({
+                "type": "string",
+                "pattern": r"^\d{4}-\d{2}-\d{2}([T ]\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:\d{2})?)?$"
+            }),
+            FieldKind::OneOf(values) => json!({ "enum": values }),
+        };
+        property["description"] = json!(field.description);
+        properties.insert(field.name.to_string(), property);
+    }
+
+    let required: Vec<&str> = FRONT_MATTER_FIELDS
+        .iter()
+        .filter(|field| field.required)
+        .map(|field| field.name)
+        .collect();
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Article front matter",
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::articles::metadata::ArticleMetadata;
+
+    #[test]
+    fn test_fields_match_article_metadata() {
+        let metadata = serde_yaml::to_value(ArticleMetadata::default()).unwrap();
+        let keys: Vec<&str> = metadata
+            .as_mapping()
+            .unwrap()
+            .keys()
+            .filter_map(|key| key.as_str())
+            .collect();
+        let fields: Vec<&str> = FRONT_MATTER_FIELDS.iter().map(|field| field.name).collect();
+        assert_eq!(keys, fields);
+    }
+
+    #[test]
+    fn test_unknown_keys_with_suggestions() {
+        let front_matter: serde_yaml::Value = serde_yaml::from_str(
+            "title: A\nhome_dispaly: true\ntag: [rust]\nCreated-At: 2024-05-01\nmood: calm\n",
+        )
+        .unwrap();
+        let unknown = unknown_keys(&front_matter);
+
+        let found: Vec<(&str, Option<&str>)> = unknown
+            .iter()
+            .map(|key| (key.key.as_str(), key.suggestion))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("home_dispaly", Some("home_display")),
+                ("tag", Some("tags")),
+                ("Created-At", Some("created_at")),
+                ("mood", None),
+            ]
+        );
+        assert_eq!(
+            unknown[1].to_string(),
+            "unknown front-matter key `tag` (did you mean `tags`?)"
+        );
+    }
+
+    #[test]
+    fn test_front_matter_schema() {
+        let schema = front_matter_schema();
+        assert_eq!(schema["additionalProperties"], json!(false));
+        assert_eq!(schema["required"], json!(["title"]));
+        assert_eq!(schema["properties"]["importance"]["maximum"], json!(5));
+        assert_eq!(
+            schema["properties"]["status"]["enum"],
+            json!(["draft", "published", "unlisted"])
+        );
+    }
+}
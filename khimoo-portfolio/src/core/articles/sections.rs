@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::slugs::INDEX_FILE_STEM;
+
+/// A directory of articles, listed on its own index page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Section {
+    /// Directory path relative to the articles directory (`notes/topology`)
+    pub path: String,
+    /// Title of the section's `index.md`, else the directory name
+    pub title: String,
+    /// Slug of the section's `index.md`, if it has one
+    pub index_slug: Option<String>,
+    /// Slugs of the articles directly in this directory, excluding the index
+    pub articles: Vec<String>,
+    /// Paths of the directories directly below this one
+    pub sections: Vec<String>,
+}
+
+impl Section {
+    /// Slug the section page is served under
+    pub fn slug(&self) -> &str {
+        self.index_slug.as_deref().unwrap_or(&self.path)
+    }
+
+    /// Last segment of the directory path
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+}
+
+/// Directory of an article file given relative to the articles directory
+pub fn section_path_of(file_path: &str) -> Option<&str> {
+    file_path
+        .rsplit_once('/')
+        .map(|(dir, _)| dir)
+        .filter(|dir| !dir.is_empty())
+}
+
+/// Whether a file is its directory's own page (`index.md`)
+pub fn is_section_index(file_path: &str) -> bool {
+    file_path
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.strip_suffix(".md"))
+        == Some(INDEX_FILE_STEM)
+}
+
+/// Section paths from the outermost to `path` itself (`a/b` gives `a`, `a/b`)
+pub fn ancestor_paths(path: &str) -> Vec<&str> {
+    path.match_indices('/')
+        .map(|(index, _)| &path[..index])
+        .chain(std::iter::once(path))
+        .filter(|ancestor| !ancestor.is_empty())
+        .collect()
+}
+
+/// Build sections from `(relative file path, slug, title)` triples, sorted by path
+pub fn build_sections<'a>(
+    articles: impl IntoIterator<Item = (&'a str, &'a str, &'a str)>,
+) -> Vec<Section> {
+    let mut sections: BTreeMap<String, Section> = BTreeMap::new();
+
+    for (file_path, slug, title) in articles {
+        let Some(dir) = section_path_of(file_path) else {
+            continue;
+        };
+
+        // Make sure every ancestor exists and lists its child directory
+        let ancestors = ancestor_paths(dir);
+        for pair in ancestors.windows(2) {
+            let children = &mut section_entry(&mut sections, pair[0]).sections;
+            if !children.iter().any(|child| child == pair[1]) {
+                children.push(pair[1].to_string());
+            }
+        }
+
+        let entry = section_entry(&mut sections, dir);
+        if is_section_index(file_path) {
+            entry.index_slug = Some(slug.to_string());
+            entry.title = title.to_string();
+        } else {
+            entry.articles.push(slug.to_string());
+        }
+    }
+
+    sections
+        .into_values()
+        .map(|mut section| {
+            section.articles.sort();
+            section.sections.sort();
+            section
+        })
+        .collect()
+}
+
+fn section_entry<'s>(sections: &'s mut BTreeMap<String, Section>, path: &str) -> &'s mut Section {
+    sections.entry(path.to_string()).or_insert_with(|| Section {
+        path: path.to_string(),
+        title: path.rsplit('/').next().unwrap_or(path).to_string(),
+        index_slug: None,
+        articles: Vec::new(),
+        sections: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_section_paths() {
+        assert_eq!(
+            section_path_of("notes/topology/index.md"),
+            Some("notes/topology")
+        );
+        assert_eq!(section_path_of("about.md"), None);
+        assert_eq!(
+            ancestor_paths("notes/topology"),
+            vec!["notes", "notes/topology"]
+        );
+        assert_eq!(ancestor_paths("notes"), vec!["notes"]);
+        assert!(is_section_index("notes/topology/index.md"));
+        assert!(!is_section_index("notes/topology/indexes.md"));
+    }
+
+    #[test]
+    fn test_build_sections() {
+        let sections = build_sections([
+            ("about.md", "about", "About"),
+            ("notes/topology/index.md", "notes/topology", "Topology"),
+            (
+                "notes/topology/compactness.md",
+                "notes/topology/compactness",
+                "Compactness",
+            ),
+            ("notes/rust/index.md", "notes/rust", "Rust"),
+            (
+                "notes/rust/ownership.md",
+                "notes/rust/ownership",
+                "Ownership",
+            ),
+        ]);
+
+        let paths: Vec<_> = sections.iter().map(|s| s.path.as_str()).collect();
+        assert_eq!(paths, ["notes", "notes/rust", "notes/topology"]);
+
+        let notes = &sections[0];
+        assert_eq!(notes.title, "notes");
+        assert_eq!(notes.index_slug, None);
+        assert_eq!(notes.slug(), "notes");
+        assert!(notes.articles.is_empty());
+        assert_eq!(notes.sections, ["notes/rust", "notes/topology"]);
+
+        let topology = &sections[2];
+        assert_eq!(topology.title, "Topology");
+        assert_eq!(topology.slug(), "notes/topology");
+        assert_eq!(topology.name(), "topology");
+        assert_eq!(topology.articles, ["notes/topology/compactness"]);
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Component, Path};
+
+use super::metadata::ArticleMetadata;
+
+/// File stem of a section's own page
+pub const INDEX_FILE_STEM: &str = "index";
+
+/// Characters that would break a slug when used as a URL path segment
+const RESERVED_SLUG_CHARS: [char; 5] = ['/', '\\', '?', '#', '%'];
+
+/// Check that a front-matter `slug` or alias can be used as a URL path segment
+pub fn validate_slug(slug: &str) -> Result<(), String> {
+    if slug.trim().is_empty() {
+        return Err("slug cannot be empty".to_string());
+    }
+    if let Some(c) = slug
+        .chars()
+        .find(|c| c.is_whitespace() || RESERVED_SLUG_CHARS.contains(c))
+    {
+        return Err(format!("slug {slug:?} contains {c:?}"));
+    }
+    Ok(())
+}
+
+/// Check an alias, which may name a nested article (`notes/old-name`)
+pub fn validate_slug_path(path: &str) -> Result<(), String> {
+    if path.trim().is_empty() {
+        return Err("slug cannot be empty".to_string());
+    }
+    path.split('/').try_for_each(validate_slug)
+}
+
+/// Slug for an article from its path relative to the articles directory
+///
+/// Directories become slug segments and `index.md` stands for its directory, so
+/// `notes/topology/index.md` is `notes/topology`. A front-matter `slug` replaces the last segment.
+pub fn article_slug(metadata: &ArticleMetadata, relative_path: &Path) -> String {
+    let mut segments: Vec<String> = relative_path
+        .parent()
+        .into_iter()
+        .flat_map(Path::components)
+        .filter_map(|component| match component {
+            Component::Normal(name) => name.to_str().map(str::to_string),
+            _ => None,
+        })
+        .collect();
+
+    let stem = relative_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("untitled");
+    if stem != INDEX_FILE_STEM || segments.is_empty() {
+        segments.push(stem.to_string());
+    }
+
+    if let Some(slug) = metadata.slug.as_deref().map(str::trim) {
+        if !slug.is_empty() {
+            segments.pop();
+            segments.push(slug.to_string());
+        }
+    }
+
+    segments.join("/")
+}
+
+/// A slug or alias claimed by more than one article
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlugConflict {
+    pub slug: String,
+    /// Canonical slugs of the claiming articles
+    pub claimed_by: Vec<String>,
+}
+
+impl std::fmt::Display for SlugConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "\"{}\" is claimed by {}",
+            self.slug,
+            self.claimed_by.join(", ")
+        )
+    }
+}
+
+/// Maps canonical slugs and aliases to the canonical slug of their article
+#[derive(Debug, Clone, Default)]
+pub struct SlugIndex {
+    targets: HashMap<String, String>,
+}
+
+impl SlugIndex {
+    /// Build the index from `(slug, aliases)` pairs, reporting every name claimed twice
+    ///
+    /// On a conflict the first claimant keeps the name.
+    pub fn build<'a, I, A>(articles: I) -> (Self, Vec<SlugConflict>)
+    where
+        I: IntoIterator<Item = (&'a str, A)>,
+        A: IntoIterator<Item = &'a String>,
+    {
+        let mut claims: BTreeMap<&'a str, Vec<&'a str>> = BTreeMap::new();
+        for (slug, aliases) in articles {
+            claims.entry(slug).or_default().push(slug);
+            for alias in aliases {
+                let claimants = claims.entry(alias.as_str()).or_default();
+                // An alias repeating the article's own slug is harmless
+                if !claimants.contains(&slug) {
+                    claimants.push(slug);
+                }
+            }
+        }
+
+        let mut targets = HashMap::new();
+        let mut conflicts = Vec::new();
+        for (name, claimants) in claims {
+            targets.insert(name.to_string(), claimants[0].to_string());
+            if claimants.len() > 1 {
+                conflicts.push(SlugConflict {
+                    slug: name.to_string(),
+                    claimed_by: claimants.iter().map(|slug| slug.to_string()).collect(),
+                });
+            }
+        }
+
+        (Self { targets }, conflicts)
+    }
+
+    /// Canonical slug for a slug or alias
+    pub fn resolve(&self, slug: &str) -> Option<&str> {
+        self.targets.get(slug).map(String::as_str)
+    }
+
+    /// Whether `slug` names an article, directly or through an alias
+    pub fn contains(&self, slug: &str) -> bool {
+        self.targets.contains_key(slug)
+    }
+
+    /// Every slug and alias with the canonical slug it names
+    pub fn names(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.targets
+            .iter()
+            .map(|(name, slug)| (name.as_str(), slug.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_article_slug_prefers_front_matter() {
+        let mut metadata = ArticleMetadata::default();
+        let path = Path::new("位相空間.md");
+        assert_eq!(article_slug(&metadata, path), "位相空間");
+
+        metadata.slug = Some("topological-space".to_string());
+        assert_eq!(article_slug(&metadata, path), "topological-space");
+    }
+
+    #[test]
+    fn test_article_slug_is_hierarchical() {
+        let mut metadata = ArticleMetadata::default();
+        let slug = |metadata: &ArticleMetadata, path: &str| article_slug(metadata, Path::new(path));
+
+        assert_eq!(slug(&metadata, "notes/topology/index.md"), "notes/topology");
+        assert_eq!(slug(&metadata, "notes/rust/index.md"), "notes/rust");
+        assert_eq!(
+            slug(&metadata, "notes/topology/compactness.md"),
+            "notes/topology/compactness"
+        );
+        assert_eq!(slug(&metadata, "index.md"), "index");
+
+        metadata.slug = Some("compact".to_string());
+        assert_eq!(
+            slug(&metadata, "notes/topology/compactness.md"),
+            "notes/topology/compact"
+        );
+        assert_eq!(slug(&metadata, "notes/topology/index.md"), "notes/compact");
+    }
+
+    #[test]
+    fn test_validate_slug() {
+        assert!(validate_slug("rust-ownership").is_ok());
+        assert!(validate_slug("位相空間").is_ok());
+        assert!(validate_slug("").is_err());
+        assert!(validate_slug("with space").is_err());
+        assert!(validate_slug("a/b").is_err());
+        assert!(validate_slug("100%").is_err());
+
+        assert!(validate_slug_path("notes/old-name").is_ok());
+        assert!(validate_slug_path("notes//old").is_err());
+        assert!(validate_slug_path("/notes").is_err());
+    }
+
+    #[test]
+    fn test_index_resolves_aliases() {
+        let topology = strings(&["topology", "位相空間"]);
+        let none = Vec::new();
+        let (index, conflicts) =
+            SlugIndex::build([("topological-space", &topology), ("rust", &none)]);
+
+        assert!(conflicts.is_empty());
+        assert_eq!(index.resolve("topology"), Some("topological-space"));
+        assert_eq!(
+            index.resolve("topological-space"),
+            Some("topological-space")
+        );
+        assert_eq!(index.resolve("rust"), Some("rust"));
+        assert_eq!(index.resolve("missing"), None);
+    }
+
+    #[test]
+    fn test_index_reports_conflicts() {
+        let old = strings(&["notes", "first"]);
+        let clash = strings(&["first"]);
+        let none = Vec::new();
+        let (index, conflicts) = SlugIndex::build([
+            ("notes", &none),
+            ("new-notes", &old),
+            ("other", &clash),
+            ("notes", &none),
+        ]);
+
+        assert_eq!(
+            conflicts,
+            vec![
+                SlugConflict {
+                    slug: "first".to_string(),
+                    claimed_by: strings(&["new-notes", "other"]),
+                },
+                SlugConflict {
+                    slug: "notes".to_string(),
+                    claimed_by: strings(&["notes", "new-notes", "notes"]),
+                },
+            ]
+        );
+        assert_eq!(index.resolve("notes"), Some("notes"));
+    }
+}
@@ -0,0 +1,176 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+use crate::core::render::markdown_options;
+
+/// Reading speed for Latin-script prose
+const WORDS_PER_MINUTE: f64 = 200.0;
+/// Reading speed for Japanese and other CJK prose
+const CJK_CHARACTERS_PER_MINUTE: f64 = 500.0;
+/// Time spent looking at an image
+const SECONDS_PER_IMAGE: f64 = 12.0;
+/// Time spent reading a code block or display equation, beyond its text
+const SECONDS_PER_BLOCK: f64 = 20.0;
+
+/// Length and content counts of an article body, computed by `process-articles`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct ArticleStats {
+    /// Words of Latin-script (and other space-separated) text
+    pub words: usize,
+    /// Han, kana and hangul characters, which are counted one by one
+    pub cjk_characters: usize,
+    /// `$$ ... $$` display equations
+    pub math_blocks: usize,
+    pub code_blocks: usize,
+    pub images: usize,
+    /// Estimated reading time, rounded up; 0 for an empty article
+    pub reading_minutes: u32,
+}
+
+impl ArticleStats {
+    /// Count the prose, equations, code blocks and images of an article body
+    ///
+    /// Code and equations are counted as blocks rather than words, and image alt text is not
+    /// counted at all.
+    pub fn from_markdown(markdown: &str) -> Self {
+        let mut stats = Self::default();
+        let mut prose = String::new();
+        let mut skip_depth = 0usize;
+
+        for event in Parser::new_ext(markdown, markdown_options()) {
+            match event {
+                Event::Start(Tag::CodeBlock(_)) => {
+                    stats.code_blocks += 1;
+                    skip_depth += 1;
+                }
+                Event::Start(Tag::Image { .. }) => {
+                    stats.images += 1;
+                    skip_depth += 1;
+                }
+                Event::End(TagEnd::CodeBlock | TagEnd::Image) => {
+                    skip_depth = skip_depth.saturating_sub(1)
+                }
+                Event::Html(html) | Event::InlineHtml(html) => {
+                    stats.images += html.to_ascii_lowercase().matches("<img").count();
+                }
+                _ if skip_depth > 0 => {}
+                Event::Text(text) | Event::Code(text) => prose.push_str(&text),
+                // Keep words in neighbouring blocks apart
+                Event::SoftBreak | Event::HardBreak | Event::End(_) => prose.push('\n'),
+                _ => {}
+            }
+        }
+
+        // Without math support in the parser, `$$` delimiters reach us as plain text:
+        // every odd segment between them is an equation
+        let segments: Vec<&str> = prose.split("$$").collect();
+        stats.math_blocks = (segments.len() - 1) / 2;
+        for (index, segment) in segments.iter().enumerate() {
+            let is_math = index % 2 == 1 && index < segments.len() - 1;
+            if !is_math {
+                let (words, cjk_characters) = count_text(segment);
+                stats.words += words;
+                stats.cjk_characters += cjk_characters;
+            }
+        }
+
+        stats.reading_minutes = stats.estimate_reading_minutes();
+        stats
+    }
+
+    fn estimate_reading_minutes(&self) -> u32 {
+        let minutes = self.words as f64 / WORDS_PER_MINUTE
+            + self.cjk_characters as f64 / CJK_CHARACTERS_PER_MINUTE
+            + (self.images as f64 * SECONDS_PER_IMAGE
+                + (self.code_blocks + self.math_blocks) as f64 * SECONDS_PER_BLOCK)
+                / 60.0;
+        minutes.ceil() as u32
+    }
+
+    /// Words and CJK characters together, for comparing articles in mixed languages
+    pub fn length(&self) -> usize {
+        self.words + self.cjk_characters
+    }
+}
+
+/// Words and CJK characters in plain text
+fn count_text(text: &str) -> (usize, usize) {
+    let mut words = 0;
+    let mut cjk_characters = 0;
+    let mut in_word = false;
+
+    for c in text.chars() {
+        if is_cjk(c) {
+            cjk_characters += 1;
+            in_word = false;
+        } else if c.is_alphanumeric() {
+            if !in_word {
+                words += 1;
+                in_word = true;
+            }
+        } else if !(in_word && matches!(c, '\'' | '’' | '-' | '_')) {
+            // Apostrophes and hyphens inside a word (`don't`, `well-known`) do not split it
+            in_word = false;
+        }
+    }
+
+    (words, cjk_characters)
+}
+
+/// Scripts written without spaces between words, counted and wrapped character by character
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30ff}'     // Hiragana, Katakana
+        | '\u{31f0}'..='\u{31ff}'   // Katakana phonetic extensions
+        | '\u{3400}'..='\u{4dbf}'   // CJK extension A
+        | '\u{4e00}'..='\u{9fff}'   // CJK unified ideographs
+        | '\u{f900}'..='\u{faff}'   // CJK compatibility ideographs
+        | '\u{ff66}'..='\u{ff9f}'   // Half-width katakana
+        | '\u{ac00}'..='\u{d7af}'   // Hangul syllables
+        | '\u{20000}'..='\u{3134f}' // CJK extensions B-G
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_words_and_cjk_characters() {
+        let stats = ArticleStats::from_markdown(
+            "# Rust の所有権\n\nOwnership isn't hard, it's **well-known**.\n\n位相空間、`Vec<T>`。\n",
+        );
+        // Rust, Ownership, isn't, hard, it's, well-known, Vec, T
+        assert_eq!(stats.words, 8);
+        // の所有権 + 位相空間
+        assert_eq!(stats.cjk_characters, 8);
+        assert_eq!(stats.length(), 16);
+    }
+
+    #[test]
+    fn test_counts_blocks_and_skips_their_text() {
+        let markdown = "Intro.\n\n```rust\nfn main() { println!(\"many words here\"); }\n```\n\n\
+                        ![a long alt text](a.png) <img src=\"b.png\">\n\n\
+                        $$\n\\int_0^1 x \\, dx\n$$\n\nCost is $$5 only.\n";
+        let stats = ArticleStats::from_markdown(markdown);
+        assert_eq!(stats.code_blocks, 1);
+        assert_eq!(stats.images, 2);
+        // The unmatched `$$` is text, not an equation
+        assert_eq!(stats.math_blocks, 1);
+        // Intro, Cost, is, 5, only
+        assert_eq!(stats.words, 5);
+    }
+
+    #[test]
+    fn test_reading_minutes() {
+        assert_eq!(ArticleStats::from_markdown("").reading_minutes, 0);
+        assert_eq!(ArticleStats::from_markdown("One word.").reading_minutes, 1);
+
+        let english = "word ".repeat(450);
+        assert_eq!(ArticleStats::from_markdown(&english).reading_minutes, 3);
+
+        let japanese = "あ".repeat(1000);
+        assert_eq!(ArticleStats::from_markdown(&japanese).reading_minutes, 2);
+    }
+}
@@ -0,0 +1,204 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::core::render::markdown_options;
+
+/// Marks the end of a hand-picked excerpt in an article body
+pub const EXCERPT_MARKER: &str = "<!-- more -->";
+
+/// Appended to summaries that were cut short
+const ELLIPSIS: &str = "…";
+
+/// `[summary]` section of project.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SummaryConfig {
+    /// Display width of automatic summaries; CJK characters count as two
+    #[serde(default = "default_width")]
+    pub width: usize,
+    /// Summary for articles without any text, if they should have one
+    #[serde(default)]
+    pub fallback: Option<String>,
+}
+
+fn default_width() -> usize {
+    160
+}
+
+impl Default for SummaryConfig {
+    fn default() -> Self {
+        Self {
+            width: default_width(),
+            fallback: None,
+        }
+    }
+}
+
+/// Summary shown in listings: the front-matter `description`, else the text before
+/// `<!-- more -->`, else the first paragraph cut to the configured width
+pub fn article_summary(
+    description: Option<&str>,
+    markdown: &str,
+    config: &SummaryConfig,
+) -> Option<String> {
+    if let Some(description) = description.map(str::trim).filter(|d| !d.is_empty()) {
+        return Some(description.to_string());
+    }
+
+    let summary = match excerpt(markdown) {
+        Some(excerpt) => paragraphs(excerpt).join(" "),
+        None => paragraphs(markdown)
+            .into_iter()
+            .next()
+            .map(|paragraph| truncate_to_width(&paragraph, config.width))
+            .unwrap_or_default(),
+    };
+
+    if summary.is_empty() {
+        config.fallback.clone()
+    } else {
+        Some(summary)
+    }
+}
+
+/// Markdown before the excerpt marker, if the article has one
+fn excerpt(markdown: &str) -> Option<&str> {
+    let marker = EXCERPT_MARKER.replace(' ', "");
+    markdown.lines().find_map(|line| {
+        // Spacing and case vary between editors (`<!--more-->`, `<!-- More -->`)
+        let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+        if compact.eq_ignore_ascii_case(&marker) {
+            // The line is a slice of `markdown`, so its offset marks where the excerpt ends
+            let end = line.as_ptr() as usize - markdown.as_ptr() as usize;
+            Some(&markdown[..end])
+        } else {
+            None
+        }
+    })
+}
+
+/// Plain text of each paragraph or list item, leaving out headings, code blocks, images,
+/// tables, raw HTML and footnotes
+fn paragraphs(markdown: &str) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    let mut current = String::new();
+    let mut skip_depth = 0usize;
+
+    for event in Parser::new_ext(markdown, markdown_options()) {
+        match event {
+            Event::Start(
+                Tag::Heading { .. }
+                | Tag::CodeBlock(_)
+                | Tag::Image { .. }
+                | Tag::HtmlBlock
+                | Tag::Table(_)
+                | Tag::FootnoteDefinition(_),
+            ) => skip_depth += 1,
+            Event::End(
+                TagEnd::Heading(_)
+                | TagEnd::CodeBlock
+                | TagEnd::Image
+                | TagEnd::HtmlBlock
+                | TagEnd::Table
+                | TagEnd::FootnoteDefinition,
+            ) => skip_depth = skip_depth.saturating_sub(1),
+            _ if skip_depth > 0 => {}
+            Event::Text(text) | Event::Code(text) => current.push_str(&text),
+            Event::SoftBreak | Event::HardBreak => current.push(' '),
+            Event::End(TagEnd::Paragraph | TagEnd::Item) => {
+                let text = current.split_whitespace().collect::<Vec<_>>().join(" ");
+                if !text.is_empty() {
+                    paragraphs.push(text);
+                }
+                current.clear();
+            }
+            _ => {}
+        }
+    }
+
+    paragraphs
+}
+
+/// Cut `text` to at most `max_width` columns at a grapheme boundary, ending with `…` if cut
+pub fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+
+    let budget = max_width.saturating_sub(ELLIPSIS.width());
+    let mut width = 0;
+    let mut truncated = String::new();
+    for grapheme in text.graphemes(true) {
+        width += grapheme.width();
+        if width > budget {
+            break;
+        }
+        truncated.push_str(grapheme);
+    }
+
+    format!("{}{ELLIPSIS}", truncated.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(width: usize) -> SummaryConfig {
+        SummaryConfig {
+            width,
+            ..SummaryConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_description_wins() {
+        let summary = article_summary(Some("  Hand-written.  "), "Body text.", &config(10));
+        assert_eq!(summary.as_deref(), Some("Hand-written."));
+    }
+
+    #[test]
+    fn test_excerpt_marker() {
+        let markdown = "# Title\n\nFirst part.\n\nSecond part.\n\n<!--  More -->\n\nRest.\n";
+        assert_eq!(
+            article_summary(None, markdown, &config(5)).as_deref(),
+            Some("First part. Second part.")
+        );
+    }
+
+    #[test]
+    fn test_first_paragraph_skips_headings_and_code() {
+        let markdown =
+            "# 見出し\n\n```rust\nfn main() {}\n```\n\n位相空間の*基本*と `Rust`。\n\n次の段落。\n";
+        assert_eq!(
+            article_summary(None, markdown, &config(160)).as_deref(),
+            Some("位相空間の基本と Rust。")
+        );
+    }
+
+    #[test]
+    fn test_truncate_by_display_width() {
+        // Each kana is two columns wide
+        assert_eq!(truncate_to_width("あいうえお", 10), "あいうえお");
+        assert_eq!(truncate_to_width("あいうえお", 8), "あいう…");
+        assert_eq!(truncate_to_width("abc def ghi", 8), "abc def…");
+        // A flag is one grapheme made of two code points and is never split
+        assert_eq!(truncate_to_width("🇯🇵🇯🇵🇯🇵", 5), "🇯🇵🇯🇵…");
+    }
+
+    #[test]
+    fn test_fallback() {
+        assert_eq!(
+            article_summary(None, "# Only a heading\n", &config(10)),
+            None
+        );
+        let with_fallback = SummaryConfig {
+            fallback: Some("No summary.".to_string()),
+            ..SummaryConfig::default()
+        };
+        assert_eq!(
+            article_summary(None, "", &with_fallback).as_deref(),
+            Some("No summary.")
+        );
+    }
+}
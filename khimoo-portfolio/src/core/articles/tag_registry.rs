@@ -0,0 +1,312 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use super::tags::{normalize_tag, tag_ancestors};
+
+/// `[tags]` section of project.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TagsConfig {
+    /// Tag registry file relative to the project root
+    #[serde(default)]
+    pub file: Option<String>,
+}
+
+/// Errors raised while reading a tag registry
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum TagRegistryError {
+    #[error("Invalid tags file: {0}")]
+    Toml(String),
+    #[error("Invalid tag name: {0:?}")]
+    InvalidName(String),
+    #[error("Tag or alias {name:?} is claimed by both {first} and {second}")]
+    Conflict {
+        name: String,
+        first: String,
+        second: String,
+    },
+    #[error("Invalid color for tag {tag}: {color:?} (use #rgb, #rrggbb or a CSS color name)")]
+    InvalidColor { tag: String, color: String },
+}
+
+/// Display details of a registered tag
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct TagInfo {
+    /// Name shown instead of the tag itself
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Markdown-free text shown on the tag's landing page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Other spellings normalised to this tag
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// `#rgb`, `#rrggbb` or a CSS color name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// Registered tags keyed by their canonical spelling, as read from `tags.toml`
+///
+/// ```toml
+/// [tags.rust]
+/// name = "Rust"
+/// description = "Notes on the Rust language"
+/// aliases = ["rustlang"]
+/// color = "#dea584"
+///
+/// [tags."math/topology"]
+/// name = "Topology"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(from = "BTreeMap<String, TagInfo>", into = "BTreeMap<String, TagInfo>")]
+pub struct TagRegistry {
+    tags: BTreeMap<String, TagInfo>,
+    /// Lower-cased tag and alias spellings to canonical tags
+    lookup: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct TagsFile {
+    #[serde(default)]
+    tags: BTreeMap<String, TagInfo>,
+}
+
+impl TagRegistry {
+    /// Read and parse a tags file
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read tag registry: {}", path.display()))?;
+        Self::parse(&source)
+            .with_context(|| format!("Failed to parse tag registry: {}", path.display()))
+    }
+
+    /// Parse a tags file; names and aliases must be unique ignoring case
+    pub fn parse(source: &str) -> Result<Self, TagRegistryError> {
+        let file: TagsFile =
+            toml::from_str(source).map_err(|e| TagRegistryError::Toml(e.to_string()))?;
+
+        let mut tags = BTreeMap::new();
+        for (tag, mut info) in file.tags {
+            let canonical =
+                normalize_tag(&tag).ok_or_else(|| TagRegistryError::InvalidName(tag.clone()))?;
+            if let Some(color) = &info.color {
+                if !is_valid_color(color) {
+                    return Err(TagRegistryError::InvalidColor {
+                        tag: canonical,
+                        color: color.clone(),
+                    });
+                }
+            }
+            info.aliases = info
+                .aliases
+                .iter()
+                .map(|alias| {
+                    normalize_tag(alias).ok_or_else(|| TagRegistryError::InvalidName(alias.clone()))
+                })
+                .collect::<Result<_, _>>()?;
+            tags.insert(canonical, info);
+        }
+
+        Self::from_tags(tags)
+    }
+
+    fn from_tags(tags: BTreeMap<String, TagInfo>) -> Result<Self, TagRegistryError> {
+        let mut lookup: HashMap<String, String> = HashMap::new();
+        for (tag, info) in &tags {
+            for name in std::iter::once(tag).chain(&info.aliases) {
+                if let Some(first) = lookup.insert(name.to_lowercase(), tag.clone()) {
+                    if &first != tag {
+                        return Err(TagRegistryError::Conflict {
+                            name: name.clone(),
+                            first,
+                            second: tag.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(Self { tags, lookup })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    /// Registered tags in name order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &TagInfo)> {
+        self.tags.iter().map(|(tag, info)| (tag.as_str(), info))
+    }
+
+    /// Details of a canonical tag
+    pub fn get(&self, tag: &str) -> Option<&TagInfo> {
+        self.tags.get(tag)
+    }
+
+    /// Canonical spelling of a registered tag or alias, ignoring case
+    pub fn resolve(&self, tag: &str) -> Option<&str> {
+        self.lookup.get(&tag.to_lowercase()).map(String::as_str)
+    }
+
+    /// Display name of a tag: its registered name, else the tag itself
+    pub fn display_name<'a>(&'a self, tag: &'a str) -> &'a str {
+        self.get(tag)
+            .and_then(|info| info.name.as_deref())
+            .unwrap_or(tag)
+    }
+
+    /// Canonical spelling of a tag, or `None` when neither it nor a parent is registered
+    ///
+    /// The longest registered parent is rewritten and the rest kept, so with `topo` an
+    /// alias of `math/topology`, `Topo/compact` becomes `math/topology/compact`.
+    pub fn normalize(&self, tag: &str) -> Option<String> {
+        let tag = normalize_tag(tag)?;
+        tag_ancestors(&tag).into_iter().rev().find_map(|ancestor| {
+            self.resolve(ancestor)
+                .map(|canonical| format!("{canonical}{}", &tag[ancestor.len()..]))
+        })
+    }
+
+    /// Normalise an article's tags, returning them with the ones not in the registry
+    ///
+    /// Unknown tags are kept as written (below a registered parent if they have one) so a
+    /// missing registry entry only warns.
+    pub fn normalize_tags(&self, tags: &[String]) -> (Vec<String>, Vec<String>) {
+        let mut normalized: Vec<String> = Vec::new();
+        let mut unknown = Vec::new();
+
+        for tag in tags {
+            let Some(cleaned) = normalize_tag(tag) else {
+                continue;
+            };
+            let tag = self.normalize(&cleaned).unwrap_or(cleaned);
+            if !self.tags.contains_key(&tag) && !unknown.contains(&tag) {
+                unknown.push(tag.clone());
+            }
+            if !normalized
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(&tag))
+            {
+                normalized.push(tag);
+            }
+        }
+
+        (normalized, unknown)
+    }
+}
+
+fn is_valid_color(color: &str) -> bool {
+    match color.strip_prefix('#') {
+        Some(hex) => matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => !color.is_empty() && color.chars().all(|c| c.is_ascii_alphabetic()),
+    }
+}
+
+/// Registry read back from articles.json, which was checked for conflicts when written
+impl From<BTreeMap<String, TagInfo>> for TagRegistry {
+    fn from(tags: BTreeMap<String, TagInfo>) -> Self {
+        let mut lookup = HashMap::new();
+        for (tag, info) in &tags {
+            for name in std::iter::once(tag).chain(&info.aliases) {
+                lookup
+                    .entry(name.to_lowercase())
+                    .or_insert_with(|| tag.clone());
+            }
+        }
+        Self { tags, lookup }
+    }
+}
+
+impl From<TagRegistry> for BTreeMap<String, TagInfo> {
+    fn from(registry: TagRegistry) -> Self {
+        registry.tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = r##"
+[tags.rust]
+name = "Rust"
+description = "Notes on the Rust language"
+aliases = ["rustlang", "#Rust-lang"]
+color = "#dea584"
+
+[tags."math/topology"]
+name = "Topology"
+aliases = ["topo"]
+"##;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_registry() {
+        let registry = TagRegistry::parse(REGISTRY).unwrap();
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.resolve("RustLang"), Some("rust"));
+        assert_eq!(registry.resolve("rust-lang"), Some("rust"));
+        assert_eq!(registry.display_name("math/topology"), "Topology");
+        assert_eq!(registry.display_name("python"), "python");
+        assert_eq!(
+            registry.get("rust").unwrap().color.as_deref(),
+            Some("#dea584")
+        );
+    }
+
+    #[test]
+    fn test_normalize_tags() {
+        let registry = TagRegistry::parse(REGISTRY).unwrap();
+        assert_eq!(
+            registry.normalize("Topo/compact").as_deref(),
+            Some("math/topology/compact")
+        );
+        assert_eq!(registry.normalize("python"), None);
+
+        let (tags, unknown) =
+            registry.normalize_tags(&strings(&["RUST", "rustlang", "python", "topo"]));
+        assert_eq!(tags, strings(&["rust", "python", "math/topology"]));
+        assert_eq!(unknown, strings(&["python"]));
+    }
+
+    #[test]
+    fn test_registry_errors() {
+        let conflict = "[tags.rust]\naliases = [\"r\"]\n[tags.r]\n";
+        assert!(matches!(
+            TagRegistry::parse(conflict),
+            Err(TagRegistryError::Conflict { .. })
+        ));
+
+        let color = "[tags.rust]\ncolor = \"red; display: none\"\n";
+        assert!(matches!(
+            TagRegistry::parse(color),
+            Err(TagRegistryError::InvalidColor { .. })
+        ));
+        assert!(TagRegistry::parse("[tags.rust]\ncolor = \"Tomato\"\n").is_ok());
+
+        // Names become tag page and feed paths
+        for source in ["[tags.\"../etc\"]\n", "[tags.rust]\naliases = [\"r?\"]\n"] {
+            assert!(matches!(
+                TagRegistry::parse(source),
+                Err(TagRegistryError::InvalidName(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_registry_round_trip() {
+        let registry = TagRegistry::parse(REGISTRY).unwrap();
+        let json = serde_json::to_string(&registry).unwrap();
+        let restored: TagRegistry = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.resolve("topo"), Some("math/topology"));
+        assert_eq!(restored, registry);
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+
+/// Separates levels of an Obsidian nested tag (`math/topology`)
+pub const TAG_SEPARATOR: char = '/';
+
+/// Find Obsidian `#tags` in plain text, in order of appearance
+///
+/// A tag starts after whitespace or at the start of the text, may contain letters, digits,
+/// `_`, `-` and `/`, and needs at least one non-digit so `#1` stays a number.
+pub fn find_hashtags(text: &str) -> Vec<String> {
+    let mut tags = Vec::new();
+    let mut previous: Option<char> = None;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        let at_boundary = previous.is_none_or(char::is_whitespace);
+        previous = Some(c);
+        if c != '#' || !at_boundary {
+            continue;
+        }
+
+        let start = index + c.len_utf8();
+        let mut end = start;
+        while let Some(&(next_index, next)) = chars.peek() {
+            if !is_tag_char(next) {
+                break;
+            }
+            end = next_index + next.len_utf8();
+            previous = Some(next);
+            chars.next();
+        }
+
+        if let Some(tag) = normalize_tag(&text[start..end]) {
+            tags.push(tag);
+        }
+    }
+
+    tags
+}
+
+fn is_tag_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | TAG_SEPARATOR)
+}
+
+/// Characters that would break a tag when its levels become URL path and file segments
+const RESERVED_TAG_CHARS: [char; 4] = ['\\', '?', '#', '%'];
+
+/// Check that a tag can name `/tag/{tag}` and `data/feeds/{tag}` after normalisation
+///
+/// Leading `#` and stray separators are allowed, since [`normalize_tag`] removes them.
+pub fn validate_tag(tag: &str) -> Result<(), String> {
+    let segments = tag
+        .trim()
+        .trim_start_matches('#')
+        .split(TAG_SEPARATOR)
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty());
+    for segment in segments {
+        if segment == "." || segment == ".." {
+            return Err(format!("tag {tag:?} contains a {segment:?} level"));
+        }
+        if let Some(c) = segment
+            .chars()
+            .find(|c| c.is_whitespace() || RESERVED_TAG_CHARS.contains(c))
+        {
+            return Err(format!("tag {tag:?} contains {c:?}"));
+        }
+    }
+    Ok(())
+}
+
+/// Clean up a tag written in front matter or inline: drop `#` and stray separators
+///
+/// `None` for tags that are only digits or fail [`validate_tag`].
+pub fn normalize_tag(tag: &str) -> Option<String> {
+    validate_tag(tag).ok()?;
+    let segments: Vec<&str> = tag
+        .trim()
+        .trim_start_matches('#')
+        .split(TAG_SEPARATOR)
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    let tag = segments.join("/");
+    if tag
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == TAG_SEPARATOR)
+    {
+        return None;
+    }
+    Some(tag)
+}
+
+/// Front-matter tags followed by inline tags, without case-insensitive duplicates
+pub fn merge_tags(front_matter: &[String], inline: &[String]) -> Vec<String> {
+    let mut merged: Vec<String> = Vec::new();
+    for tag in front_matter
+        .iter()
+        .chain(inline)
+        .filter_map(|tag| normalize_tag(tag))
+    {
+        if !merged
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(&tag))
+        {
+            merged.push(tag);
+        }
+    }
+    merged
+}
+
+/// The tag and every parent, outermost first (`a/b/c` gives `a`, `a/b`, `a/b/c`)
+pub fn tag_ancestors(tag: &str) -> Vec<&str> {
+    tag.match_indices(TAG_SEPARATOR)
+        .map(|(index, _)| &tag[..index])
+        .chain(std::iter::once(tag))
+        .collect()
+}
+
+/// Whether `tag` is `filter` itself or nested below it
+pub fn tag_matches(tag: &str, filter: &str) -> bool {
+    tag.strip_prefix(filter)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(TAG_SEPARATOR))
+}
+
+/// One level of the tag hierarchy
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagNode {
+    /// Full tag (`math/topology`)
+    pub path: String,
+    /// Last segment (`topology`)
+    pub name: String,
+    /// Articles tagged with this tag or one below it
+    pub count: usize,
+    pub children: Vec<TagNode>,
+}
+
+/// Build the tag hierarchy from each article's tags, sorted by name
+pub fn build_tag_tree<'a>(article_tags: impl IntoIterator<Item = &'a [String]>) -> Vec<TagNode> {
+    // Count each article once per tag level, even if it has several tags below it
+    let mut counts: BTreeMap<&'a str, usize> = BTreeMap::new();
+    for tags in article_tags {
+        let mut levels: Vec<&str> = tags.iter().flat_map(|tag| tag_ancestors(tag)).collect();
+        levels.sort_unstable();
+        levels.dedup();
+        for level in levels {
+            *counts.entry(level).or_default() += 1;
+        }
+    }
+
+    fn children_of(counts: &BTreeMap<&str, usize>, parent: Option<&str>) -> Vec<TagNode> {
+        counts
+            .iter()
+            .filter(|(path, _)| match parent {
+                Some(parent) => path
+                    .strip_prefix(parent)
+                    .and_then(|rest| rest.strip_prefix(TAG_SEPARATOR))
+                    .is_some_and(|rest| !rest.contains(TAG_SEPARATOR)),
+                None => !path.contains(TAG_SEPARATOR),
+            })
+            .map(|(&path, &count)| TagNode {
+                path: path.to_string(),
+                name: path
+                    .rsplit(TAG_SEPARATOR)
+                    .next()
+                    .unwrap_or(path)
+                    .to_string(),
+                count,
+                children: children_of(counts, Some(path)),
+            })
+            .collect()
+    }
+
+    children_of(&counts, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_find_hashtags() {
+        assert_eq!(
+            find_hashtags("Notes on #math/topology and #rust, see #位相空間."),
+            strings(&["math/topology", "rust", "位相空間"])
+        );
+        // Not tags: mid-word, numbers only, bare #
+        assert!(find_hashtags("C# page#anchor issue #123 # heading").is_empty());
+        assert_eq!(find_hashtags("#2024年 #a/"), strings(&["2024年", "a"]));
+    }
+
+    #[test]
+    fn test_validate_tag() {
+        assert!(validate_tag("math/topology").is_ok());
+        assert!(validate_tag("#位相空間/").is_ok());
+        assert!(validate_tag("c++").is_ok());
+        assert!(validate_tag("../etc").is_err());
+        assert!(validate_tag("math/./topology").is_err());
+        assert!(validate_tag("machine learning").is_err());
+        assert!(validate_tag("c#").is_err());
+        for tag in ["a?b", "100%", "a\\b"] {
+            assert!(validate_tag(tag).is_err(), "{tag}");
+            assert_eq!(normalize_tag(tag), None);
+        }
+    }
+
+    #[test]
+    fn test_merge_tags() {
+        let merged = merge_tags(
+            &strings(&["Rust", "#math/topology"]),
+            &strings(&["rust", "math", "math/topology/"]),
+        );
+        assert_eq!(merged, strings(&["Rust", "math/topology", "math"]));
+    }
+
+    #[test]
+    fn test_tag_matches_children() {
+        assert!(tag_matches("math/topology", "math"));
+        assert!(tag_matches("math", "math"));
+        assert!(!tag_matches("mathematics", "math"));
+        assert!(!tag_matches("math", "math/topology"));
+        assert_eq!(
+            tag_ancestors("math/topology/compact"),
+            vec!["math", "math/topology", "math/topology/compact"]
+        );
+    }
+
+    #[test]
+    fn test_build_tag_tree() {
+        let first = strings(&["math/topology", "math/algebra"]);
+        let second = strings(&["math/topology", "rust"]);
+        let tree = build_tag_tree([first.as_slice(), second.as_slice()]);
+
+        assert_eq!(tree.len(), 2);
+        let math = &tree[0];
+        assert_eq!((math.path.as_str(), math.count), ("math", 2));
+        let children: Vec<_> = math
+            .children
+            .iter()
+            .map(|child| (child.name.as_str(), child.count))
+            .collect();
+        assert_eq!(children, [("algebra", 1), ("topology", 2)]);
+        assert_eq!((tree[1].path.as_str(), tree[1].count), ("rust", 1));
+    }
+}
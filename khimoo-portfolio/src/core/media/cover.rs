@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+/// URL prefix under which the images directory is served
+pub const IMAGES_URL_PREFIX: &str = "/articles/img/";
+
+/// Size of a cover variant; both share the 1.91:1 Open Graph ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverVariant {
+    /// Index and tag cards
+    Card,
+    /// Article header and link previews
+    Hero,
+}
+
+impl CoverVariant {
+    pub const ALL: [CoverVariant; 2] = [CoverVariant::Card, CoverVariant::Hero];
+
+    pub fn size(self) -> (u32, u32) {
+        match self {
+            CoverVariant::Card => (600, 315),
+            CoverVariant::Hero => (1200, 630),
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            CoverVariant::Card => "_card",
+            CoverVariant::Hero => "_hero",
+        }
+    }
+
+    /// Path relative to the images directory (`covers/a.webp` → `covers/a_card.png`)
+    ///
+    /// JPEG covers stay JPEG; everything else is written as PNG.
+    pub fn path_for(self, source: &str) -> String {
+        let (stem, extension) = match source.rsplit_once('.') {
+            Some((stem, extension)) if !extension.contains('/') => (stem, extension),
+            _ => (source, ""),
+        };
+        let extension = match extension.to_ascii_lowercase().as_str() {
+            "jpg" | "jpeg" => extension.to_string(),
+            _ => "png".to_string(),
+        };
+        format!("{stem}{}.{extension}", self.suffix())
+    }
+}
+
+/// Whether a file in the images directory is a generated cover variant
+pub fn is_cover_variant(file_name: &str) -> bool {
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem);
+    CoverVariant::ALL
+        .iter()
+        .any(|variant| stem.ends_with(variant.suffix()))
+}
+
+/// Path of a `cover` front-matter value relative to the images directory
+///
+/// Accepts `/articles/img/a.png` (as `author_image` is written) or a bare `a.png`; external URLs
+/// and paths leaving the directory give `None`.
+pub fn cover_source(cover: &str) -> Option<&str> {
+    let cover = cover.trim();
+    if cover.contains("://") {
+        return None;
+    }
+
+    let relative = cover.trim_start_matches('/');
+    let relative = relative
+        .strip_prefix(IMAGES_URL_PREFIX.trim_start_matches('/'))
+        .unwrap_or(relative);
+    if relative.is_empty()
+        || relative
+            .split('/')
+            .any(|part| part == ".." || part.is_empty())
+    {
+        return None;
+    }
+    Some(relative)
+}
+
+/// Site URLs of an article's cover variants
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CoverImage {
+    pub card: String,
+    pub hero: String,
+}
+
+impl CoverImage {
+    /// URLs of the variants generated for a source relative to the images directory
+    pub fn for_source(source: &str) -> Self {
+        let url =
+            |variant: CoverVariant| format!("{IMAGES_URL_PREFIX}{}", variant.path_for(source));
+        Self {
+            card: url(CoverVariant::Card),
+            hero: url(CoverVariant::Hero),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cover_source() {
+        assert_eq!(cover_source("/articles/img/a.png"), Some("a.png"));
+        assert_eq!(cover_source("covers/b.jpg"), Some("covers/b.jpg"));
+        assert_eq!(cover_source("https://example.com/c.png"), None);
+        assert_eq!(cover_source("/articles/img/../secret.png"), None);
+        assert_eq!(cover_source(""), None);
+    }
+
+    #[test]
+    fn test_variant_paths() {
+        assert_eq!(
+            CoverVariant::Card.path_for("covers/a.JPG"),
+            "covers/a_card.JPG"
+        );
+        assert_eq!(CoverVariant::Hero.path_for("b.webp"), "b_hero.png");
+        assert_eq!(CoverVariant::Card.path_for("v1.2/c"), "v1.2/c_card.png");
+        assert_eq!(
+            CoverImage::for_source("a.png"),
+            CoverImage {
+                card: "/articles/img/a_card.png".to_string(),
+                hero: "/articles/img/a_hero.png".to_string(),
+            }
+        );
+        assert!(is_cover_variant("a_hero.png"));
+        assert!(!is_cover_variant("a_small.png"));
+    }
+}
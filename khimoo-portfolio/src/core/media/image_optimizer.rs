@@ -2,6 +2,9 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
+#[cfg(feature = "cli-tools")]
+use super::cover::{is_cover_variant, CoverVariant};
+
 #[cfg(feature = "cli-tools")]
 use anyhow::Context;
 #[cfg(feature = "cli-tools")]
@@ -256,6 +259,52 @@ impl ImageOptimizer {
         })
     }
 
+    /// Cut a cover into its card and hero variants next to it in `images_dir`
+    ///
+    /// Variants newer than the source are kept; returns whether anything was written.
+    #[cfg(feature = "cli-tools")]
+    pub fn optimize_cover(&self, images_dir: &Path, source: &str) -> Result<bool> {
+        let input_path = images_dir.join(source);
+        let source_modified = fs::metadata(&input_path)
+            .and_then(|metadata| metadata.modified())
+            .with_context(|| format!("Cover image not found: {input_path:?}"))?;
+
+        let outputs: Vec<(CoverVariant, PathBuf)> = CoverVariant::ALL
+            .iter()
+            .map(|&variant| (variant, images_dir.join(variant.path_for(source))))
+            .collect();
+        let up_to_date = outputs.iter().all(|(_, path)| {
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| modified >= source_modified)
+        });
+        if up_to_date {
+            return Ok(false);
+        }
+
+        let img = image::open(&input_path)
+            .with_context(|| format!("Failed to open image: {input_path:?}"))?;
+        for (variant, output_path) in outputs {
+            let (width, height) = variant.size();
+            // Crop to the variant's ratio from the centre rather than letterboxing
+            let resized = img.resize_to_fill(width, height, image::imageops::FilterType::Lanczos3);
+            // JPEG has no alpha channel
+            let resized = match ImageFormat::from_path(&output_path) {
+                Ok(ImageFormat::Jpeg) => DynamicImage::ImageRgb8(resized.to_rgb8()),
+                _ => resized,
+            };
+            resized
+                .save(&output_path)
+                .with_context(|| format!("Failed to save cover: {output_path:?}"))?;
+
+            if self.verbose {
+                println!("🖼️  Created cover ({width}x{height}): {output_path:?}");
+            }
+        }
+
+        Ok(true)
+    }
+
     #[cfg(feature = "cli-tools")]
     fn save_webp(&self, img: &DynamicImage, path: &Path) -> Result<()> {
         // For now, save as PNG since WebP support in the image crate requires additional features
@@ -333,13 +382,14 @@ impl ImageOptimizer {
             let path = entry.path();
 
             if path.is_file() {
-                // Skip already optimized images
+                // Skip already optimized images and cover variants
                 if let Some(file_name) = path.file_name() {
                     let file_name_str = file_name.to_string_lossy();
                     if file_name_str.contains("_small")
                         || file_name_str.contains("_medium")
                         || file_name_str.contains("_large")
                         || file_name_str.contains("_tiny")
+                        || is_cover_variant(&file_name_str)
                     {
                         if self.verbose {
                             println!("⏭️  Skipping already optimized: {path:?}");
@@ -549,6 +599,27 @@ External image: ![External](https://example.com/image.png)
         assert!(!images.iter().any(|img| img.contains("example.com")));
     }
 
+    #[cfg(feature = "cli-tools")]
+    #[test]
+    fn test_optimize_cover() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("covers")).unwrap();
+        image::RgbImage::new(1400, 700)
+            .save(dir.path().join("covers/wide.jpg"))
+            .unwrap();
+
+        let optimizer = ImageOptimizer::with_defaults();
+        assert!(optimizer.optimize_cover(dir.path(), "covers/wide.jpg").unwrap());
+        let card = image::open(dir.path().join("covers/wide_card.jpg")).unwrap();
+        let hero = image::open(dir.path().join("covers/wide_hero.jpg")).unwrap();
+        assert_eq!((card.width(), card.height()), (600, 315));
+        assert_eq!((hero.width(), hero.height()), (1200, 630));
+
+        // Up-to-date variants are left alone
+        assert!(!optimizer.optimize_cover(dir.path(), "covers/wide.jpg").unwrap());
+        assert!(optimizer.optimize_cover(dir.path(), "missing.png").is_err());
+    }
+
     #[test]
     fn test_image_optimizer_creation() {
         let config = ImageOptimizationConfig {
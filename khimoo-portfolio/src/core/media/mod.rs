@@ -3,9 +3,13 @@
 //! This module contains core logic for media processing, image optimization,
 //! and asset management functionality.
 
+pub mod cover;
 pub mod image_optimizer;
+#[cfg(feature = "cli-tools")]
+pub mod og_image;
 
 // Re-export main components
+pub use cover::{cover_source, CoverImage, CoverVariant};
 pub use image_optimizer::{
     CompressedImage, ImageOptimizationConfig, ImageOptimizer, ImageProcessingError,
     OptimizedImageSet, Thumbnail,
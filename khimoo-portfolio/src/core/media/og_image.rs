@@ -0,0 +1,358 @@
+use ab_glyph::{point, Font, FontVec, PxScale, ScaleFont};
+use anyhow::{Context, Result};
+use image::{Rgb, RgbImage};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::core::articles::stats::is_cjk;
+
+/// Size of generated Open Graph images
+pub const OG_IMAGE_SIZE: (u32, u32) = (1200, 630);
+
+const PADDING: f32 = 80.0;
+const ACCENT_BAR_WIDTH: u32 = 16;
+/// Title sizes tried in turn until the title fits in `MAX_TITLE_LINES`
+const TITLE_SIZES: [f32; 3] = [72.0, 60.0, 52.0];
+const MAX_TITLE_LINES: usize = 3;
+const TAG_SIZE: f32 = 32.0;
+const SITE_NAME_SIZE: f32 = 28.0;
+const ELLIPSIS: &str = "…";
+
+/// `[og_image]` section of project.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct OgImageConfig {
+    /// TrueType/OpenType font (relative to project root); needs CJK glyphs for Japanese titles
+    pub font: Option<String>,
+    /// Printed in the bottom-right corner
+    pub site_name: Option<String>,
+    pub background: String,
+    pub text_color: String,
+    /// Bar and tag colour for articles whose first tag has no registered colour
+    pub accent: String,
+}
+
+impl Default for OgImageConfig {
+    fn default() -> Self {
+        Self {
+            font: None,
+            site_name: None,
+            background: "#081D35".to_string(),
+            text_color: "#e0e0e0".to_string(),
+            accent: "#66b3ff".to_string(),
+        }
+    }
+}
+
+/// Draws link-preview cards for articles without a cover: title, tags and site name
+pub struct OgImageRenderer {
+    font: FontVec,
+    site_name: Option<String>,
+    background: Rgb<u8>,
+    text_color: Rgb<u8>,
+    accent: Rgb<u8>,
+}
+
+impl OgImageRenderer {
+    /// Load the font at `font_path`, taking colours and site name from `config`
+    pub fn load(font_path: &Path, config: &OgImageConfig) -> Result<Self> {
+        let data = std::fs::read(font_path)
+            .with_context(|| format!("Failed to read font: {}", font_path.display()))?;
+        let font = FontVec::try_from_vec(data)
+            .with_context(|| format!("Invalid font file: {}", font_path.display()))?;
+
+        let color = |value: &str| {
+            parse_hex_color(value).with_context(|| format!("Invalid OG image colour: {value}"))
+        };
+        Ok(Self {
+            font,
+            site_name: config.site_name.clone(),
+            background: color(&config.background)?,
+            text_color: color(&config.text_color)?,
+            accent: color(&config.accent)?,
+        })
+    }
+
+    /// Render the card; `accent` (a tag colour) replaces the configured accent when it is hex
+    pub fn render(&self, title: &str, tags: &[String], accent: Option<&str>) -> RgbImage {
+        let (width, height) = OG_IMAGE_SIZE;
+        let accent = accent.and_then(parse_hex_color).unwrap_or(self.accent);
+        let mut image = RgbImage::from_pixel(width, height, self.background);
+
+        for x in 0..ACCENT_BAR_WIDTH {
+            for y in 0..height {
+                image.put_pixel(x, y, accent);
+            }
+        }
+
+        let max_width = width as f32 - 2.0 * PADDING;
+        let (scale, lines) = self.layout_title(title, max_width);
+        let line_height = scale.y * 1.3;
+        let mut baseline = PADDING + self.font.as_scaled(scale).ascent();
+        for line in &lines {
+            self.draw_text(&mut image, line, PADDING, baseline, scale, self.text_color);
+            baseline += line_height;
+        }
+
+        let tag_scale = PxScale::from(TAG_SIZE);
+        let tag_line = tags
+            .iter()
+            .map(|tag| format!("#{tag}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        if let Some(tag_line) = wrap_lines(&tag_line, max_width, 1, |text| {
+            self.text_width(text, tag_scale)
+        })
+        .first()
+        {
+            let tag_baseline = height as f32 - PADDING - SITE_NAME_SIZE * 1.6;
+            self.draw_text(
+                &mut image,
+                tag_line,
+                PADDING,
+                tag_baseline,
+                tag_scale,
+                accent,
+            );
+        }
+
+        if let Some(site_name) = &self.site_name {
+            let site_scale = PxScale::from(SITE_NAME_SIZE);
+            let x = width as f32 - PADDING - self.text_width(site_name, site_scale);
+            let site_baseline = height as f32 - PADDING;
+            let color = blend(self.background, self.text_color, 0.7);
+            self.draw_text(&mut image, site_name, x, site_baseline, site_scale, color);
+        }
+
+        image
+    }
+
+    /// Largest title size that fits; the smallest one truncates with `…`
+    fn layout_title(&self, title: &str, max_width: f32) -> (PxScale, Vec<String>) {
+        for size in TITLE_SIZES {
+            let scale = PxScale::from(size);
+            let measure = |text: &str| self.text_width(text, scale);
+            let lines = wrap_lines(title, max_width, MAX_TITLE_LINES + 1, measure);
+            if lines.len() <= MAX_TITLE_LINES {
+                return (scale, lines);
+            }
+        }
+
+        let scale = PxScale::from(TITLE_SIZES[TITLE_SIZES.len() - 1]);
+        let lines = wrap_lines(title, max_width, MAX_TITLE_LINES, |text| {
+            self.text_width(text, scale)
+        });
+        (scale, lines)
+    }
+
+    fn text_width(&self, text: &str, scale: PxScale) -> f32 {
+        let font = self.font.as_scaled(scale);
+        let mut width = 0.0;
+        let mut previous = None;
+        for c in text.chars() {
+            let glyph = font.glyph_id(c);
+            if let Some(previous) = previous {
+                width += font.kern(previous, glyph);
+            }
+            width += font.h_advance(glyph);
+            previous = Some(glyph);
+        }
+        width
+    }
+
+    fn draw_text(
+        &self,
+        image: &mut RgbImage,
+        text: &str,
+        x: f32,
+        baseline: f32,
+        scale: PxScale,
+        color: Rgb<u8>,
+    ) {
+        let font = self.font.as_scaled(scale);
+        let mut caret = x;
+        let mut previous = None;
+        for c in text.chars() {
+            let glyph_id = font.glyph_id(c);
+            if let Some(previous) = previous {
+                caret += font.kern(previous, glyph_id);
+            }
+            let glyph = glyph_id.with_scale_and_position(scale, point(caret, baseline));
+            caret += font.h_advance(glyph_id);
+            previous = Some(glyph_id);
+
+            let Some(outline) = self.font.outline_glyph(glyph) else {
+                continue;
+            };
+            let bounds = outline.px_bounds();
+            outline.draw(|gx, gy, coverage| {
+                let px = bounds.min.x as i64 + i64::from(gx);
+                let py = bounds.min.y as i64 + i64::from(gy);
+                if px < 0 || py < 0 || px >= image.width() as i64 || py >= image.height() as i64 {
+                    return;
+                }
+                let pixel = image.get_pixel_mut(px as u32, py as u32);
+                *pixel = blend(*pixel, color, coverage);
+            });
+        }
+    }
+}
+
+fn blend(background: Rgb<u8>, foreground: Rgb<u8>, alpha: f32) -> Rgb<u8> {
+    let alpha = alpha.clamp(0.0, 1.0);
+    let mix = |b: u8, f: u8| (b as f32 + (f as f32 - b as f32) * alpha).round() as u8;
+    Rgb([
+        mix(background[0], foreground[0]),
+        mix(background[1], foreground[1]),
+        mix(background[2], foreground[2]),
+    ])
+}
+
+/// `#rgb` or `#rrggbb`
+pub fn parse_hex_color(value: &str) -> Option<Rgb<u8>> {
+    let hex = value.trim().strip_prefix('#')?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+    match hex.len() {
+        3 => {
+            let mut rgb = [0; 3];
+            for (i, c) in hex.chars().enumerate() {
+                rgb[i] = channel(&c.to_string())? * 17;
+            }
+            Some(Rgb(rgb))
+        }
+        6 => Some(Rgb([
+            channel(&hex[0..2])?,
+            channel(&hex[2..4])?,
+            channel(&hex[4..6])?,
+        ])),
+        _ => None,
+    }
+}
+
+/// Break `text` into at most `max_lines` lines no wider than `max_width`
+///
+/// Latin text breaks between words and CJK text between characters. Punctuation never starts
+/// a line, and text beyond the last line is cut with `…`.
+pub fn wrap_lines(
+    text: &str,
+    max_width: f32,
+    max_lines: usize,
+    measure: impl Fn(&str) -> f32,
+) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut truncated = false;
+
+    for token in tokens(text) {
+        if token.trim().is_empty() {
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            continue;
+        }
+
+        let candidate = format!("{current}{token}");
+        let is_punctuation = !token.chars().any(|c| c.is_alphanumeric() || is_cjk(c));
+        if current.trim().is_empty() || is_punctuation || measure(candidate.trim_end()) <= max_width
+        {
+            current = candidate;
+            continue;
+        }
+
+        lines.push(current.trim_end().to_string());
+        if lines.len() == max_lines {
+            truncated = true;
+            break;
+        }
+        current = token.to_string();
+    }
+
+    if !truncated && !current.trim().is_empty() {
+        lines.push(current.trim_end().to_string());
+    }
+
+    if truncated {
+        if let Some(last) = lines.last_mut() {
+            while !last.is_empty() && measure(&format!("{last}{ELLIPSIS}")) > max_width {
+                last.pop();
+            }
+            let trimmed_len = last.trim_end().len();
+            last.truncate(trimmed_len);
+            last.push_str(ELLIPSIS);
+        }
+    }
+
+    lines
+}
+
+/// Words, single CJK characters or punctuation marks, and runs of whitespace
+fn tokens(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut previous_kind = None;
+
+    for (index, c) in text.char_indices() {
+        let kind = if c.is_whitespace() {
+            0
+        } else if is_cjk(c) || matches!(c, '\u{3000}'..='\u{303f}' | '\u{ff01}'..='\u{ff65}') {
+            // CJK punctuation stands alone too, so it can hang on the previous line
+            1
+        } else {
+            2
+        };
+        // Every CJK character is a token of its own
+        if previous_kind.is_some_and(|previous| previous != kind || kind == 1) {
+            tokens.push(&text[start..index]);
+            start = index;
+        }
+        previous_kind = Some(kind);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monospace(text: &str) -> f32 {
+        text.chars().count() as f32
+    }
+
+    #[test]
+    fn test_wrap_latin_between_words() {
+        assert_eq!(
+            wrap_lines("Compactness in topological spaces", 14.0, 3, monospace),
+            ["Compactness in", "topological", "spaces"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_cjk_between_characters() {
+        assert_eq!(
+            wrap_lines("位相空間のコンパクト性、Rust で", 6.0, 3, monospace),
+            ["位相空間のコ", "ンパクト性、", "Rust で"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_truncates_with_ellipsis() {
+        assert_eq!(
+            wrap_lines("one two three four five", 9.0, 2, monospace),
+            ["one two", "three…"]
+        );
+    }
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(parse_hex_color("#dea584"), Some(Rgb([0xde, 0xa5, 0x84])));
+        assert_eq!(parse_hex_color("#fff"), Some(Rgb([255, 255, 255])));
+        assert_eq!(parse_hex_color("rebeccapurple"), None);
+        assert_eq!(parse_hex_color("#12345"), None);
+    }
+}
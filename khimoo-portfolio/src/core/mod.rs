@@ -1,10 +1,13 @@
 //! Core business logic module
 //!
 //! This module contains UI-independent business logic for article processing,
-//! metadata extraction, link management, and media optimization.
+//! metadata extraction, link management, media optimization, rendering, full-text search and site metadata.
 
 pub mod articles;
 pub mod media;
+pub mod render;
+pub mod search;
+pub mod site;
 
 // Re-export commonly used items from articles
 pub use articles::{
@@ -17,3 +20,12 @@ pub use media::{
     CompressedImage, ImageOptimizationConfig, ImageOptimizer, ImageProcessingError,
     OptimizedImageSet, Thumbnail,
 };
+
+// Re-export commonly used items from render
+pub use render::{HtmlSanitizer, LinkRewriter, MarkdownRenderer, SanitizerPolicy};
+
+// Re-export commonly used items from search
+pub use search::{SearchHit, SearchIndex};
+
+// Re-export commonly used items from site
+pub use site::{PageHead, SiteConfig};
@@ -0,0 +1,437 @@
+use pulldown_cmark::{CowStr, Event, HeadingLevel, Parser, Tag, TagEnd};
+use std::collections::HashMap;
+use std::ops::Range;
+
+use super::links::escape_attribute;
+use super::markdown::markdown_options;
+use crate::core::articles::bibliography::{CitationStyle, Reference};
+
+/// Class of the inline citation wrapper
+pub const CITATION_CLASS: &str = "citation";
+
+/// Class of the references list appended to cited articles
+pub const REFERENCES_CLASS: &str = "references";
+
+/// Heading of the references section
+const REFERENCES_HEADING: &str = "参考文献";
+
+/// One cited work inside a `[...]` citation group
+#[derive(Debug, Clone, PartialEq)]
+pub struct CitationItem {
+    pub key: String,
+    /// Text before the key, e.g. `see`
+    pub prefix: String,
+    /// Text after the key, e.g. `p. 12`
+    pub locator: String,
+}
+
+/// Find Pandoc-style citation groups such as `[@key]`, `[see @a, p. 12; @b]`
+pub fn find_citations(text: &str) -> Vec<(Range<usize>, Vec<CitationItem>)> {
+    let mut groups = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(offset) = text[search_from..].find('[') {
+        let start = search_from + offset;
+        let inner_start = start + 1;
+        let Some(length) = text[inner_start..].find(']') else {
+            break;
+        };
+        let inner = &text[inner_start..inner_start + length];
+
+        // A nested '[' starts a new candidate group
+        if let Some(nested) = inner.rfind('[') {
+            search_from = inner_start + nested;
+            continue;
+        }
+
+        let end = inner_start + length + 1;
+        match parse_citation_group(inner) {
+            Some(items) => {
+                groups.push((start..end, items));
+                search_from = end;
+            }
+            None => search_from = inner_start,
+        }
+    }
+
+    groups
+}
+
+/// Collect cited keys in order of first appearance, ignoring code
+pub fn collect_citation_keys(content: &str) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for_each_text_run(Parser::new_ext(content, markdown_options()), |text| {
+        for (_, items) in find_citations(text) {
+            for item in items {
+                if !keys.contains(&item.key) {
+                    keys.push(item.key);
+                }
+            }
+        }
+    });
+    keys
+}
+
+/// Replace citations with links to the references section and append that section
+pub fn resolve_citations<'a>(
+    events: Vec<Event<'a>>,
+    references: &[Reference],
+    style: CitationStyle,
+) -> Vec<Event<'a>> {
+    if references.is_empty() {
+        return events;
+    }
+
+    let labels: HashMap<&str, &str> = references
+        .iter()
+        .map(|reference| (reference.key.as_str(), reference.label.as_str()))
+        .collect();
+
+    let mut output = Vec::with_capacity(events.len());
+    let mut pending = String::new();
+    let mut code_depth = 0usize;
+
+    for event in events {
+        match event {
+            Event::Text(text) if code_depth == 0 => {
+                pending.push_str(&text);
+                continue;
+            }
+            Event::Start(Tag::CodeBlock(_)) => code_depth += 1,
+            Event::End(TagEnd::CodeBlock) => code_depth = code_depth.saturating_sub(1),
+            _ => {}
+        }
+        flush_text(&mut pending, &labels, style, &mut output);
+        output.push(event);
+    }
+    flush_text(&mut pending, &labels, style, &mut output);
+
+    output.extend(references_section(references, style));
+    output
+}
+
+/// Emit buffered text, turning citation groups into inline HTML
+fn flush_text<'a>(
+    pending: &mut String,
+    labels: &HashMap<&str, &str>,
+    style: CitationStyle,
+    output: &mut Vec<Event<'a>>,
+) {
+    if pending.is_empty() {
+        return;
+    }
+    let text = std::mem::take(pending);
+
+    let mut last = 0;
+    for (range, items) in find_citations(&text) {
+        if !items
+            .iter()
+            .any(|item| labels.contains_key(item.key.as_str()))
+        {
+            continue;
+        }
+        if range.start > last {
+            output.push(Event::Text(CowStr::from(
+                text[last..range.start].to_string(),
+            )));
+        }
+        output.push(Event::InlineHtml(CowStr::from(render_citation(
+            &items, labels, style,
+        ))));
+        last = range.end;
+    }
+    if last < text.len() {
+        output.push(Event::Text(CowStr::from(text[last..].to_string())));
+    }
+}
+
+fn render_citation(
+    items: &[CitationItem],
+    labels: &HashMap<&str, &str>,
+    style: CitationStyle,
+) -> String {
+    let (open, close) = match style {
+        CitationStyle::Numeric => ('[', ']'),
+        CitationStyle::AuthorYear => ('(', ')'),
+    };
+
+    let parts: Vec<String> = items
+        .iter()
+        .map(|item| {
+            let mut part = String::new();
+            if !item.prefix.is_empty() {
+                part.push_str(&escape_attribute(&item.prefix));
+                part.push(' ');
+            }
+            match labels.get(item.key.as_str()) {
+                Some(label) => part.push_str(&format!(
+                    "<a href=\"#{}\">{}</a>",
+                    escape_attribute(&reference_id(&item.key)),
+                    escape_attribute(label)
+                )),
+                // Unknown keys stay visible so the gap is obvious; validate-links reports them
+                None => part.push_str(&escape_attribute(&format!("@{}", item.key))),
+            }
+            if !item.locator.is_empty() {
+                part.push_str(", ");
+                part.push_str(&escape_attribute(&item.locator));
+            }
+            part
+        })
+        .collect();
+
+    format!(
+        "<span class=\"{CITATION_CLASS}\">{open}{}{close}</span>",
+        parts.join("; ")
+    )
+}
+
+/// Heading plus list of references; the heading gets its ID from the anchor pass
+fn references_section<'a>(references: &[Reference], style: CitationStyle) -> Vec<Event<'a>> {
+    let list_tag = match style {
+        CitationStyle::Numeric => "ol",
+        CitationStyle::AuthorYear => "ul",
+    };
+
+    let mut list = format!("<{list_tag} class=\"{REFERENCES_CLASS}\">\n");
+    for reference in references {
+        list.push_str(&format!(
+            "<li id=\"{}\">{}",
+            escape_attribute(&reference_id(&reference.key)),
+            escape_attribute(&reference.text)
+        ));
+        if let Some(url) = &reference.url {
+            list.push_str(&format!(
+                " <a href=\"{}\">{}</a>",
+                escape_attribute(url),
+                escape_attribute(url)
+            ));
+        }
+        list.push_str("</li>\n");
+    }
+    list.push_str(&format!("</{list_tag}>\n"));
+
+    let heading = Tag::Heading {
+        level: HeadingLevel::H2,
+        id: None,
+        classes: Vec::new(),
+        attrs: Vec::new(),
+    };
+    vec![
+        Event::Start(heading),
+        Event::Text(CowStr::Borrowed(REFERENCES_HEADING)),
+        Event::End(TagEnd::Heading(HeadingLevel::H2)),
+        Event::Html(CowStr::from(list)),
+    ]
+}
+
+/// ID of a reference's entry in the references list
+pub(crate) fn reference_id(key: &str) -> String {
+    format!("ref-{key}")
+}
+
+/// Call `f` with each run of adjacent text outside code blocks
+pub(crate) fn for_each_text_run<'a>(
+    events: impl Iterator<Item = Event<'a>>,
+    mut f: impl FnMut(&str),
+) {
+    let mut pending = String::new();
+    let mut code_depth = 0usize;
+
+    for event in events {
+        match event {
+            Event::Text(text) if code_depth == 0 => {
+                pending.push_str(&text);
+                continue;
+            }
+            Event::Start(Tag::CodeBlock(_)) => code_depth += 1,
+            Event::End(TagEnd::CodeBlock) => code_depth = code_depth.saturating_sub(1),
+            _ => {}
+        }
+        if !pending.is_empty() {
+            f(&pending);
+            pending.clear();
+        }
+    }
+    if !pending.is_empty() {
+        f(&pending);
+    }
+}
+
+/// Parse the inside of `[...]`; every `;`-separated item must contain one `@key`
+fn parse_citation_group(inner: &str) -> Option<Vec<CitationItem>> {
+    if !inner.contains('@') {
+        return None;
+    }
+    inner.split(';').map(parse_citation_item).collect()
+}
+
+fn parse_citation_item(item: &str) -> Option<CitationItem> {
+    let item = item.trim();
+
+    // '@' must start a word so e-mail addresses are not taken as citations
+    let at = item.char_indices().find_map(|(index, c)| {
+        let starts_word = item[..index]
+            .chars()
+            .next_back()
+            .is_none_or(|previous| previous.is_whitespace());
+        (c == '@' && starts_word).then_some(index)
+    })?;
+
+    let key_start = at + 1;
+    let key_len = citation_key_len(&item[key_start..]);
+    if key_len == 0 {
+        return None;
+    }
+    let key_end = key_start + key_len;
+
+    let rest = &item[key_end..];
+    if !(rest.is_empty() || rest.starts_with(',') || rest.starts_with(char::is_whitespace)) {
+        return None;
+    }
+
+    Some(CitationItem {
+        key: item[key_start..key_end].to_string(),
+        prefix: item[..at].trim().to_string(),
+        locator: rest.trim_start_matches(',').trim().to_string(),
+    })
+}
+
+/// Length of a citation key: alphanumerics and `_`, with internal punctuation like `:` or `-`
+fn citation_key_len(text: &str) -> usize {
+    let is_key_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut chars = text.char_indices().peekable();
+    let mut len = 0;
+
+    while let Some((index, c)) = chars.next() {
+        if is_key_char(c) {
+            len = index + c.len_utf8();
+        } else if ":.#$%&-+?<>~/".contains(c)
+            && len > 0
+            && chars.peek().is_some_and(|&(_, next)| is_key_char(next))
+        {
+            continue;
+        } else {
+            break;
+        }
+    }
+
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::html;
+
+    fn references() -> Vec<Reference> {
+        vec![
+            Reference {
+                key: "munkres2000".to_string(),
+                label: "1".to_string(),
+                text: "Munkres, James R. (2000). Topology. Prentice Hall.".to_string(),
+                url: None,
+            },
+            Reference {
+                key: "maclane:cwm".to_string(),
+                label: "2".to_string(),
+                text: "Mac Lane, Saunders (1971). Categories for the Working Mathematician."
+                    .to_string(),
+                url: Some("https://doi.org/10.1007/978-1-4757-4721-8".to_string()),
+            },
+        ]
+    }
+
+    fn render(content: &str, style: CitationStyle) -> String {
+        let events = Parser::new_ext(content, markdown_options()).collect();
+        let mut output = String::new();
+        html::push_html(
+            &mut output,
+            resolve_citations(events, &references(), style).into_iter(),
+        );
+        output
+    }
+
+    #[test]
+    fn test_find_citations() {
+        let text =
+            "As shown [see @munkres2000, p. 12; @maclane:cwm] and [@x]. Mail me@example.com [link]";
+        let groups = find_citations(text);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(
+            &text[groups[0].0.clone()],
+            "[see @munkres2000, p. 12; @maclane:cwm]"
+        );
+        assert_eq!(
+            groups[0].1,
+            vec![
+                CitationItem {
+                    key: "munkres2000".to_string(),
+                    prefix: "see".to_string(),
+                    locator: "p. 12".to_string(),
+                },
+                CitationItem {
+                    key: "maclane:cwm".to_string(),
+                    prefix: String::new(),
+                    locator: String::new(),
+                },
+            ]
+        );
+        assert_eq!(groups[1].1[0].key, "x");
+    }
+
+    #[test]
+    fn test_ignores_non_citations() {
+        assert!(find_citations("[me@example.com]").is_empty());
+        assert!(find_citations("[@]").is_empty());
+        assert!(find_citations("[text; @key]").is_empty());
+        assert!(find_citations("[plain link text]").is_empty());
+    }
+
+    #[test]
+    fn test_collect_citation_keys_skips_code() {
+        let content = "Intro [@b].\n\n```\n[@code]\n```\n\nLater [@a; @b] and `[@inline]`.\n";
+        assert_eq!(collect_citation_keys(content), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn test_resolve_numeric_citations() {
+        let html = render(
+            "Compact spaces [@munkres2000, p. 12; @maclane:cwm].",
+            CitationStyle::Numeric,
+        );
+
+        assert!(html.contains(
+            "<span class=\"citation\">[<a href=\"#ref-munkres2000\">1</a>, p. 12; <a href=\"#ref-maclane:cwm\">2</a>]</span>"
+        ));
+        assert!(html.contains("<h2>参考文献</h2>"));
+        assert!(html.contains("<ol class=\"references\">"));
+        assert!(html.contains(
+            "<li id=\"ref-munkres2000\">Munkres, James R. (2000). Topology. Prentice Hall.</li>"
+        ));
+        assert!(html.contains("<a href=\"https://doi.org/10.1007/978-1-4757-4721-8\">"));
+    }
+
+    #[test]
+    fn test_resolve_author_year_and_unknown_keys() {
+        let mut refs = references();
+        refs[0].label = "Munkres 2000".to_string();
+        let events = Parser::new_ext("See [@munkres2000; @unknown].", markdown_options()).collect();
+        let mut html = String::new();
+        html::push_html(
+            &mut html,
+            resolve_citations(events, &refs, CitationStyle::AuthorYear).into_iter(),
+        );
+
+        assert!(html.contains("(<a href=\"#ref-munkres2000\">Munkres 2000</a>; @unknown)"));
+        assert!(html.contains("<ul class=\"references\">"));
+    }
+
+    #[test]
+    fn test_no_references_leaves_text() {
+        let events: Vec<_> = Parser::new_ext("See [@munkres2000].", markdown_options()).collect();
+        let resolved = resolve_citations(events.clone(), &[], CitationStyle::Numeric);
+        assert_eq!(resolved, events);
+    }
+}
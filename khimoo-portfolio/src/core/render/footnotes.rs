@@ -0,0 +1,171 @@
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+use std::collections::HashMap;
+
+use super::links::escape_attribute;
+
+/// Class of the link from a footnote definition back to its first reference
+pub const FOOTNOTE_BACKREF_CLASS: &str = "footnote-backref";
+
+/// Number footnotes by first reference and add back-references to their definitions
+pub fn add_footnote_backrefs<'a>(events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+    let mut numbers: HashMap<String, usize> = HashMap::new();
+    let mut reference_counts: HashMap<String, usize> = HashMap::new();
+
+    // Numbers follow the order in which footnotes are first referenced
+    for event in &events {
+        if let Event::FootnoteReference(name) = event {
+            let next = numbers.len() + 1;
+            numbers.entry(name.to_string()).or_insert(next);
+        }
+    }
+
+    let mut output = Vec::with_capacity(events.len());
+    let mut iter = events.into_iter();
+
+    while let Some(event) = iter.next() {
+        match event {
+            Event::FootnoteReference(name) => {
+                let number = numbers[name.as_ref()];
+                let count = reference_counts.entry(name.to_string()).or_insert(0);
+                *count += 1;
+                let reference_id = numbered_reference_id(&name, *count);
+
+                output.push(Event::InlineHtml(CowStr::from(format!(
+                    "<sup class=\"footnote-reference\" id=\"{}\"><a href=\"#{}\">{number}</a></sup>",
+                    escape_attribute(&reference_id),
+                    escape_attribute(&footnote_definition_id(&name)),
+                ))));
+            }
+            Event::Start(Tag::FootnoteDefinition(name)) => {
+                let referenced = numbers.contains_key(name.as_ref());
+                let next = numbers.len() + 1;
+                let number = *numbers.entry(name.to_string()).or_insert(next);
+
+                let mut body = Vec::new();
+                for inner in iter.by_ref() {
+                    if matches!(inner, Event::End(TagEnd::FootnoteDefinition)) {
+                        break;
+                    }
+                    body.push(inner);
+                }
+
+                output.push(Event::Html(CowStr::from(format!(
+                    "<div class=\"footnote-definition\" id=\"{}\"><sup class=\"footnote-definition-label\">{number}</sup>",
+                    escape_attribute(&footnote_definition_id(&name)),
+                ))));
+
+                if referenced {
+                    let backref = Event::InlineHtml(CowStr::from(format!(
+                        " <a class=\"{FOOTNOTE_BACKREF_CLASS}\" href=\"#{}\" aria-label=\"本文へ戻る\">↩</a>",
+                        escape_attribute(&footnote_reference_id(&name)),
+                    )));
+
+                    // Keep the back-reference inside the last paragraph so it sits on the same line
+                    let closing = if matches!(body.last(), Some(Event::End(TagEnd::Paragraph))) {
+                        body.pop()
+                    } else {
+                        None
+                    };
+                    output.extend(body);
+                    output.push(backref);
+                    output.extend(closing);
+                } else {
+                    output.extend(body);
+                }
+
+                output.push(Event::Html(CowStr::Borrowed("</div>\n")));
+            }
+            other => output.push(other),
+        }
+    }
+
+    output
+}
+
+/// IDs `add_footnote_backrefs` gives the references and definitions in `events`
+pub fn footnote_ids(events: &[Event]) -> Vec<String> {
+    let mut reference_counts: HashMap<&str, usize> = HashMap::new();
+    let mut ids = Vec::new();
+    for event in events {
+        match event {
+            Event::FootnoteReference(name) => {
+                let count = reference_counts.entry(name.as_ref()).or_insert(0);
+                *count += 1;
+                ids.push(numbered_reference_id(name, *count));
+            }
+            Event::Start(Tag::FootnoteDefinition(name)) => ids.push(footnote_definition_id(name)),
+            _ => {}
+        }
+    }
+    ids
+}
+
+/// ID of the `count`-th reference to a footnote; later ones get a `-2`, `-3`, ... suffix
+fn numbered_reference_id(name: &str, count: usize) -> String {
+    if count == 1 {
+        footnote_reference_id(name)
+    } else {
+        format!("{}-{count}", footnote_reference_id(name))
+    }
+}
+
+fn footnote_reference_id(name: &str) -> String {
+    format!("fnref-{name}")
+}
+
+fn footnote_definition_id(name: &str) -> String {
+    format!("fn-{name}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::render::markdown::markdown_options;
+    use pulldown_cmark::{html, Parser};
+
+    fn render(content: &str) -> String {
+        let events = Parser::new_ext(content, markdown_options()).collect();
+        let mut output = String::new();
+        html::push_html(&mut output, add_footnote_backrefs(events).into_iter());
+        output
+    }
+
+    #[test]
+    fn test_footnotes_are_numbered_by_reference_order() {
+        let html = render("First[^b] and second[^a].\n\n[^a]: Note A\n[^b]: Note B\n");
+
+        assert!(html.contains(
+            "<sup class=\"footnote-reference\" id=\"fnref-b\"><a href=\"#fn-b\">1</a></sup>"
+        ));
+        assert!(html.contains(
+            "<sup class=\"footnote-reference\" id=\"fnref-a\"><a href=\"#fn-a\">2</a></sup>"
+        ));
+        assert!(html.contains("<div class=\"footnote-definition\" id=\"fn-a\"><sup class=\"footnote-definition-label\">2</sup>"));
+    }
+
+    #[test]
+    fn test_footnote_definitions_link_back() {
+        let html = render("Text[^note] again[^note].\n\n[^note]: The note.\n");
+
+        assert!(html.contains("id=\"fnref-note\""));
+        assert!(html.contains("id=\"fnref-note-2\""));
+        assert!(html.contains(
+            "<p>The note. <a class=\"footnote-backref\" href=\"#fnref-note\" aria-label=\"本文へ戻る\">↩</a></p>"
+        ));
+    }
+
+    #[test]
+    fn test_footnote_ids_match_rendered_ids() {
+        let content = "Text[^note] again[^note].\n\n[^note]: The note.\n";
+        let events: Vec<Event> = Parser::new_ext(content, markdown_options()).collect();
+
+        assert_eq!(
+            footnote_ids(&events),
+            vec!["fnref-note", "fnref-note-2", "fn-note"]
+        );
+        let html = render(content);
+        for id in footnote_ids(&events) {
+            assert!(html.contains(&format!("id=\"{id}\"")), "missing {id}");
+        }
+    }
+}
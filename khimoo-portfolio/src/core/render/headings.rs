@@ -0,0 +1,205 @@
+use pulldown_cmark::{CowStr, Event, HeadingLevel, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::links::escape_attribute;
+
+/// Class of the hover anchor link appended to every heading
+pub const HEADING_ANCHOR_CLASS: &str = "heading-anchor";
+
+/// Heading levels included in the table of contents (h2–h4)
+const TOC_LEVELS: std::ops::RangeInclusive<u8> = 2..=4;
+
+/// Single table of contents entry
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TocEntry {
+    pub level: u8,
+    pub id: String,
+    pub text: String,
+}
+
+/// Generates stable, unique heading IDs within one document
+#[derive(Debug, Default)]
+pub struct HeadingIdGenerator {
+    used: HashMap<String, usize>,
+}
+
+impl HeadingIdGenerator {
+    /// Create a new generator with no IDs used yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the slug for a heading, suffixed with `-1`, `-2`, ... on repeats
+    pub fn unique_id(&mut self, text: &str) -> String {
+        let base = slugify_heading(text);
+        let count = self.used.entry(base.clone()).or_insert(0);
+        let id = if *count == 0 {
+            base.clone()
+        } else {
+            format!("{base}-{count}")
+        };
+        *count += 1;
+
+        // A generated suffix may collide with a literal heading such as "intro-1"
+        if id != base {
+            self.used.entry(id.clone()).or_insert(1);
+        }
+        id
+    }
+}
+
+/// Convert heading text to an ID, keeping Unicode letters so Japanese stays readable
+pub fn slugify_heading(text: &str) -> String {
+    let mut slug = String::new();
+    let mut pending_dash = false;
+
+    for c in text.trim().chars() {
+        if c.is_alphanumeric() || c == '_' {
+            if pending_dash && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_dash = false;
+            slug.extend(c.to_lowercase());
+        } else if c.is_whitespace() || c == '-' {
+            pending_dash = true;
+        }
+    }
+
+    if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Assign IDs and hover anchors to every heading, returning the h2–h4 table of contents
+pub fn add_heading_anchors<'a>(events: Vec<Event<'a>>) -> (Vec<Event<'a>>, Vec<TocEntry>) {
+    let mut generator = HeadingIdGenerator::new();
+    let mut output = Vec::with_capacity(events.len());
+    let mut toc = Vec::new();
+    let mut iter = events.into_iter();
+
+    while let Some(event) = iter.next() {
+        let Event::Start(Tag::Heading {
+            level,
+            id,
+            classes,
+            attrs,
+        }) = event
+        else {
+            output.push(event);
+            continue;
+        };
+
+        // Buffer the heading body so its text is known before the start tag is written
+        let mut body = Vec::new();
+        let mut text = String::new();
+        for inner in iter.by_ref() {
+            match &inner {
+                Event::End(TagEnd::Heading(_)) => break,
+                Event::Text(value) | Event::Code(value) => text.push_str(value),
+                _ => {}
+            }
+            body.push(inner);
+        }
+
+        let text = text.trim().to_string();
+        let heading_id = match id {
+            Some(explicit) => explicit.to_string(),
+            None => generator.unique_id(&text),
+        };
+
+        let level_number = heading_level_number(level);
+        if TOC_LEVELS.contains(&level_number) {
+            toc.push(TocEntry {
+                level: level_number,
+                id: heading_id.clone(),
+                text,
+            });
+        }
+
+        output.push(Event::Start(Tag::Heading {
+            level,
+            id: Some(CowStr::from(heading_id.clone())),
+            classes,
+            attrs,
+        }));
+        output.extend(body);
+        output.push(Event::InlineHtml(CowStr::from(format!(
+            "<a class=\"{HEADING_ANCHOR_CLASS}\" href=\"#{}\" aria-hidden=\"true\">#</a>",
+            escape_attribute(&heading_id)
+        ))));
+        output.push(Event::End(TagEnd::Heading(level)));
+    }
+
+    (output, toc)
+}
+
+fn heading_level_number(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::Parser;
+
+    #[test]
+    fn test_slugify_heading() {
+        assert_eq!(slugify_heading("Getting Started"), "getting-started");
+        assert_eq!(
+            slugify_heading("  Rust & WebAssembly!  "),
+            "rust-webassembly"
+        );
+        assert_eq!(slugify_heading("はじめに"), "はじめに");
+        assert_eq!(slugify_heading("位相空間 の 定義。"), "位相空間-の-定義");
+        assert_eq!(slugify_heading("「圏論」入門"), "圏論入門");
+        assert_eq!(slugify_heading("!!!"), "section");
+    }
+
+    #[test]
+    fn test_unique_ids_for_repeated_headings() {
+        let mut generator = HeadingIdGenerator::new();
+        assert_eq!(generator.unique_id("Example"), "example");
+        assert_eq!(generator.unique_id("Example"), "example-1");
+        assert_eq!(generator.unique_id("Example 1"), "example-1-1");
+        assert_eq!(generator.unique_id("Example"), "example-2");
+    }
+
+    #[test]
+    fn test_add_heading_anchors_builds_toc() {
+        let content = "# Title\n\n## 概要\n\ntext\n\n### Details `code`\n\n##### Deep\n";
+        let events: Vec<_> = Parser::new(content).collect();
+
+        let (events, toc) = add_heading_anchors(events);
+
+        assert_eq!(
+            toc,
+            vec![
+                TocEntry {
+                    level: 2,
+                    id: "概要".to_string(),
+                    text: "概要".to_string(),
+                },
+                TocEntry {
+                    level: 3,
+                    id: "details-code".to_string(),
+                    text: "Details code".to_string(),
+                },
+            ]
+        );
+
+        let mut html = String::new();
+        pulldown_cmark::html::push_html(&mut html, events.into_iter());
+        assert!(html.contains("<h1 id=\"title\">Title<a class=\"heading-anchor\" href=\"#title\""));
+        assert!(html.contains("<h5 id=\"deep\">"));
+    }
+}
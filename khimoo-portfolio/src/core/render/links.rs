@@ -0,0 +1,276 @@
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+use std::collections::BTreeMap;
+
+use crate::config::AppConfig;
+use crate::core::articles::links::join_link_path;
+
+/// Class added to links that leave the site
+pub const EXTERNAL_LINK_CLASS: &str = "external-link";
+
+/// Classification of a markdown link destination
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkTarget {
+    /// Another article, resolved to a router path under the base path
+    Internal { href: String, slug: String },
+    /// Absolute URL on another site
+    External { href: String },
+    /// In-page anchors, mailto: and anything else left untouched
+    Other { href: String },
+}
+
+/// Rewrites markdown link destinations into router-aware anchors
+pub struct LinkRewriter<'c> {
+    config: &'c AppConfig,
+    /// Directory of the article below the articles directory
+    base: &'c str,
+    /// Link paths as written → canonical slugs, from `LinkResolver` at build time
+    targets: Option<&'c BTreeMap<String, String>>,
+}
+
+impl<'c> LinkRewriter<'c> {
+    /// Create a new link rewriter using the given application configuration
+    pub fn new(config: &'c AppConfig) -> Self {
+        Self {
+            config,
+            base: "",
+            targets: None,
+        }
+    }
+
+    /// Resolve relative links from `base`, preferring the slugs the build resolved them to
+    pub fn with_link_targets(
+        mut self,
+        base: &'c str,
+        targets: &'c BTreeMap<String, String>,
+    ) -> Self {
+        self.base = base;
+        self.targets = Some(targets);
+        self
+    }
+
+    /// Classify a link destination and compute the href it should render with
+    pub fn resolve(&self, dest_url: &str) -> LinkTarget {
+        let dest = dest_url.trim();
+
+        if dest.starts_with("http://") || dest.starts_with("https://") || dest.starts_with("//") {
+            return LinkTarget::External {
+                href: dest.to_string(),
+            };
+        }
+
+        if dest.is_empty() || dest.starts_with('#') || dest.contains(':') {
+            return LinkTarget::Other {
+                href: dest.to_string(),
+            };
+        }
+
+        // Absolute paths already point inside the app; only the base path is missing
+        if dest.starts_with('/') {
+            return LinkTarget::Internal {
+                href: self.config.get_url(dest),
+                slug: dest.trim_start_matches('/').to_string(),
+            };
+        }
+
+        let (path, fragment) = match dest.split_once('#') {
+            Some((path, fragment)) => (path, Some(fragment)),
+            None => (dest, None),
+        };
+        // Unresolved links still point where the article's directory suggests
+        let slug = self
+            .targets
+            .and_then(|targets| targets.get(path).cloned())
+            .or_else(|| join_link_path(self.base, path))
+            .unwrap_or_else(|| path.trim_end_matches(".md").to_string());
+
+        let mut href = self.config.get_url(&format!("article/{slug}"));
+        if let Some(fragment) = fragment {
+            href.push('#');
+            href.push_str(fragment);
+        }
+
+        LinkTarget::Internal { href, slug }
+    }
+
+    /// Replace link start/end events with raw anchors carrying routing attributes
+    pub fn rewrite<'a>(&self, event: Event<'a>) -> Event<'a> {
+        match event {
+            Event::Start(Tag::Link {
+                dest_url, title, ..
+            }) => Event::InlineHtml(CowStr::from(self.render_anchor(&dest_url, &title))),
+            Event::End(TagEnd::Link) => Event::InlineHtml(CowStr::Borrowed("</a>")),
+            other => other,
+        }
+    }
+
+    /// Render the opening `<a>` tag for a link destination
+    fn render_anchor(&self, dest_url: &str, title: &str) -> String {
+        let mut anchor = String::from("<a href=\"");
+
+        match self.resolve(dest_url) {
+            LinkTarget::Internal { href, slug } => {
+                anchor.push_str(&escape_attribute(&href));
+                anchor.push_str("\" data-internal=\"");
+                anchor.push_str(&escape_attribute(&slug));
+                anchor.push('"');
+            }
+            LinkTarget::External { href } => {
+                anchor.push_str(&escape_attribute(&href));
+                anchor.push_str("\" class=\"");
+                anchor.push_str(EXTERNAL_LINK_CLASS);
+                anchor.push_str("\" target=\"_blank\"");
+            }
+            LinkTarget::Other { href } => {
+                anchor.push_str(&escape_attribute(&href));
+                anchor.push('"');
+            }
+        }
+
+        if !title.is_empty() {
+            anchor.push_str(" title=\"");
+            anchor.push_str(&escape_attribute(title));
+            anchor.push('"');
+        }
+
+        anchor.push('>');
+        anchor
+    }
+}
+
+/// Escape a value for use inside a double-quoted HTML attribute
+pub(crate) fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_base(base_path: &str) -> AppConfig {
+        AppConfig {
+            base_path: base_path.to_string(),
+            ..AppConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_resolve_internal_links() {
+        let config = config_with_base("/portfolio-page");
+        let rewriter = LinkRewriter::new(&config);
+
+        assert_eq!(
+            rewriter.resolve("other-article"),
+            LinkTarget::Internal {
+                href: "/portfolio-page/article/other-article".to_string(),
+                slug: "other-article".to_string(),
+            }
+        );
+        assert_eq!(
+            rewriter.resolve("./other-article.md#section"),
+            LinkTarget::Internal {
+                href: "/portfolio-page/article/other-article#section".to_string(),
+                slug: "other-article".to_string(),
+            }
+        );
+        assert_eq!(
+            rewriter.resolve("/article"),
+            LinkTarget::Internal {
+                href: "/portfolio-page/article".to_string(),
+                slug: "article".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_resolve_relative_to_article_directory() {
+        let config = config_with_base("");
+        let targets = BTreeMap::from([("compactness".to_string(), "位相空間".to_string())]);
+        let rewriter = LinkRewriter::new(&config).with_link_targets("notes/topology", &targets);
+
+        // Sibling link, resolved at build time
+        assert_eq!(
+            rewriter.resolve("compactness#proof"),
+            LinkTarget::Internal {
+                href: "/article/位相空間#proof".to_string(),
+                slug: "位相空間".to_string(),
+            }
+        );
+        assert_eq!(
+            rewriter.resolve("./open-sets.md"),
+            LinkTarget::Internal {
+                href: "/article/notes/topology/open-sets".to_string(),
+                slug: "notes/topology/open-sets".to_string(),
+            }
+        );
+        assert_eq!(
+            rewriter.resolve("../rust/ownership"),
+            LinkTarget::Internal {
+                href: "/article/notes/rust/ownership".to_string(),
+                slug: "notes/rust/ownership".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_resolve_without_base_path() {
+        let config = config_with_base("");
+        let rewriter = LinkRewriter::new(&config);
+
+        assert_eq!(
+            rewriter.resolve("other-article"),
+            LinkTarget::Internal {
+                href: "/article/other-article".to_string(),
+                slug: "other-article".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_resolve_external_and_other_links() {
+        let config = config_with_base("/portfolio-page");
+        let rewriter = LinkRewriter::new(&config);
+
+        assert!(matches!(
+            rewriter.resolve("https://example.com"),
+            LinkTarget::External { .. }
+        ));
+        assert!(matches!(
+            rewriter.resolve("//cdn.example.com/x"),
+            LinkTarget::External { .. }
+        ));
+        assert!(matches!(
+            rewriter.resolve("mailto:me@example.com"),
+            LinkTarget::Other { .. }
+        ));
+        assert!(matches!(
+            rewriter.resolve("#section"),
+            LinkTarget::Other { .. }
+        ));
+    }
+
+    #[test]
+    fn test_render_anchor_attributes() {
+        let config = config_with_base("/portfolio-page");
+        let rewriter = LinkRewriter::new(&config);
+
+        assert_eq!(
+            rewriter.render_anchor("other", "Other \"quoted\""),
+            "<a href=\"/portfolio-page/article/other\" data-internal=\"other\" title=\"Other &quot;quoted&quot;\">"
+        );
+        assert_eq!(
+            rewriter.render_anchor("https://example.com/?a=1&b=2", ""),
+            "<a href=\"https://example.com/?a=1&amp;b=2\" class=\"external-link\" target=\"_blank\">"
+        );
+    }
+}
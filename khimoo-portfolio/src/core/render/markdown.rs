@@ -0,0 +1,227 @@
+use pulldown_cmark::{html, Event, Options, Parser, Tag};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+use super::citations::{reference_id, resolve_citations};
+use super::footnotes::{add_footnote_backrefs, footnote_ids};
+use super::headings::{add_heading_anchors, TocEntry};
+use super::links::LinkRewriter;
+use super::sanitizer::HtmlSanitizer;
+use crate::config::AppConfig;
+use crate::core::articles::bibliography::Reference;
+
+/// Markdown extensions enabled everywhere article markdown is parsed
+/// Matches what Obsidian renders: GFM tables, footnotes, strikethrough and task lists
+pub fn markdown_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+}
+
+/// Rendered article body with its table of contents
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RenderedArticle {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+}
+
+/// Markdown to HTML renderer used for article bodies
+/// Runs link rewriting and heading anchoring on the event stream and sanitizes the final HTML
+pub struct MarkdownRenderer<'c> {
+    config: &'c AppConfig,
+    sanitizer: HtmlSanitizer,
+    references: &'c [Reference],
+    link_base: &'c str,
+    link_targets: Option<&'c BTreeMap<String, String>>,
+}
+
+impl<'c> MarkdownRenderer<'c> {
+    /// Create a new renderer using the given application configuration
+    pub fn new(config: &'c AppConfig) -> Self {
+        Self {
+            config,
+            sanitizer: HtmlSanitizer::new(config.sanitizer_policy.clone()),
+            references: &[],
+            link_base: "",
+            link_targets: None,
+        }
+    }
+
+    /// Resolve `[@key]` citations against the article's references
+    pub fn with_references(mut self, references: &'c [Reference]) -> Self {
+        self.references = references;
+        self
+    }
+
+    /// Resolve relative links from the article's directory, using the targets found at build time
+    pub fn with_link_targets(
+        mut self,
+        base: &'c str,
+        targets: &'c BTreeMap<String, String>,
+    ) -> Self {
+        self.link_base = base;
+        self.link_targets = Some(targets);
+        self
+    }
+
+    /// Render markdown content (without front matter) to sanitized HTML
+    pub fn render(&self, content: &str) -> String {
+        self.render_article(content).html
+    }
+
+    /// Render markdown content to sanitized HTML and collect its table of contents
+    pub fn render_article(&self, content: &str) -> RenderedArticle {
+        let mut link_rewriter = LinkRewriter::new(self.config);
+        if let Some(targets) = self.link_targets {
+            link_rewriter = link_rewriter.with_link_targets(self.link_base, targets);
+        }
+        let events: Vec<Event> = Parser::new_ext(content, markdown_options())
+            .map(|event| link_rewriter.rewrite(event))
+            .collect();
+
+        // Only ids generated here survive sanitizing; raw HTML in notes loses its own
+        let mut generated_ids: HashSet<String> = footnote_ids(&events).into_iter().collect();
+        let events = add_footnote_backrefs(events);
+        let events = resolve_citations(events, self.references, self.config.citation_style);
+        let (events, toc) = add_heading_anchors(events);
+        if !self.references.is_empty() {
+            generated_ids.extend(self.references.iter().map(|r| reference_id(&r.key)));
+        }
+        generated_ids.extend(events.iter().filter_map(|event| match event {
+            Event::Start(Tag::Heading { id: Some(id), .. }) => Some(id.to_string()),
+            _ => None,
+        }));
+
+        let mut html_output = String::new();
+        html::push_html(&mut html_output, events.into_iter());
+
+        // Raw HTML in notes passes through the parser, so sanitize last
+        RenderedArticle {
+            html: self
+                .sanitizer
+                .sanitize_with_ids(&html_output, &generated_ids),
+            toc,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_base(base_path: &str) -> AppConfig {
+        AppConfig {
+            base_path: base_path.to_string(),
+            ..AppConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_render_rewrites_internal_links() {
+        let config = config_with_base("/portfolio-page");
+        let renderer = MarkdownRenderer::new(&config);
+
+        let html = renderer.render("See [other](other-article).");
+        assert!(html.contains("href=\"/portfolio-page/article/other-article\""));
+        assert!(html.contains("data-internal=\"other-article\""));
+        assert!(!html.contains("external-link"));
+    }
+
+    #[test]
+    fn test_render_marks_external_links() {
+        let config = config_with_base("/portfolio-page");
+        let renderer = MarkdownRenderer::new(&config);
+
+        let html = renderer.render("Visit [example](https://example.com).");
+        assert!(html.contains("href=\"https://example.com\""));
+        assert!(html.contains("class=\"external-link\""));
+        assert!(html.contains("noopener"));
+        assert!(!html.contains("data-internal"));
+    }
+
+    #[test]
+    fn test_render_article_keeps_heading_anchors() {
+        let config = config_with_base("");
+        let renderer = MarkdownRenderer::new(&config);
+
+        let rendered = renderer.render_article("## はじめに\n\ntext\n\n### Setup\n");
+        assert_eq!(rendered.toc.len(), 2);
+        assert!(rendered.html.contains("<h2 id=\"はじめに\">"));
+        assert!(rendered.html.contains("class=\"heading-anchor\""));
+        assert!(rendered.html.contains("href=\"#setup\""));
+    }
+
+    #[test]
+    fn test_render_gfm_extensions() {
+        let config = config_with_base("");
+        let renderer = MarkdownRenderer::new(&config);
+
+        let html = renderer.render(concat!(
+            "| a | b |\n|:--|--:|\n| 1 | 2 |\n\n",
+            "~~old~~ text[^1]\n\n",
+            "- [x] done\n- [ ] todo\n\n",
+            "[^1]: A footnote.\n",
+        ));
+        assert!(html.contains("<table>"));
+        assert!(html.contains("style=\"text-align:right\""));
+        assert!(html.contains("<del>old</del>"));
+        assert!(html.contains("<input checked=\"\""));
+        assert!(html.contains("type=\"checkbox\""));
+        assert!(html.contains("class=\"footnote-reference\""));
+        assert!(html.contains("class=\"footnote-backref\""));
+    }
+
+    #[test]
+    fn test_render_citations_with_references() {
+        let config = config_with_base("");
+        let references = vec![Reference {
+            key: "munkres2000".to_string(),
+            label: "1".to_string(),
+            text: "Munkres, James R. (2000). Topology.".to_string(),
+            url: None,
+        }];
+        let renderer = MarkdownRenderer::new(&config).with_references(&references);
+
+        let rendered = renderer.render_article("Compactness [@munkres2000, p. 164].");
+        assert!(rendered
+            .html
+            .contains("<span class=\"citation\">[<a href=\"#ref-munkres2000\""));
+        assert!(rendered.html.contains(">1</a>, p. 164]</span>"));
+        assert!(rendered.html.contains("<li id=\"ref-munkres2000\">"));
+        assert_eq!(
+            rendered.toc.last().map(|entry| entry.text.as_str()),
+            Some("参考文献")
+        );
+    }
+
+    #[test]
+    fn test_render_sanitizes_raw_html() {
+        let config = config_with_base("");
+        let renderer = MarkdownRenderer::new(&config);
+
+        let html =
+            renderer.render("Hello <img src=x onerror=alert(1)>\n\n<script>alert(1)</script>");
+        assert!(!html.contains("onerror"));
+        assert!(!html.contains("<script"));
+    }
+
+    #[test]
+    fn test_render_keeps_only_generated_ids() {
+        let config = config_with_base("");
+        let renderer = MarkdownRenderer::new(&config);
+
+        let html = renderer.render(concat!(
+            "## Intro\n\n",
+            "<div id=\"app\">raw</div>\n\n",
+            "<h3 id=\"main\">raw heading</h3>\n\n",
+            "Text[^x].\n\n[^x]: Note.\n",
+        ));
+        assert!(html.contains("<h2 id=\"intro\">"));
+        assert!(html.contains("id=\"fnref-x\""));
+        assert!(html.contains("<div class=\"footnote-definition\" id=\"fn-x\">"));
+        assert!(!html.contains("id=\"app\""));
+        assert!(html.contains("<h3 id=\"raw-heading\">") || html.contains("<h3>raw heading</h3>"));
+        assert!(!html.contains("id=\"main\""));
+    }
+}
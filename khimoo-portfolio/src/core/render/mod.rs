@@ -0,0 +1,18 @@
+//! Rendering module
+//!
+//! This module contains logic shared by every place that turns article
+//! markdown into HTML, such as parser options, link rewriting, heading anchors, footnotes, citations and sanitization of the rendered output.
+
+pub mod citations;
+pub mod footnotes;
+pub mod headings;
+pub mod links;
+pub mod markdown;
+pub mod sanitizer;
+
+// Re-export main components
+pub use citations::{collect_citation_keys, CitationItem};
+pub use headings::{slugify_heading, TocEntry};
+pub use links::{LinkRewriter, LinkTarget};
+pub use markdown::{markdown_options, MarkdownRenderer, RenderedArticle};
+pub use sanitizer::{HtmlSanitizer, SanitizerPolicy};
@@ -0,0 +1,354 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use super::citations::{CITATION_CLASS, REFERENCES_CLASS};
+use super::footnotes::FOOTNOTE_BACKREF_CLASS;
+use super::headings::HEADING_ANCHOR_CLASS;
+use super::links::EXTERNAL_LINK_CLASS;
+
+/// Allowlist policy for sanitizing rendered article HTML
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SanitizerPolicy {
+    /// Hosts whose pages may be embedded with `<iframe src="https://...">`
+    #[serde(default)]
+    pub iframe_hosts: Vec<String>,
+}
+
+impl SanitizerPolicy {
+    /// Read the `[sanitizer]` section of project.toml, falling back to the default policy
+    pub fn from_project_toml(content: &str) -> Self {
+        toml::from_str::<toml::Value>(content)
+            .ok()
+            .and_then(|config| config.get("sanitizer").cloned())
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default()
+    }
+
+    /// Check whether an iframe `src` points at an allowed host over HTTPS
+    pub fn is_allowed_iframe_src(&self, src: &str) -> bool {
+        match extract_https_host(src) {
+            Some(host) => self
+                .iframe_hosts
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(host)),
+            None => false,
+        }
+    }
+}
+
+/// Allowlist-based HTML sanitizer applied to markdown output before it is injected into the DOM
+pub struct HtmlSanitizer {
+    policy: SanitizerPolicy,
+}
+
+impl HtmlSanitizer {
+    /// Create a new sanitizer with the given policy
+    pub fn new(policy: SanitizerPolicy) -> Self {
+        Self { policy }
+    }
+
+    /// Remove every tag, attribute and URL scheme that is not explicitly allowed, including all `id`s
+    pub fn sanitize(&self, html: &str) -> String {
+        self.sanitize_with_ids(html, &HashSet::new())
+    }
+
+    /// Sanitize like `sanitize`, keeping only the `id`s the renderer generated itself
+    ///
+    /// Raw HTML in notes could otherwise claim ids the app or other anchors rely on.
+    pub fn sanitize_with_ids(&self, html: &str, generated_ids: &HashSet<String>) -> String {
+        let mut builder = ammonia::Builder::default();
+
+        // Code blocks keep their `language-*` class for syntax highlighting
+        builder.add_tag_attributes("code", &["class"]);
+
+        // Routing attributes emitted by `LinkRewriter` and heading anchors
+        builder
+            .add_tag_attributes(
+                "a",
+                &["data-internal", "target", "aria-hidden", "aria-label"],
+            )
+            .add_allowed_classes("a", &[EXTERNAL_LINK_CLASS, HEADING_ANCHOR_CLASS]);
+        for heading in ["h1", "h2", "h3", "h4", "h5", "h6"] {
+            builder.add_tag_attributes(heading, &["id"]);
+        }
+
+        // GFM extensions: footnotes, task list checkboxes and table column alignment
+        builder
+            .add_tag_attributes("sup", &["id"])
+            .add_tag_attributes("div", &["id"])
+            .add_allowed_classes("sup", &["footnote-reference", "footnote-definition-label"])
+            .add_allowed_classes("div", &["footnote-definition"])
+            .add_allowed_classes("a", &[FOOTNOTE_BACKREF_CLASS])
+            .add_tags(&["input"])
+            .add_tag_attributes("input", &["checked"])
+            .set_tag_attribute_value("input", "type", "checkbox")
+            .set_tag_attribute_value("input", "disabled", "")
+            .add_tag_attributes("th", &["style"])
+            .add_tag_attributes("td", &["style"])
+            .filter_style_properties(["text-align"].into_iter().collect());
+
+        // Citations and the references list
+        builder
+            .add_allowed_classes("span", &[CITATION_CLASS])
+            .add_allowed_classes("ol", &[REFERENCES_CLASS])
+            .add_allowed_classes("ul", &[REFERENCES_CLASS])
+            .add_tag_attributes("li", &["id"]);
+
+        if !self.policy.iframe_hosts.is_empty() {
+            builder.add_tags(&["iframe"]).add_tag_attributes(
+                "iframe",
+                &[
+                    "src",
+                    "width",
+                    "height",
+                    "title",
+                    "allow",
+                    "allowfullscreen",
+                    "frameborder",
+                    "loading",
+                ],
+            );
+        }
+
+        let policy = self.policy.clone();
+        let generated_ids = generated_ids.clone();
+        builder.attribute_filter(move |element, attribute, value| {
+            if element == "iframe" && attribute == "src" && !policy.is_allowed_iframe_src(value) {
+                return None;
+            }
+            if attribute == "id" && !generated_ids.contains(value) {
+                return None;
+            }
+            Some(Cow::Borrowed(value))
+        });
+
+        builder.clean(html).to_string()
+    }
+
+    /// Get sanitizer policy
+    pub fn policy(&self) -> &SanitizerPolicy {
+        &self.policy
+    }
+}
+
+impl Default for HtmlSanitizer {
+    fn default() -> Self {
+        Self::new(SanitizerPolicy::default())
+    }
+}
+
+/// Extract the host part of an absolute `https://` URL
+fn extract_https_host(url: &str) -> Option<&str> {
+    let rest = url.trim().strip_prefix("https://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    // Reject credentials such as `https://trusted.example@evil.example/`
+    if authority.contains('@') {
+        return None;
+    }
+    let host = authority.split(':').next()?;
+    if host.is_empty() {
+        None
+    } else {
+        Some(host)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn youtube_policy() -> SanitizerPolicy {
+        SanitizerPolicy {
+            iframe_hosts: vec!["www.youtube-nocookie.com".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_removes_script_tags() {
+        let sanitizer = HtmlSanitizer::default();
+        let html = "<p>Hello</p><script>alert('xss')</script>";
+
+        let result = sanitizer.sanitize(html);
+        assert_eq!(result, "<p>Hello</p>");
+    }
+
+    #[test]
+    fn test_removes_event_handlers() {
+        let sanitizer = HtmlSanitizer::default();
+        let payloads = [
+            r#"<img src="x" onerror="alert(1)">"#,
+            r#"<p onclick="alert(1)">text</p>"#,
+            r#"<svg onload="alert(1)"></svg>"#,
+            r#"<body onload="alert(1)">"#,
+            r#"<details open ontoggle="alert(1)"></details>"#,
+        ];
+
+        for payload in payloads {
+            let result = sanitizer.sanitize(payload);
+            assert!(!result.contains("alert"), "payload survived: {result}");
+        }
+    }
+
+    #[test]
+    fn test_removes_javascript_urls() {
+        let sanitizer = HtmlSanitizer::default();
+        let payloads = [
+            r#"<a href="javascript:alert(1)">click</a>"#,
+            r#"<a href="JaVaScRiPt:alert(1)">click</a>"#,
+            r#"<a href="&#106;avascript:alert(1)">click</a>"#,
+            r#"<a href=" javascript:alert(1)">click</a>"#,
+            r#"<img src="javascript:alert(1)">"#,
+            r#"<a href="data:text/html;base64,PHNjcmlwdD5hbGVydCgxKTwvc2NyaXB0Pg==">click</a>"#,
+            r#"<a href="vbscript:msgbox(1)">click</a>"#,
+        ];
+
+        for payload in payloads {
+            let result = sanitizer.sanitize(payload);
+            assert!(
+                !result.to_lowercase().contains("script:"),
+                "payload survived: {result}"
+            );
+            assert!(!result.contains("data:"), "payload survived: {result}");
+        }
+    }
+
+    #[test]
+    fn test_removes_dangerous_elements() {
+        let sanitizer = HtmlSanitizer::default();
+        let payloads = [
+            r#"<iframe src="https://evil.example/"></iframe>"#,
+            r#"<object data="evil.swf"></object>"#,
+            r#"<embed src="evil.swf">"#,
+            r#"<style>body { display: none; }</style>"#,
+            r#"<meta http-equiv="refresh" content="0;url=https://evil.example/">"#,
+            r#"<base href="https://evil.example/">"#,
+        ];
+
+        for payload in payloads {
+            let result = sanitizer.sanitize(payload);
+            assert!(!result.contains('<'), "payload survived: {result}");
+        }
+    }
+
+    #[test]
+    fn test_inputs_become_disabled_checkboxes() {
+        let sanitizer = HtmlSanitizer::default();
+        let html =
+            r#"<form action="https://evil.example/"><input name="q" type="password"></form>"#;
+
+        let result = sanitizer.sanitize(html);
+        assert!(!result.contains("form"));
+        assert!(!result.contains("name"));
+        assert!(!result.contains("password"));
+        assert!(result.contains("type=\"checkbox\""));
+        assert!(result.contains("disabled"));
+    }
+
+    #[test]
+    fn test_keeps_gfm_output() {
+        let sanitizer = HtmlSanitizer::default();
+        let html = concat!(
+            "<table><thead><tr><th style=\"text-align: center; position: fixed\">a</th></tr></thead></table>\n",
+            "<ul><li><input disabled=\"\" type=\"checkbox\" checked=\"\">done</li></ul>\n",
+            "<p><del>old</del><sup class=\"footnote-reference\" id=\"fnref-1\"><a href=\"#fn-1\">1</a></sup></p>\n",
+            "<div class=\"footnote-definition\" id=\"fn-1\"></div>"
+        );
+
+        let ids = ["fnref-1", "fn-1"].map(String::from).into_iter().collect();
+        let result = sanitizer.sanitize_with_ids(html, &ids);
+        assert!(result.contains("style=\"text-align:center\""));
+        assert!(!result.contains("position"));
+        assert!(result.contains("checked"));
+        assert!(result.contains("<del>old</del>"));
+        assert!(result.contains("class=\"footnote-reference\" id=\"fnref-1\""));
+        assert!(result.contains("class=\"footnote-definition\" id=\"fn-1\""));
+    }
+
+    #[test]
+    fn test_keeps_markdown_output() {
+        let sanitizer = HtmlSanitizer::default();
+        let html = concat!(
+            "<h2>Title</h2>\n",
+            "<p>Some <strong>bold</strong> and <a href=\"https://example.com\">link</a>.</p>\n",
+            "<pre><code class=\"language-rust\">fn main() {}\n</code></pre>\n",
+            "<img src=\"/articles/img/photo.png\" alt=\"photo\">"
+        );
+
+        let result = sanitizer.sanitize(html);
+        assert!(result.contains("<h2>Title</h2>"));
+        assert!(result.contains("<strong>bold</strong>"));
+        assert!(result.contains("href=\"https://example.com\""));
+        assert!(result.contains("class=\"language-rust\""));
+        assert!(result.contains("src=\"/articles/img/photo.png\""));
+    }
+
+    #[test]
+    fn test_keeps_only_generated_ids() {
+        let sanitizer = HtmlSanitizer::default();
+        let html = concat!(
+            "<h2 id=\"intro\">Intro</h2>\n",
+            "<h2 id=\"app\">Raw</h2>\n",
+            "<div id=\"fn-evil\">x</div><sup id=\"fnref-evil\">1</sup><li id=\"main\">y</li>"
+        );
+
+        let ids = HashSet::from(["intro".to_string()]);
+        let result = sanitizer.sanitize_with_ids(html, &ids);
+        assert!(result.contains("<h2 id=\"intro\">"));
+        assert!(result.contains("<h2>Raw</h2>"));
+        assert_eq!(
+            result.matches("id=").count(),
+            1,
+            "unexpected id in {result}"
+        );
+
+        assert!(!sanitizer.sanitize(html).contains("id="));
+    }
+
+    #[test]
+    fn test_allows_iframes_from_trusted_hosts() {
+        let sanitizer = HtmlSanitizer::new(youtube_policy());
+        let html =
+            r#"<iframe src="https://www.youtube-nocookie.com/embed/abc" width="560"></iframe>"#;
+
+        let result = sanitizer.sanitize(html);
+        assert!(result.contains("src=\"https://www.youtube-nocookie.com/embed/abc\""));
+        assert!(result.contains("width=\"560\""));
+    }
+
+    #[test]
+    fn test_strips_iframe_src_from_untrusted_hosts() {
+        let sanitizer = HtmlSanitizer::new(youtube_policy());
+        let payloads = [
+            r#"<iframe src="https://evil.example/embed"></iframe>"#,
+            r#"<iframe src="http://www.youtube-nocookie.com/embed/abc"></iframe>"#,
+            r#"<iframe src="https://www.youtube-nocookie.com@evil.example/"></iframe>"#,
+            r#"<iframe src="javascript:alert(1)"></iframe>"#,
+            r#"<iframe srcdoc="<script>alert(1)</script>"></iframe>"#,
+        ];
+
+        for payload in payloads {
+            let result = sanitizer.sanitize(payload);
+            assert!(!result.contains("src"), "payload survived: {result}");
+        }
+    }
+
+    #[test]
+    fn test_policy_from_project_toml() {
+        let content = r#"
+[paths]
+articles_dir = "content/articles"
+
+[sanitizer]
+iframe_hosts = ["player.vimeo.com"]
+"#;
+
+        let policy = SanitizerPolicy::from_project_toml(content);
+        assert_eq!(policy.iframe_hosts, vec!["player.vimeo.com"]);
+        assert!(policy.is_allowed_iframe_src("https://player.vimeo.com/video/1"));
+        assert!(!policy.is_allowed_iframe_src("https://vimeo.com/video/1"));
+
+        let missing = SanitizerPolicy::from_project_toml("[paths]\n");
+        assert!(missing.iframe_hosts.is_empty());
+    }
+}
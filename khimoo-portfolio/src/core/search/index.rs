@@ -0,0 +1,168 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use super::tokenizer::tokenize;
+use crate::core::render::markdown_options;
+
+/// How much one occurrence counts in each field, relative to the body
+const TITLE_WEIGHT: u16 = 10;
+const TAG_WEIGHT: u16 = 6;
+/// Added on top of the body weight, since headings are part of the text
+const HEADING_WEIGHT: u16 = 2;
+const BODY_WEIGHT: u16 = 1;
+
+/// Article as stored in the search index
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchDocument {
+    pub slug: String,
+    pub title: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Plain text of the body (headings included, code left out) that snippets are cut from
+    pub text: String,
+    /// Weighted token count, for length normalisation
+    pub length: u32,
+}
+
+/// Inverted index over titles, tags, headings and body text, written to `search.json`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SearchIndex {
+    pub documents: Vec<SearchDocument>,
+    /// Token → (document index, weighted frequency), in document order
+    pub terms: BTreeMap<String, Vec<(u32, u16)>>,
+}
+
+impl SearchIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index an article from its markdown body (front matter already removed)
+    pub fn add_article(&mut self, slug: &str, title: &str, tags: &[String], markdown: &str) {
+        let (text, headings) = plain_text(markdown);
+
+        let mut frequencies: HashMap<String, u16> = HashMap::new();
+        let mut length: u32 = 0;
+        let mut count = |field: &str, weight: u16| {
+            for token in tokenize(field) {
+                let frequency = frequencies.entry(token.text).or_default();
+                *frequency = frequency.saturating_add(weight);
+                length += u32::from(weight);
+            }
+        };
+        count(title, TITLE_WEIGHT);
+        for tag in tags {
+            count(tag, TAG_WEIGHT);
+        }
+        for heading in &headings {
+            count(heading, HEADING_WEIGHT);
+        }
+        count(&text, BODY_WEIGHT);
+
+        let document = self.documents.len() as u32;
+        for (term, frequency) in frequencies {
+            self.terms
+                .entry(term)
+                .or_default()
+                .push((document, frequency));
+        }
+        self.documents.push(SearchDocument {
+            slug: slug.to_string(),
+            title: title.to_string(),
+            tags: tags.to_vec(),
+            text,
+            length,
+        });
+    }
+
+    /// Mean weighted length of the documents
+    pub(crate) fn average_length(&self) -> f32 {
+        if self.documents.is_empty() {
+            return 1.0;
+        }
+        let total: u64 = self.documents.iter().map(|d| u64::from(d.length)).sum();
+        (total as f32 / self.documents.len() as f32).max(1.0)
+    }
+}
+
+/// Body text in reading order with blocks on separate lines, and the heading texts
+///
+/// Code blocks, raw HTML and image URLs are left out; inline code stays, as readers
+/// search for identifiers.
+pub(crate) fn plain_text(markdown: &str) -> (String, Vec<String>) {
+    let mut text = String::new();
+    let mut headings = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut in_code_block = false;
+
+    for event in Parser::new_ext(markdown, markdown_options()) {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Start(Tag::Heading { .. }) => heading = Some(String::new()),
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(heading) = heading.take() {
+                    headings.push(heading);
+                }
+                text.push('\n');
+            }
+            Event::Text(content) | Event::Code(content) if !in_code_block => {
+                if let Some(heading) = heading.as_mut() {
+                    heading.push_str(&content);
+                }
+                text.push_str(&content);
+            }
+            Event::SoftBreak | Event::HardBreak => text.push(' '),
+            Event::End(TagEnd::Paragraph | TagEnd::Item | TagEnd::TableCell) => text.push('\n'),
+            _ => {}
+        }
+    }
+
+    let text = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    (text, headings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text_skips_code() {
+        let (text, headings) = plain_text(concat!(
+            "# 位相空間\n\nOpen `sets` and\n*covers*.\n\n",
+            "```rust\nfn hidden() {}\n```\n\n- item\n",
+        ));
+        assert_eq!(text, "位相空間\nOpen sets and covers.\nitem");
+        assert_eq!(headings, ["位相空間"]);
+    }
+
+    #[test]
+    fn test_add_article_weights_fields() {
+        let mut index = SearchIndex::new();
+        index.add_article(
+            "compactness",
+            "Compactness",
+            &["math/topology".to_string()],
+            "## Covers\n\nEvery open cover has a finite subcover.\n",
+        );
+        index.add_article("other", "Other", &[], "Compactness once.");
+
+        assert_eq!(index.documents.len(), 2);
+        assert_eq!(
+            index.terms["compactness"],
+            [(0, TITLE_WEIGHT), (1, BODY_WEIGHT)]
+        );
+        assert_eq!(index.terms["topology"], [(0, TAG_WEIGHT)]);
+        assert_eq!(index.terms["covers"], [(0, HEADING_WEIGHT + BODY_WEIGHT)]);
+        assert_eq!(
+            index.documents[0].text,
+            "Covers\nEvery open cover has a finite subcover."
+        );
+    }
+}
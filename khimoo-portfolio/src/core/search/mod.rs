@@ -0,0 +1,18 @@
+//! Search module
+//!
+//! This module contains the full-text search over articles: the tokenizer that cuts
+//! English into words and Japanese into character bigrams, the inverted index that
+//! `process-articles` writes to `search.json`, the ranked, typo-tolerant queries with
+//! highlighted snippets that the search page runs against it, and the TF-IDF and tag
+//! similarity that picks each article's related articles.
+
+pub mod index;
+pub mod query;
+pub mod similarity;
+pub mod tokenizer;
+
+// Re-export main components
+pub use index::{SearchDocument, SearchIndex};
+pub use query::{SearchHit, SnippetPart};
+pub use similarity::{RelatedArticle, RelatedConfig, SimilarityIndex};
+pub use tokenizer::{tokenize, Token, TokenKind};
@@ -0,0 +1,369 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Bound;
+
+use super::index::{SearchDocument, SearchIndex};
+use super::tokenizer::{is_word_char, normalize_char, tokenize, Token, TokenKind};
+
+/// How much a term found by completing the last word counts against an exact match
+const PREFIX_FACTOR: f32 = 0.7;
+/// How much a term within a typo's distance counts against an exact match
+const FUZZY_FACTOR: f32 = 0.5;
+/// Characters of body text shown around the matches
+const SNIPPET_LENGTH: usize = 80;
+
+/// BM25 parameters
+const K1: f32 = 1.2;
+const B: f32 = 0.75;
+
+/// Piece of a title or snippet, highlighted where it matched the query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnippetPart {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+/// Article found by a query
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit<'a> {
+    pub document: &'a SearchDocument,
+    pub score: f32,
+    pub title: Vec<SnippetPart>,
+    /// Part of the body around the densest matches, with `…` where it was cut
+    pub snippet: Vec<SnippetPart>,
+}
+
+impl SearchIndex {
+    /// Best `limit` articles for a query, ranked with BM25
+    ///
+    /// The last word is completed as a prefix while the reader is typing, and words of
+    /// four or more letters also match indexed words one or two edits away. Articles must
+    /// match at least half of the query's tokens, which keeps a bigram query from matching
+    /// everything that shares one character pair.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit<'_>> {
+        let mut tokens = tokenize(query);
+        let mut seen = HashSet::new();
+        tokens.retain(|token| seen.insert(token.text.clone()));
+        if tokens.is_empty() || self.documents.is_empty() {
+            return Vec::new();
+        }
+
+        let completes_last = !query.ends_with(char::is_whitespace);
+        let total = self.documents.len() as f32;
+        let average_length = self.average_length();
+
+        // Document → (tokens matched, score, terms to highlight)
+        let mut matches: HashMap<u32, (usize, f32, Vec<&str>)> = HashMap::new();
+        for (position, token) in tokens.iter().enumerate() {
+            let is_last = position + 1 == tokens.len();
+            let mut best: HashMap<u32, (f32, &str)> = HashMap::new();
+
+            for (term, factor) in self.expand(token, is_last && completes_last) {
+                let postings = &self.terms[term];
+                let frequency = postings.len() as f32;
+                let idf = (1.0 + (total - frequency + 0.5) / (frequency + 0.5)).ln();
+                for &(document, weight) in postings {
+                    let length = self.documents[document as usize].length as f32;
+                    let tf = f32::from(weight);
+                    let score = factor * idf * tf * (K1 + 1.0)
+                        / (tf + K1 * (1.0 - B + B * length / average_length));
+                    let entry = best.entry(document).or_insert((0.0, term));
+                    if score > entry.0 {
+                        *entry = (score, term);
+                    }
+                }
+            }
+
+            for (document, (score, term)) in best {
+                let entry = matches.entry(document).or_default();
+                entry.0 += 1;
+                entry.1 += score;
+                entry.2.push(term);
+            }
+        }
+
+        let mut hits: Vec<(usize, f32, u32, Vec<&str>)> = matches
+            .into_iter()
+            .filter(|(_, (matched, _, _))| matched * 2 >= tokens.len())
+            .map(|(document, (matched, score, terms))| (matched, score, document, terms))
+            .collect();
+        hits.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.total_cmp(&a.1)).then(a.2.cmp(&b.2)));
+        hits.truncate(limit);
+
+        hits.into_iter()
+            .map(|(_, score, document, terms)| {
+                let document = &self.documents[document as usize];
+                SearchHit {
+                    document,
+                    score,
+                    title: highlight(&document.title, &terms, None),
+                    snippet: highlight(&document.text, &terms, Some(SNIPPET_LENGTH)),
+                }
+            })
+            .collect()
+    }
+
+    /// Indexed terms a query token stands for, with how much each counts
+    fn expand(&self, token: &Token, complete: bool) -> Vec<(&str, f32)> {
+        let mut terms = Vec::new();
+        if let Some((term, _)) = self.terms.get_key_value(&token.text) {
+            terms.push((term.as_str(), 1.0));
+        }
+
+        match token.kind {
+            // A lone character matches every bigram that contains it
+            TokenKind::Cjk if token.text.chars().count() == 1 => {
+                terms.extend(
+                    self.terms
+                        .keys()
+                        .filter(|term| *term != &token.text && term.contains(&token.text))
+                        .map(|term| (term.as_str(), PREFIX_FACTOR)),
+                );
+            }
+            TokenKind::Cjk => {}
+            TokenKind::Word => {
+                if complete {
+                    terms.extend(
+                        self.terms
+                            .range::<str, _>((
+                                Bound::Included(token.text.as_str()),
+                                Bound::Unbounded,
+                            ))
+                            .map(|(term, _)| term.as_str())
+                            .take_while(|term| term.starts_with(&token.text))
+                            .filter(|term| *term != token.text)
+                            .map(|term| (term, PREFIX_FACTOR)),
+                    );
+                }
+                if terms.is_empty() {
+                    terms.extend(self.typo_matches(&token.text));
+                }
+            }
+        }
+        terms
+    }
+
+    /// Indexed words close enough to a word that matched nothing
+    fn typo_matches(&self, word: &str) -> Vec<(&str, f32)> {
+        let word: Vec<char> = word.chars().collect();
+        let max_distance = match word.len() {
+            0..=3 => return Vec::new(),
+            4..=7 => 1,
+            _ => 2,
+        };
+
+        self.terms
+            .keys()
+            .filter(|term| term.starts_with(is_word_char))
+            .filter_map(|term| {
+                let candidate: Vec<char> = term.chars().collect();
+                if candidate.len().abs_diff(word.len()) > max_distance {
+                    return None;
+                }
+                (edit_distance(&word, &candidate) <= max_distance)
+                    .then_some((term.as_str(), FUZZY_FACTOR))
+            })
+            .collect()
+    }
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions and swaps
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = Vec::new();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let before = std::mem::replace(&mut previous, row.clone());
+        row[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (previous[j] + 1)
+                .min(row[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(before[j - 2] + 1);
+            }
+        }
+    }
+    row[b.len()]
+}
+
+/// Split `text` into highlighted and plain parts, cut to about `window` characters
+/// around the most highlighted stretch when given
+fn highlight(text: &str, terms: &[&str], window: Option<usize>) -> Vec<SnippetPart> {
+    let chars: Vec<char> = text.chars().collect();
+    let normalized: Vec<char> = chars.iter().copied().map(normalize_char).collect();
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for term in terms {
+        let term: Vec<char> = term.chars().collect();
+        if term.is_empty() || term.len() > normalized.len() {
+            continue;
+        }
+        let needs_boundary = is_word_char(term[0]);
+        for start in 0..=normalized.len() - term.len() {
+            let at_boundary = start == 0 || !is_word_char(normalized[start - 1]);
+            if normalized[start..start + term.len()] == term[..] && (!needs_boundary || at_boundary)
+            {
+                ranges.push((start, start + term.len()));
+            }
+        }
+    }
+    ranges.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let (from, to) = match window {
+        Some(window) if chars.len() > window => {
+            let covered = |from: usize| -> usize {
+                merged
+                    .iter()
+                    .map(|&(start, end)| end.min(from + window).saturating_sub(start.max(from)))
+                    .sum()
+            };
+            // Start a little before a match so it reads in context
+            let from = merged
+                .iter()
+                .map(|&(start, _)| start.saturating_sub(window / 4).min(chars.len() - window))
+                .max_by_key(|&from| (covered(from), std::cmp::Reverse(from)))
+                .unwrap_or(0);
+            (from, from + window)
+        }
+        _ => (0, chars.len()),
+    };
+
+    let mut parts: Vec<SnippetPart> = Vec::new();
+    let mut push = |text: String, highlighted: bool| {
+        if text.is_empty() {
+            return;
+        }
+        match parts.last_mut() {
+            Some(last) if last.highlighted == highlighted => last.text.push_str(&text),
+            _ => parts.push(SnippetPart { text, highlighted }),
+        }
+    };
+    let slice = |start: usize, end: usize| -> String {
+        chars[start..end]
+            .iter()
+            .map(|&c| if c == '\n' { ' ' } else { c })
+            .collect()
+    };
+
+    if from > 0 {
+        push("…".to_string(), false);
+    }
+    let mut position = from;
+    for &(start, end) in &merged {
+        let (start, end) = (start.max(from), end.min(to));
+        if start >= end {
+            continue;
+        }
+        push(slice(position, start), false);
+        push(slice(start, end), true);
+        position = end;
+    }
+    push(slice(position, to), false);
+    if to < chars.len() {
+        push("…".to_string(), false);
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> SearchIndex {
+        let mut index = SearchIndex::new();
+        index.add_article(
+            "compactness",
+            "Compactness",
+            &["math/topology".to_string()],
+            "Every open cover has a finite subcover. 位相空間の性質です。",
+        );
+        index.add_article(
+            "rust-ownership",
+            "Rust の所有権",
+            &["rust".to_string()],
+            "Ownership and borrowing in Rust.",
+        );
+        index.add_article(
+            "topology-notes",
+            "位相空間メモ",
+            &["math/topology".to_string()],
+            "開集合と閉集合について。",
+        );
+        index
+    }
+
+    fn slugs(hits: &[SearchHit]) -> Vec<String> {
+        hits.iter().map(|hit| hit.document.slug.clone()).collect()
+    }
+
+    fn highlighted(parts: &[SnippetPart]) -> Vec<&str> {
+        parts
+            .iter()
+            .filter(|part| part.highlighted)
+            .map(|part| part.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_search_ranks_title_matches_first() {
+        let index = index();
+        let hits = index.search("位相空間", 10);
+        assert_eq!(slugs(&hits), ["topology-notes", "compactness"]);
+        assert_eq!(highlighted(&hits[0].title), ["位相空間"]);
+        assert_eq!(highlighted(&hits[1].snippet), ["位相空間"]);
+
+        assert_eq!(slugs(&index.search("所有権", 10)), ["rust-ownership"]);
+        assert!(index.search("", 10).is_empty());
+        // One of four tokens is not enough
+        let hits = index.search("rust 位相空間", 10);
+        assert!(hits.iter().all(|hit| hit.document.slug != "rust-ownership"));
+    }
+
+    #[test]
+    fn test_search_completes_prefix_and_forgives_typos() {
+        let index = index();
+        let hits = index.search("own", 10);
+        assert_eq!(slugs(&hits), ["rust-ownership"]);
+        assert_eq!(highlighted(&hits[0].snippet), ["Ownership"]);
+        // A finished word is not completed
+        assert!(index.search("own ", 10).is_empty());
+
+        let hits = index.search("compactnes", 10);
+        assert_eq!(slugs(&hits), ["compactness"]);
+        assert_eq!(slugs(&index.search("topolgy ", 10)).len(), 2);
+        assert_eq!(slugs(&index.search("borowing", 10)), ["rust-ownership"]);
+    }
+
+    #[test]
+    fn test_highlight_cuts_around_matches() {
+        let text = format!("{}Rust here.\nMore{}", "x ".repeat(60), " y".repeat(60));
+        let parts = highlight(&text, &["rust"], Some(20));
+        assert!(parts.first().unwrap().text.starts_with('…'));
+        assert_eq!(highlighted(&parts), ["Rust"]);
+        assert!(parts.last().unwrap().text.ends_with('…'));
+        assert!(parts.iter().all(|part| !part.text.contains('\n')));
+
+        // Words only match from their start
+        assert!(highlighted(&highlight("trust", &["rust"], None)).is_empty());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        let distance = |a: &str, b: &str| {
+            edit_distance(
+                &a.chars().collect::<Vec<_>>(),
+                &b.chars().collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(distance("topology", "topolgoy"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "abc"), 3);
+    }
+}
@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+use super::index::plain_text;
+use super::tokenizer::tokenize;
+use crate::core::articles::tags::tag_ancestors;
+
+/// `[related]` section of project.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RelatedConfig {
+    /// Similar articles kept per article
+    pub limit: usize,
+    /// Share of the score that comes from shared tags rather than shared words (0–1)
+    pub tag_weight: f32,
+    /// Articles scoring below this are not shown as related
+    pub min_score: f32,
+}
+
+impl Default for RelatedConfig {
+    fn default() -> Self {
+        Self {
+            limit: 5,
+            tag_weight: 0.3,
+            min_score: 0.1,
+        }
+    }
+}
+
+/// Article shown under another one, with what the page needs to list it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RelatedArticle {
+    pub slug: String,
+    pub title: String,
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Content similarity (0–1); `None` for articles listed in `related_articles`
+    #[serde(default)]
+    pub score: Option<f32>,
+}
+
+/// Term and tag profiles of articles, compared pairwise to find similar ones
+#[derive(Debug, Clone, Default)]
+pub struct SimilarityIndex {
+    documents: Vec<Profile>,
+}
+
+#[derive(Debug, Clone)]
+struct Profile {
+    terms: HashMap<String, u32>,
+    /// Tags and their parents, so `math/topology` and `math/analysis` share `math`
+    tags: BTreeSet<String>,
+}
+
+impl SimilarityIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an article from its markdown body (front matter already removed); articles are
+    /// identified by the order they were added
+    pub fn add_article(&mut self, title: &str, tags: &[String], markdown: &str) {
+        let (text, _) = plain_text(markdown);
+        let mut terms: HashMap<String, u32> = HashMap::new();
+        for token in tokenize(title).into_iter().chain(tokenize(&text)) {
+            *terms.entry(token.text).or_default() += 1;
+        }
+        let tags = tags
+            .iter()
+            .flat_map(|tag| tag_ancestors(tag))
+            .map(str::to_string)
+            .collect();
+        self.documents.push(Profile { terms, tags });
+    }
+
+    /// For every article, the most similar others as (position, score), best first
+    ///
+    /// Scores blend the cosine similarity of TF-IDF vectors with the Jaccard similarity
+    /// of the tag sets, and are rounded to three decimals.
+    pub fn neighbours(&self, config: &RelatedConfig) -> Vec<Vec<(usize, f32)>> {
+        let vectors = self.tf_idf_vectors();
+        let tag_weight = config.tag_weight.clamp(0.0, 1.0);
+        let profiles = &self.documents;
+
+        (0..profiles.len())
+            .map(|this| {
+                let mut scores: Vec<(usize, f32)> = (0..profiles.len())
+                    .filter(|&other| other != this)
+                    .map(|other| {
+                        let text = cosine(&vectors[this], &vectors[other]);
+                        let tags = jaccard(&profiles[this].tags, &profiles[other].tags);
+                        let score = (1.0 - tag_weight) * text + tag_weight * tags;
+                        (other, (score * 1000.0).round() / 1000.0)
+                    })
+                    .filter(|&(_, score)| score > 0.0 && score >= config.min_score)
+                    .collect();
+                scores.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+                scores.truncate(config.limit);
+                scores
+            })
+            .collect()
+    }
+
+    /// Unit-length TF-IDF vectors; terms found in every article weigh nothing
+    fn tf_idf_vectors(&self) -> Vec<HashMap<&str, f32>> {
+        let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+        for document in &self.documents {
+            for term in document.terms.keys() {
+                *document_frequency.entry(term).or_default() += 1;
+            }
+        }
+
+        let total = self.documents.len() as f32;
+        self.documents
+            .iter()
+            .map(|document| {
+                let mut vector: HashMap<&str, f32> = document
+                    .terms
+                    .iter()
+                    .map(|(term, &count)| {
+                        let idf = (total / document_frequency[term.as_str()] as f32).ln();
+                        (term.as_str(), (1.0 + (count as f32).ln()) * idf)
+                    })
+                    .filter(|&(_, weight)| weight > 0.0)
+                    .collect();
+                let norm = vector.values().map(|w| w * w).sum::<f32>().sqrt();
+                if norm > 0.0 {
+                    vector.values_mut().for_each(|w| *w /= norm);
+                }
+                vector
+            })
+            .collect()
+    }
+}
+
+fn cosine(a: &HashMap<&str, f32>, b: &HashMap<&str, f32>) -> f32 {
+    let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    small
+        .iter()
+        .filter_map(|(term, weight)| large.get(term).map(|other| weight * other))
+        .sum()
+}
+
+fn jaccard(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|tag| tag.to_string()).collect()
+    }
+
+    fn index() -> SimilarityIndex {
+        let mut index = SimilarityIndex::new();
+        index.add_article(
+            "コンパクト性",
+            &tags(&["math/topology"]),
+            "位相空間のコンパクト性と開被覆について。",
+        );
+        index.add_article(
+            "開被覆メモ",
+            &tags(&["math/topology"]),
+            "開被覆と位相空間の基本的な性質。",
+        );
+        index.add_article(
+            "Rust ownership",
+            &tags(&["rust"]),
+            "Ownership and borrowing rules in Rust.",
+        );
+        index.add_article("Lifetimes", &tags(&["rust"]), "Borrowing needs lifetimes.");
+        index
+    }
+
+    #[test]
+    fn test_neighbours_prefer_shared_words_and_tags() {
+        let neighbours = index().neighbours(&RelatedConfig::default());
+        assert_eq!(neighbours[0].first().map(|&(other, _)| other), Some(1));
+        assert_eq!(neighbours[2].first().map(|&(other, _)| other), Some(3));
+        // Nothing in common with the Rust articles
+        assert!(neighbours[0].iter().all(|&(other, _)| other < 2));
+        assert!(neighbours[0][0].1 > 0.3 && neighbours[0][0].1 <= 1.0);
+    }
+
+    #[test]
+    fn test_neighbours_respect_limit_and_min_score() {
+        let index = index();
+        let config = RelatedConfig {
+            limit: 1,
+            tag_weight: 1.0,
+            min_score: 0.0,
+        };
+        let neighbours = index.neighbours(&config);
+        assert!(neighbours.iter().all(|list| list.len() == 1));
+        assert_eq!(neighbours[1], [(0, 1.0)]);
+
+        let strict = RelatedConfig {
+            min_score: 1.1,
+            ..RelatedConfig::default()
+        };
+        assert!(index.neighbours(&strict).iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn test_jaccard_counts_parent_tags() {
+        let mut index = SimilarityIndex::new();
+        index.add_article("a", &tags(&["math/topology"]), "");
+        index.add_article("b", &tags(&["math/analysis"]), "");
+        // {math, math/topology} and {math, math/analysis} share one of three
+        assert!(
+            (jaccard(&index.documents[0].tags, &index.documents[1].tags) - 1.0 / 3.0).abs() < 1e-6
+        );
+    }
+}
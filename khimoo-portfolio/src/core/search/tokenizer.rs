@@ -0,0 +1,114 @@
+use crate::core::articles::stats::is_cjk;
+
+/// How a token was cut, which decides how queries may match it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Run of letters and digits between spaces or punctuation (English and the like)
+    Word,
+    /// Two adjacent CJK characters, or a lone one
+    Cjk,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    pub kind: TokenKind,
+}
+
+/// Lowercase and fold full-width ASCII, one character for one, so positions in the
+/// normalised text are positions in the original
+pub fn normalize_char(c: char) -> char {
+    let c = match c {
+        '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+        '\u{3000}' => ' ',
+        _ => c,
+    };
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) => lower,
+        _ => c,
+    }
+}
+
+/// Letters and digits that make up words; CJK characters are handled separately
+pub fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() && !is_cjk(c)
+}
+
+/// Words for Latin-script text and bigrams for Japanese, Chinese and Korean
+///
+/// Bigrams need no dictionary and still match any substring of two or more characters,
+/// which is what a reader typing part of a Japanese phrase expects.
+pub fn tokenize(text: &str) -> Vec<Token> {
+    let chars: Vec<char> = text.chars().map(normalize_char).collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+        let run_end = |start: usize, belongs: fn(char) -> bool| {
+            (start..chars.len())
+                .find(|&i| !belongs(chars[i]))
+                .unwrap_or(chars.len())
+        };
+
+        if is_cjk(c) {
+            let end = run_end(index, is_cjk);
+            let run = &chars[index..end];
+            if run.len() == 1 {
+                tokens.push(Token {
+                    text: run[0].to_string(),
+                    kind: TokenKind::Cjk,
+                });
+            }
+            tokens.extend(run.windows(2).map(|pair| Token {
+                text: pair.iter().collect(),
+                kind: TokenKind::Cjk,
+            }));
+            index = end;
+        } else if is_word_char(c) {
+            let end = run_end(index, is_word_char);
+            let word: String = chars[index..end].iter().collect();
+            // Single letters are noise in English; single digits still mean something
+            if end - index > 1 || c.is_ascii_digit() {
+                tokens.push(Token {
+                    text: word,
+                    kind: TokenKind::Word,
+                });
+            }
+            index = end;
+        } else {
+            index += 1;
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(text: &str) -> Vec<String> {
+        tokenize(text).into_iter().map(|token| token.text).collect()
+    }
+
+    #[test]
+    fn test_tokenize_mixed_text() {
+        assert_eq!(
+            texts("Rustの位相空間 (Topology) a 2"),
+            ["rust", "の位", "位相", "相空", "空間", "topology", "2"]
+        );
+        assert_eq!(texts("ＲＵＳＴ　と"), ["rust", "と"]);
+        assert_eq!(tokenize("空間")[0].kind, TokenKind::Cjk);
+        assert_eq!(tokenize("don't")[0].kind, TokenKind::Word);
+    }
+
+    #[test]
+    fn test_normalize_char_keeps_positions() {
+        let text = "Ｒust İ";
+        let normalized: String = text.chars().map(normalize_char).collect();
+        assert_eq!(normalized.chars().count(), text.chars().count());
+        assert!(normalized.starts_with("rust"));
+    }
+}
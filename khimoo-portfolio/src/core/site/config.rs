@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::github_pages_path_from_toml;
+
+/// `[site]` section of project.toml: how the deployed site presents itself
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SiteConfig {
+    /// Scheme and host the site is deployed on, without a path
+    pub origin: String,
+    /// Public URL of the site: `origin` plus `[deployment] github_pages_path`
+    #[serde(skip)]
+    pub url: String,
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    /// BCP 47 language tag of the articles
+    pub language: String,
+    /// `@handle` for `twitter:site`
+    pub twitter: Option<String>,
+}
+
+impl Default for SiteConfig {
+    fn default() -> Self {
+        Self {
+            origin: "https://khimoo.github.io".to_string(),
+            url: "https://khimoo.github.io/portfolio-page".to_string(),
+            name: "Khimoo Portfolio".to_string(),
+            description: String::new(),
+            author: "khimoo".to_string(),
+            language: "ja".to_string(),
+            twitter: None,
+        }
+    }
+}
+
+impl SiteConfig {
+    /// Read the `[site]` section from project.toml content, with the public URL under
+    /// `[deployment] github_pages_path`
+    pub fn from_project_toml(content: &str) -> Self {
+        let mut site: Self = toml::from_str::<toml::Value>(content)
+            .ok()
+            .and_then(|config| config.get("site").cloned())
+            .and_then(|section| section.try_into().ok())
+            .unwrap_or_default();
+        site.url = format!(
+            "{}{}",
+            site.origin.trim_end_matches('/'),
+            github_pages_path_from_toml(content)
+        );
+        site
+    }
+
+    /// Absolute URL of a site path (`/article/位相` → `https://…/article/%E4%BD%8D%E7%9B%B8`)
+    ///
+    /// URLs that are already absolute are returned unchanged.
+    pub fn absolute_url(&self, path: &str) -> String {
+        if path.starts_with("https://") || path.starts_with("http://") {
+            return path.to_string();
+        }
+        let path = encode_path(path.trim_start_matches('/'));
+        format!("{}/{path}", self.url.trim_end_matches('/'))
+    }
+}
+
+/// Percent-encode everything in a URL path except unreserved characters and separators
+pub fn encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Undo percent-encoding, as in a `location.hash`; invalid escapes are kept as written
+pub fn decode_path(encoded: &str) -> String {
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| encoded.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_site_from_project_toml() {
+        let site = SiteConfig::from_project_toml(concat!(
+            "[deployment]\ngithub_pages_path = \"/blog/\"\n",
+            "[site]\norigin = \"https://example.com/\"\nname = \"Notes\"\n",
+        ));
+        assert_eq!(site.url, "https://example.com/blog");
+        assert_eq!(site.name, "Notes");
+        assert_eq!(site.language, "ja");
+        assert_eq!(
+            site.absolute_url("/article/位相 空間"),
+            "https://example.com/blog/article/%E4%BD%8D%E7%9B%B8%20%E7%A9%BA%E9%96%93"
+        );
+        assert_eq!(
+            site.absolute_url("https://cdn.example.com/a.png"),
+            "https://cdn.example.com/a.png"
+        );
+        assert_eq!(site.absolute_url("/"), "https://example.com/blog/");
+    }
+
+    #[test]
+    fn test_decode_path() {
+        assert_eq!(decode_path(&encode_path("位相 空間/a-b")), "位相 空間/a-b");
+        assert_eq!(decode_path("100%"), "100%");
+        assert_eq!(decode_path("%zz%4"), "%zz%4");
+    }
+}
@@ -0,0 +1,411 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::config::SiteConfig;
+use super::sitemap::latest_date;
+use crate::core::render::links::escape_attribute;
+
+/// How much of each article the feeds carry
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedContent {
+    /// The listing summary
+    #[default]
+    Summary,
+    /// The whole rendered article
+    Full,
+}
+
+/// `[feed]` section of project.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct FeedConfig {
+    pub content: FeedContent,
+    /// Newest articles kept in each feed
+    pub limit: usize,
+}
+
+impl Default for FeedConfig {
+    fn default() -> Self {
+        Self {
+            content: FeedContent::Summary,
+            limit: 20,
+        }
+    }
+}
+
+/// One article of a feed; paths are site paths made absolute when written
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FeedEntry {
+    pub title: String,
+    pub path: String,
+    pub summary: Option<String>,
+    /// Rendered article with absolute URLs, for `content = "full"`
+    pub content_html: Option<String>,
+    /// RFC 3339
+    pub published: Option<String>,
+    /// RFC 3339
+    pub updated: Option<String>,
+    pub tags: Vec<String>,
+    pub image: Option<String>,
+}
+
+/// Feed advertised by a page with `<link rel="alternate">`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedLink {
+    pub title: String,
+    /// Absolute URL of the Atom feed
+    pub atom_url: String,
+    /// Absolute URL of the JSON Feed
+    pub json_url: String,
+}
+
+impl FeedLink {
+    /// Link to the feed written to `data/{stem}.xml` and `data/{stem}.json`
+    pub fn new(site: &SiteConfig, title: impl Into<String>, stem: &str) -> Self {
+        Self {
+            title: title.into(),
+            atom_url: site.absolute_url(&atom_path(stem)),
+            json_url: site.absolute_url(&json_path(stem)),
+        }
+    }
+
+    /// `(type, href)` of each format, Atom first
+    pub fn alternates(&self) -> [(&'static str, &str); 2] {
+        [
+            ("application/atom+xml", self.atom_url.as_str()),
+            ("application/feed+json", self.json_url.as_str()),
+        ]
+    }
+}
+
+/// Stem of the feed of `tag` and its children
+pub fn tag_feed_stem(tag: &str) -> String {
+    format!("feeds/{tag}")
+}
+
+fn atom_path(stem: &str) -> String {
+    format!("/data/{stem}.xml")
+}
+
+fn json_path(stem: &str) -> String {
+    format!("/data/{stem}.json")
+}
+
+/// Atom and JSON Feed documents for a set of articles
+#[derive(Debug, Clone)]
+pub struct Feed<'s> {
+    site: &'s SiteConfig,
+    title: String,
+    home_path: String,
+    /// Path of the feed files without extension, relative to the data directory
+    stem: String,
+    entries: Vec<FeedEntry>,
+}
+
+impl<'s> Feed<'s> {
+    /// Feed of the `limit` newest entries, written to `data/{stem}.xml` and `data/{stem}.json`
+    pub fn new(
+        site: &'s SiteConfig,
+        title: impl Into<String>,
+        home_path: impl Into<String>,
+        stem: impl Into<String>,
+        mut entries: Vec<FeedEntry>,
+        limit: usize,
+    ) -> Self {
+        // Newest first; undated articles last
+        entries.sort_by_key(|entry| {
+            std::cmp::Reverse(
+                entry
+                    .published
+                    .as_deref()
+                    .and_then(|value| DateTime::parse_from_rfc3339(value).ok()),
+            )
+        });
+        entries.truncate(limit);
+
+        Self {
+            site,
+            title: title.into(),
+            home_path: home_path.into(),
+            stem: stem.into(),
+            entries,
+        }
+    }
+
+    /// Site path of the Atom feed
+    pub fn atom_path(&self) -> String {
+        atom_path(&self.stem)
+    }
+
+    /// Site path of the JSON Feed
+    pub fn json_path(&self) -> String {
+        json_path(&self.stem)
+    }
+
+    /// Atom 1.0 document; `generated_at` stands in for undated articles
+    pub fn atom(&self, generated_at: &str) -> String {
+        let site = self.site;
+        let feed_url = site.absolute_url(&self.atom_path());
+        let updated = latest_date(
+            self.entries
+                .iter()
+                .map(|entry| entry.updated.as_deref().or(entry.published.as_deref())),
+        )
+        .unwrap_or(generated_at);
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        xml.push_str(&format!(
+            "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"{}\">\n",
+            escape_attribute(&site.language)
+        ));
+        xml.push_str(&format!(
+            "  <title>{}</title>\n",
+            escape_attribute(&self.title)
+        ));
+        if !site.description.is_empty() {
+            xml.push_str(&format!(
+                "  <subtitle>{}</subtitle>\n",
+                escape_attribute(&site.description)
+            ));
+        }
+        xml.push_str(&format!(
+            "  <link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>\n",
+            escape_attribute(&site.absolute_url(&self.home_path))
+        ));
+        xml.push_str(&format!(
+            "  <link rel=\"self\" type=\"application/atom+xml\" href=\"{}\"/>\n",
+            escape_attribute(&feed_url)
+        ));
+        xml.push_str(&format!("  <id>{}</id>\n", escape_attribute(&feed_url)));
+        xml.push_str(&format!(
+            "  <updated>{}</updated>\n",
+            escape_attribute(updated)
+        ));
+        xml.push_str(&format!(
+            "  <author><name>{}</name></author>\n",
+            escape_attribute(&site.author)
+        ));
+
+        for entry in &self.entries {
+            let url = escape_attribute(&site.absolute_url(&entry.path));
+            xml.push_str("  <entry>\n");
+            xml.push_str(&format!(
+                "    <title>{}</title>\n",
+                escape_attribute(&entry.title)
+            ));
+            xml.push_str(&format!(
+                "    <link rel=\"alternate\" type=\"text/html\" href=\"{url}\"/>\n"
+            ));
+            xml.push_str(&format!("    <id>{url}</id>\n"));
+            if let Some(published) = &entry.published {
+                xml.push_str(&format!(
+                    "    <published>{}</published>\n",
+                    escape_attribute(published)
+                ));
+            }
+            let entry_updated = entry
+                .updated
+                .as_deref()
+                .or(entry.published.as_deref())
+                .unwrap_or(generated_at);
+            xml.push_str(&format!(
+                "    <updated>{}</updated>\n",
+                escape_attribute(entry_updated)
+            ));
+            for tag in &entry.tags {
+                xml.push_str(&format!(
+                    "    <category term=\"{}\"/>\n",
+                    escape_attribute(tag)
+                ));
+            }
+            if let Some(summary) = &entry.summary {
+                xml.push_str(&format!(
+                    "    <summary>{}</summary>\n",
+                    escape_attribute(summary)
+                ));
+            }
+            if let Some(content) = &entry.content_html {
+                xml.push_str(&format!(
+                    "    <content type=\"html\">{}</content>\n",
+                    escape_attribute(content)
+                ));
+            }
+            xml.push_str("  </entry>\n");
+        }
+        xml.push_str("</feed>\n");
+        xml
+    }
+
+    /// JSON Feed 1.1 document
+    pub fn json(&self) -> Value {
+        let site = self.site;
+        let items: Vec<Value> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let url = site.absolute_url(&entry.path);
+                let mut item = json!({ "id": url, "url": url, "title": entry.title });
+                let mut set = |key: &str, value: Option<Value>| {
+                    if let Some(value) = value {
+                        item[key] = value;
+                    }
+                };
+                set("summary", entry.summary.as_ref().map(|s| json!(s)));
+                set(
+                    "content_html",
+                    entry.content_html.as_ref().map(|c| json!(c)),
+                );
+                // JSON Feed requires content; summaries double as plain text
+                if entry.content_html.is_none() {
+                    set(
+                        "content_text",
+                        Some(json!(entry.summary.as_deref().unwrap_or(""))),
+                    );
+                }
+                set("date_published", entry.published.as_ref().map(|d| json!(d)));
+                set("date_modified", entry.updated.as_ref().map(|d| json!(d)));
+                set(
+                    "image",
+                    entry
+                        .image
+                        .as_ref()
+                        .map(|image| json!(site.absolute_url(image))),
+                );
+                if !entry.tags.is_empty() {
+                    set("tags", Some(json!(entry.tags)));
+                }
+                item
+            })
+            .collect();
+
+        let mut feed = json!({
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": self.title,
+            "home_page_url": site.absolute_url(&self.home_path),
+            "feed_url": site.absolute_url(&self.json_path()),
+            "language": site.language,
+            "authors": [{ "name": site.author }],
+            "items": items,
+        });
+        if !site.description.is_empty() {
+            feed["description"] = json!(site.description);
+        }
+        feed
+    }
+}
+
+/// Make root-relative `href` and `src` attributes absolute, as feed readers show content
+/// outside the site; `html` must be rendered without a base path
+pub fn absolutize_urls(html: &str, site: &SiteConfig) -> String {
+    let base = site.url.trim_end_matches('/');
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(index) = ["href=\"/", "src=\"/"]
+        .iter()
+        .filter_map(|attribute| rest.find(attribute).map(|i| i + attribute.len() - 1))
+        .min()
+    {
+        output.push_str(&rest[..index]);
+        rest = &rest[index..];
+        // Protocol-relative URLs already name a host
+        if !rest.starts_with("//") {
+            output.push_str(base);
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn site() -> SiteConfig {
+        SiteConfig {
+            url: "https://example.com/blog".to_string(),
+            name: "Notes".to_string(),
+            author: "khimoo".to_string(),
+            ..SiteConfig::default()
+        }
+    }
+
+    fn entry(title: &str, published: Option<&str>) -> FeedEntry {
+        FeedEntry {
+            title: title.to_string(),
+            path: format!("/article/{}", title.to_lowercase()),
+            summary: Some(format!("About {title} & more")),
+            published: published.map(str::to_string),
+            tags: vec!["math/topology".to_string()],
+            ..FeedEntry::default()
+        }
+    }
+
+    #[test]
+    fn test_feed_sorts_and_limits_entries() {
+        let site = site();
+        let entries = vec![
+            entry("Old", Some("2023-01-01T00:00:00+09:00")),
+            entry("Undated", None),
+            entry("New", Some("2024-05-01T00:00:00+09:00")),
+        ];
+        let feed = Feed::new(&site, "Notes", "/article", "feed", entries, 2);
+
+        let atom = feed.atom("2024-06-01T00:00:00+00:00");
+        assert!(
+            atom.find("<title>New</title>").unwrap() < atom.find("<title>Old</title>").unwrap()
+        );
+        assert!(!atom.contains("Undated"));
+        assert!(atom.contains("<updated>2024-05-01T00:00:00+09:00</updated>\n  <author>"));
+        assert!(atom.contains(
+            "<link rel=\"self\" type=\"application/atom+xml\" href=\"https://example.com/blog/data/feed.xml\"/>"
+        ));
+        assert!(atom.contains("<id>https://example.com/blog/article/new</id>"));
+        assert!(atom.contains("<summary>About New &amp; more</summary>"));
+        assert!(atom.contains("<category term=\"math/topology\"/>"));
+
+        let json = feed.json();
+        assert_eq!(json["version"], "https://jsonfeed.org/version/1.1");
+        assert_eq!(json["feed_url"], "https://example.com/blog/data/feed.json");
+        assert_eq!(
+            json["items"][0]["url"],
+            "https://example.com/blog/article/new"
+        );
+        assert_eq!(json["items"][0]["content_text"], "About New & more");
+        assert_eq!(json["items"][1]["tags"][0], "math/topology");
+    }
+
+    #[test]
+    fn test_full_content_is_escaped() {
+        let site = site();
+        let mut article = entry("Full", Some("2024-05-01T00:00:00+09:00"));
+        article.content_html = Some("<p>A &amp; B</p>".to_string());
+        let feed = Feed::new(&site, "Notes", "/tag/rust", "feeds/rust", vec![article], 20);
+
+        assert!(feed
+            .atom("2024-06-01T00:00:00+00:00")
+            .contains("<content type=\"html\">&lt;p&gt;A &amp;amp; B&lt;/p&gt;</content>"));
+        let json = feed.json();
+        assert_eq!(json["items"][0]["content_html"], "<p>A &amp; B</p>");
+        assert!(json["items"][0].get("content_text").is_none());
+        assert_eq!(json["home_page_url"], "https://example.com/blog/tag/rust");
+    }
+
+    #[test]
+    fn test_absolutize_urls() {
+        let html = concat!(
+            "<a href=\"/article/other#x\">o</a><img src=\"/articles/img/a.png\">",
+            "<a href=\"//cdn.example.com/x\">c</a><a href=\"#top\">t</a>",
+        );
+        assert_eq!(
+            absolutize_urls(html, &site()),
+            concat!(
+                "<a href=\"https://example.com/blog/article/other#x\">o</a>",
+                "<img src=\"https://example.com/blog/articles/img/a.png\">",
+                "<a href=\"//cdn.example.com/x\">c</a><a href=\"#top\">t</a>",
+            )
+        );
+    }
+}
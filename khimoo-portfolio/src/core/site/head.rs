@@ -0,0 +1,391 @@
+use serde_json::{json, Value};
+
+use super::config::SiteConfig;
+use super::feed::{tag_feed_stem, FeedLink};
+use crate::core::articles::metadata::ArticleMetadata;
+use crate::core::articles::tags::validate_tag;
+
+/// Attribute marking the head elements that belong to the current route
+pub const PAGE_HEAD_ATTRIBUTE: &str = "data-page-head";
+
+/// `<meta>` element of a page head
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetaTag {
+    /// `name` for standard and Twitter tags, `property` for Open Graph
+    pub attribute: &'static str,
+    pub key: &'static str,
+    pub content: String,
+}
+
+impl MetaTag {
+    fn name(key: &'static str, content: impl Into<String>) -> Self {
+        Self {
+            attribute: "name",
+            key,
+            content: content.into(),
+        }
+    }
+
+    fn property(key: &'static str, content: impl Into<String>) -> Self {
+        Self {
+            attribute: "property",
+            key,
+            content: content.into(),
+        }
+    }
+}
+
+/// Article details for `og:type=article` and JSON-LD
+#[derive(Debug, Clone, PartialEq)]
+struct ArticleDetails {
+    published: Option<String>,
+    modified: Option<String>,
+    tags: Vec<String>,
+}
+
+/// Title, description, link-preview tags and structured data of one page
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageHead {
+    /// `document.title`, with the site name appended
+    pub title: String,
+    /// Page title alone, for Open Graph and JSON-LD
+    pub headline: String,
+    pub description: Option<String>,
+    pub canonical_url: Option<String>,
+    /// Absolute URL of the link-preview image
+    pub image: Option<String>,
+    /// Ask search engines not to index the page (`<meta name="robots">`)
+    pub noindex: bool,
+    /// Feeds for feed readers to discover; the site feed on every page
+    pub feeds: Vec<FeedLink>,
+    site_name: String,
+    language: String,
+    author: String,
+    twitter: Option<String>,
+    article: Option<ArticleDetails>,
+}
+
+impl PageHead {
+    /// Head for pages without their own: the site name and description
+    pub fn site(site: &SiteConfig) -> Self {
+        Self {
+            title: site.name.clone(),
+            headline: site.name.clone(),
+            description: Some(site.description.clone()).filter(|d| !d.is_empty()),
+            canonical_url: None,
+            image: None,
+            noindex: false,
+            feeds: vec![FeedLink::new(site, site.name.clone(), "feed")],
+            site_name: site.name.clone(),
+            language: site.language.clone(),
+            author: site.author.clone(),
+            twitter: site.twitter.clone(),
+            article: None,
+        }
+    }
+
+    /// Head for a listing page at `path`
+    pub fn page(site: &SiteConfig, path: &str, title: &str, description: Option<&str>) -> Self {
+        Self {
+            title: format!("{title} | {}", site.name),
+            headline: title.to_string(),
+            description: description.map(str::to_string),
+            canonical_url: Some(site.absolute_url(path)),
+            ..Self::site(site)
+        }
+    }
+
+    /// Head for the article index (`/article`)
+    pub fn article_index(site: &SiteConfig) -> Self {
+        Self::page(site, "/article", "記事一覧", None)
+    }
+
+    /// Head for `/tag/{tag}`; without a registered description, the article count is used
+    pub fn tag(
+        site: &SiteConfig,
+        tag: &str,
+        name: &str,
+        description: Option<&str>,
+        count: usize,
+    ) -> Self {
+        let description = description
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("#{tag} の記事 {count} 件"));
+        let mut head = Self::page(
+            site,
+            &format!("/tag/{tag}"),
+            &format!("#{name}"),
+            Some(&description),
+        );
+        // Feeds are only written for tags with articles and a usable path
+        if count > 0 && validate_tag(tag).is_ok() {
+            head.feeds.push(FeedLink::new(
+                site,
+                format!("{} #{name}", site.name),
+                &tag_feed_stem(tag),
+            ));
+        }
+        head
+    }
+
+    /// Head for the article at `path`; `image` is a site path or absolute URL
+    pub fn article(
+        site: &SiteConfig,
+        path: &str,
+        title: &str,
+        metadata: &ArticleMetadata,
+        summary: Option<&str>,
+        image: Option<&str>,
+    ) -> Self {
+        Self {
+            image: image.map(|image| site.absolute_url(image)),
+            noindex: !metadata.is_indexable(),
+            article: Some(ArticleDetails {
+                published: metadata.created_at.clone(),
+                modified: metadata.updated_at.clone(),
+                tags: metadata.tags.clone(),
+            }),
+            ..Self::page(site, path, title, summary)
+        }
+    }
+
+    /// `<meta>` elements in document order; `article:tag` repeats once per tag
+    pub fn meta_tags(&self) -> Vec<MetaTag> {
+        let mut tags = Vec::new();
+        if let Some(description) = &self.description {
+            tags.push(MetaTag::name("description", description));
+        }
+        if self.noindex {
+            tags.push(MetaTag::name("robots", "noindex"));
+        }
+
+        let og_type = if self.article.is_some() {
+            "article"
+        } else {
+            "website"
+        };
+        tags.push(MetaTag::property("og:site_name", &self.site_name));
+        tags.push(MetaTag::property("og:type", og_type));
+        tags.push(MetaTag::property("og:title", &self.headline));
+        if let Some(description) = &self.description {
+            tags.push(MetaTag::property("og:description", description));
+        }
+        if let Some(url) = &self.canonical_url {
+            tags.push(MetaTag::property("og:url", url));
+        }
+        if let Some(image) = &self.image {
+            tags.push(MetaTag::property("og:image", image));
+        }
+        if let Some(article) = &self.article {
+            if let Some(published) = &article.published {
+                tags.push(MetaTag::property("article:published_time", published));
+            }
+            if let Some(modified) = &article.modified {
+                tags.push(MetaTag::property("article:modified_time", modified));
+            }
+            for tag in &article.tags {
+                tags.push(MetaTag::property("article:tag", tag));
+            }
+        }
+
+        let card = if self.image.is_some() {
+            "summary_large_image"
+        } else {
+            "summary"
+        };
+        tags.push(MetaTag::name("twitter:card", card));
+        if let Some(handle) = &self.twitter {
+            tags.push(MetaTag::name("twitter:site", handle));
+        }
+        tags.push(MetaTag::name("twitter:title", &self.headline));
+        if let Some(description) = &self.description {
+            tags.push(MetaTag::name("twitter:description", description));
+        }
+        if let Some(image) = &self.image {
+            tags.push(MetaTag::name("twitter:image", image));
+        }
+        tags
+    }
+
+    /// `schema.org/BlogPosting` for article pages
+    pub fn json_ld(&self) -> Option<Value> {
+        let article = self.article.as_ref()?;
+        let mut data = json!({
+            "@context": "https://schema.org",
+            "@type": "BlogPosting",
+            "headline": self.headline,
+            "inLanguage": self.language,
+            "author": { "@type": "Person", "name": self.author },
+            "publisher": { "@type": "Person", "name": self.author },
+        });
+        let mut set = |key: &str, value: Option<Value>| {
+            if let Some(value) = value {
+                data[key] = value;
+            }
+        };
+        set("description", self.description.as_ref().map(|d| json!(d)));
+        set("image", self.image.as_ref().map(|image| json!(image)));
+        set("url", self.canonical_url.as_ref().map(|url| json!(url)));
+        set(
+            "mainEntityOfPage",
+            self.canonical_url
+                .as_ref()
+                .map(|url| json!({ "@type": "WebPage", "@id": url })),
+        );
+        set(
+            "datePublished",
+            article.published.as_ref().map(|d| json!(d)),
+        );
+        set(
+            "dateModified",
+            article
+                .modified
+                .as_ref()
+                .or(article.published.as_ref())
+                .map(|d| json!(d)),
+        );
+        if !article.tags.is_empty() {
+            set("keywords", Some(json!(article.tags.join(", "))));
+        }
+        Some(data)
+    }
+
+    /// JSON-LD as `<script type="application/ld+json">` content that cannot close the element
+    pub fn json_ld_script(&self) -> Option<String> {
+        let data = self.json_ld()?;
+        Some(escape_script_json(&data.to_string()))
+    }
+}
+
+/// Make JSON safe to embed in a `<script>` element
+///
+/// Escaping only `</` is not enough: `<!--` followed by `<script` keeps the next
+/// `</script>` from closing the element. `<`, `>` and `&` only occur inside JSON strings,
+/// where `\u003c` and friends decode to the same text.
+pub fn escape_script_json(json: &str) -> String {
+    json.replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn site() -> SiteConfig {
+        SiteConfig {
+            url: "https://example.com/blog".to_string(),
+            name: "Notes".to_string(),
+            twitter: Some("@notes".to_string()),
+            ..SiteConfig::default()
+        }
+    }
+
+    fn content<'a>(tags: &'a [MetaTag], key: &str) -> Vec<&'a str> {
+        tags.iter()
+            .filter(|tag| tag.key == key)
+            .map(|tag| tag.content.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_article_head() {
+        let metadata = ArticleMetadata {
+            tags: vec!["rust".to_string(), "math/topology".to_string()],
+            created_at: Some("2024-05-01T00:00:00+09:00".to_string()),
+            ..ArticleMetadata::default()
+        };
+        let head = PageHead::article(
+            &site(),
+            "/article/compactness",
+            "Compactness",
+            &metadata,
+            Some("Open covers </script> and more."),
+            Some("/data/og/compactness.png"),
+        );
+
+        assert_eq!(head.title, "Compactness | Notes");
+        let tags = head.meta_tags();
+        assert_eq!(content(&tags, "og:type"), ["article"]);
+        assert_eq!(content(&tags, "og:title"), ["Compactness"]);
+        assert_eq!(
+            content(&tags, "og:url"),
+            ["https://example.com/blog/article/compactness"]
+        );
+        assert_eq!(
+            content(&tags, "twitter:image"),
+            ["https://example.com/blog/data/og/compactness.png"]
+        );
+        assert_eq!(content(&tags, "article:tag"), ["rust", "math/topology"]);
+        assert_eq!(content(&tags, "twitter:card"), ["summary_large_image"]);
+        assert!(content(&tags, "robots").is_empty());
+
+        let json_ld = head.json_ld().unwrap();
+        assert_eq!(json_ld["@type"], "BlogPosting");
+        assert_eq!(json_ld["dateModified"], "2024-05-01T00:00:00+09:00");
+        assert_eq!(json_ld["keywords"], "rust, math/topology");
+        assert!(!head.json_ld_script().unwrap().contains("</script>"));
+    }
+
+    #[test]
+    fn test_noindex_article_head() {
+        let metadata = ArticleMetadata {
+            noindex: true,
+            ..ArticleMetadata::default()
+        };
+        let head = PageHead::article(&site(), "/article/a", "A", &metadata, None, None);
+        assert_eq!(content(&head.meta_tags(), "robots"), ["noindex"]);
+    }
+
+    #[test]
+    fn test_site_and_listing_heads() {
+        let default = PageHead::site(&site());
+        assert_eq!(default.title, "Notes");
+        assert!(default.canonical_url.is_none());
+        assert!(default.json_ld().is_none());
+        assert_eq!(content(&default.meta_tags(), "og:type"), ["website"]);
+
+        let index = PageHead::article_index(&site());
+        assert_eq!(index.title, "記事一覧 | Notes");
+        assert!(content(&index.meta_tags(), "description").is_empty());
+        assert_eq!(content(&index.meta_tags(), "twitter:card"), ["summary"]);
+
+        let tag = PageHead::tag(&site(), "math/topology", "位相空間論", None, 3);
+        assert_eq!(tag.title, "#位相空間論 | Notes");
+        assert_eq!(
+            tag.canonical_url.as_deref(),
+            Some("https://example.com/blog/tag/math/topology")
+        );
+        assert_eq!(
+            tag.description.as_deref(),
+            Some("#math/topology の記事 3 件")
+        );
+    }
+
+    #[test]
+    fn test_feed_links() {
+        let default = PageHead::site(&site());
+        assert_eq!(default.feeds.len(), 1);
+        assert_eq!(default.feeds[0].title, "Notes");
+        assert_eq!(
+            default.feeds[0].atom_url,
+            "https://example.com/blog/data/feed.xml"
+        );
+
+        let tag = PageHead::tag(&site(), "math/topology", "位相空間論", None, 3);
+        let urls: Vec<&str> = tag.feeds.iter().map(|f| f.json_url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://example.com/blog/data/feed.json",
+                "https://example.com/blog/data/feeds/math/topology.json",
+            ]
+        );
+        assert_eq!(tag.feeds[1].title, "Notes #位相空間論");
+
+        let empty = PageHead::tag(&site(), "draft", "draft", None, 0);
+        assert_eq!(empty.feeds.len(), 1);
+        let invalid = PageHead::tag(&site(), "a?b", "a?b", None, 2);
+        assert_eq!(invalid.feeds.len(), 1);
+    }
+}
@@ -0,0 +1,24 @@
+//! Site module
+//!
+//! This module contains how the deployed site presents itself: its public URL and name,
+//! the per-page document head (title, description, Open Graph and JSON-LD), the static
+//! HTML pages prerendered for crawlers and readers without JavaScript, the `404.html`
+//! that sends other deep links on GitHub Pages back into the app, the Atom and JSON feeds,
+//! and the sitemap and robots.txt for search engines.
+
+pub mod config;
+pub mod feed;
+pub mod head;
+pub mod prerender;
+pub mod redirect;
+pub mod sitemap;
+
+// Re-export main components
+pub use config::{decode_path, encode_path, SiteConfig};
+pub use feed::{tag_feed_stem, Feed, FeedConfig, FeedContent, FeedEntry, FeedLink};
+pub use head::{MetaTag, PageHead, PAGE_HEAD_ATTRIBUTE};
+pub use prerender::{
+    ListingItem, PageTemplate, PreloadedArticle, PRELOADED_ARTICLE_ID, PRERENDERED_ELEMENT_ID,
+};
+pub use redirect::{redirect_target, spa_redirect_page, REDIRECT_STORAGE_KEY};
+pub use sitemap::{robots_txt, sitemap_xml, SitemapUrl};
@@ -0,0 +1,279 @@
+use serde::{Deserialize, Serialize};
+
+use super::head::{escape_script_json, PageHead, PAGE_HEAD_ATTRIBUTE};
+use crate::config::AppConfig;
+use crate::core::articles::metadata::{display_date, ArticleMetadata};
+use crate::core::render::links::escape_attribute;
+
+/// `id` of the element holding the prerendered markup; the app removes it when it starts
+pub const PRERENDERED_ELEMENT_ID: &str = "prerendered";
+
+/// `id` of the JSON script with the article data the app reuses instead of fetching it
+pub const PRELOADED_ARTICLE_ID: &str = "preloaded-article";
+
+/// Article and markdown body embedded in its prerendered page
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PreloadedArticle<A> {
+    pub article: A,
+    /// Markdown without front matter
+    pub content: String,
+}
+
+/// One entry of a prerendered article listing
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListingItem<'a> {
+    /// Site-relative href including the base path
+    pub href: String,
+    pub title: &'a str,
+    pub summary: Option<&'a str>,
+    pub created_at: Option<&'a str>,
+}
+
+/// The app's built `index.html`, filled in once per prerendered route
+#[derive(Debug, Clone)]
+pub struct PageTemplate {
+    html: String,
+}
+
+impl PageTemplate {
+    pub fn new(html: impl Into<String>) -> Self {
+        Self { html: html.into() }
+    }
+
+    /// Whether the template already went through `render`, so it would get a second head
+    pub fn is_rendered(&self) -> bool {
+        self.html.contains(PAGE_HEAD_ATTRIBUTE)
+    }
+
+    /// Page with `head` in `<head>` and nothing prerendered, for routes the app draws itself
+    pub fn render_head(&self, head: &PageHead) -> String {
+        let mut html = self.html.clone();
+
+        let title = format!("<title>{}</title>", escape_attribute(&head.title));
+        match (html.find("<title>"), html.find("</title>")) {
+            (Some(start), Some(end)) if start < end => {
+                html.replace_range(start..end + "</title>".len(), &title)
+            }
+            _ => insert_before(&mut html, "</head>", &title),
+        }
+        insert_before(&mut html, "</head>", &head_html(head));
+        html
+    }
+
+    /// Page with `head` in `<head>`, `body` shown until the app mounts and optional preloaded data
+    pub fn render(&self, head: &PageHead, body: &str, preload: Option<&str>) -> String {
+        let mut html = self.render_head(head);
+
+        let mut content = format!("\n<div id=\"{PRERENDERED_ELEMENT_ID}\">\n{body}\n</div>\n");
+        if let Some(json) = preload {
+            content.push_str(&format!(
+                "<script type=\"application/json\" id=\"{PRELOADED_ARTICLE_ID}\">{}</script>\n",
+                escape_script_json(json)
+            ));
+        }
+        match html
+            .find("<body")
+            .and_then(|start| html[start..].find('>').map(|end| start + end + 1))
+        {
+            Some(index) => html.insert_str(index, &content),
+            None => html.push_str(&content),
+        }
+        html
+    }
+}
+
+fn insert_before(html: &mut String, marker: &str, content: &str) {
+    match html.find(marker) {
+        Some(index) => html.insert_str(index, content),
+        None => html.push_str(content),
+    }
+}
+
+/// Meta tags, canonical and feed links and JSON-LD, marked so the app replaces them on navigation
+pub fn head_html(head: &PageHead) -> String {
+    let mut html = String::new();
+    for tag in head.meta_tags() {
+        html.push_str(&format!(
+            "<meta {PAGE_HEAD_ATTRIBUTE} {}=\"{}\" content=\"{}\">\n",
+            tag.attribute,
+            tag.key,
+            escape_attribute(&tag.content)
+        ));
+    }
+    if let Some(url) = &head.canonical_url {
+        html.push_str(&format!(
+            "<link {PAGE_HEAD_ATTRIBUTE} rel=\"canonical\" href=\"{}\">\n",
+            escape_attribute(url)
+        ));
+    }
+    for feed in &head.feeds {
+        for (kind, url) in feed.alternates() {
+            html.push_str(&format!(
+                "<link {PAGE_HEAD_ATTRIBUTE} rel=\"alternate\" type=\"{kind}\" title=\"{}\" href=\"{}\">\n",
+                escape_attribute(&feed.title),
+                escape_attribute(url)
+            ));
+        }
+    }
+    if let Some(script) = head.json_ld_script() {
+        html.push_str(&format!(
+            "<script {PAGE_HEAD_ATTRIBUTE} type=\"application/ld+json\">{script}</script>\n"
+        ));
+    }
+    html
+}
+
+/// Article title, dates and tags above its rendered (already sanitized) body
+pub fn article_body(
+    config: &AppConfig,
+    title: &str,
+    metadata: &ArticleMetadata,
+    body_html: &str,
+) -> String {
+    let mut html = format!(
+        "<article>\n<header>\n<h1>{}</h1>\n",
+        escape_attribute(title)
+    );
+
+    let dates: Vec<String> = [
+        ("作成", &metadata.created_at),
+        ("更新", &metadata.updated_at),
+    ]
+    .into_iter()
+    .filter_map(|(label, date)| {
+        let date = date.as_deref()?;
+        let shown = display_date(date)?;
+        Some(format!(
+            "{label} <time datetime=\"{}\">{shown}</time>",
+            escape_attribute(date)
+        ))
+    })
+    .collect();
+    if !dates.is_empty() {
+        html.push_str(&format!("<p>{}</p>\n", dates.join(" · ")));
+    }
+
+    if !metadata.tags.is_empty() {
+        html.push_str("<ul class=\"tags\">\n");
+        for tag in &metadata.tags {
+            html.push_str(&format!(
+                "<li><a href=\"{}\">#{}</a></li>\n",
+                escape_attribute(&config.get_url(&format!("tag/{tag}"))),
+                escape_attribute(tag)
+            ));
+        }
+        html.push_str("</ul>\n");
+    }
+
+    html.push_str(&format!(
+        "</header>\n<div class=\"markdown-body\">\n{body_html}\n</div>\n</article>"
+    ));
+    html
+}
+
+/// Heading, optional description and linked list of articles
+pub fn listing_body(title: &str, description: Option<&str>, items: &[ListingItem]) -> String {
+    let mut html = format!("<h1>{}</h1>\n", escape_attribute(title));
+    if let Some(description) = description {
+        html.push_str(&format!("<p>{}</p>\n", escape_attribute(description)));
+    }
+
+    html.push_str("<ul>\n");
+    for item in items {
+        html.push_str(&format!(
+            "<li><a href=\"{}\">{}</a>",
+            escape_attribute(&item.href),
+            escape_attribute(item.title)
+        ));
+        if let Some(date) = item.created_at.and_then(display_date) {
+            html.push_str(&format!(" <time>{date}</time>"));
+        }
+        if let Some(summary) = item.summary {
+            html.push_str(&format!("<p>{}</p>", escape_attribute(summary)));
+        }
+        html.push_str("</li>\n");
+    }
+    html.push_str("</ul>");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::site::SiteConfig;
+
+    const INDEX_HTML: &str = concat!(
+        "<!doctype html>\n<html lang=\"ja\">\n<head>\n<title>Khimoo Portfolio</title>\n",
+        "<script type=\"module\">import init from '/portfolio-page/app.js';</script>\n",
+        "</head>\n<body class=\"app\">\n</body>\n</html>\n",
+    );
+
+    #[test]
+    fn test_render_article_page() {
+        let config = AppConfig {
+            base_path: "/portfolio-page".to_string(),
+            ..AppConfig::default()
+        };
+        let metadata = ArticleMetadata {
+            tags: vec!["math/topology".to_string()],
+            created_at: Some("2024-05-01T00:00:00+09:00".to_string()),
+            ..ArticleMetadata::default()
+        };
+        let head = PageHead::article(
+            &SiteConfig::default(),
+            "/article/compactness",
+            "Compactness & covers",
+            &metadata,
+            Some("Open covers."),
+            None,
+        );
+        let body = article_body(&config, "Compactness & covers", &metadata, "<p>Body</p>");
+        let preload = r#"{"content":"<!-- more --> <script></script><script>alert(1)"}"#;
+
+        let page = PageTemplate::new(INDEX_HTML).render(&head, &body, Some(preload));
+
+        assert!(page.contains("<title>Compactness &amp; covers | Khimoo Portfolio</title>"));
+        assert!(!page.contains("<title>Khimoo Portfolio</title>"));
+        assert!(page.contains("<meta data-page-head property=\"og:type\" content=\"article\">"));
+        assert!(page.contains(
+            "rel=\"canonical\" href=\"https://khimoo.github.io/portfolio-page/article/compactness\""
+        ));
+        assert!(page.contains(concat!(
+            "<link data-page-head rel=\"alternate\" type=\"application/atom+xml\" ",
+            "title=\"Khimoo Portfolio\" href=\"https://khimoo.github.io/portfolio-page/data/feed.xml\">"
+        )));
+        assert!(page.find("application/ld+json").unwrap() < page.find("</head>").unwrap());
+        assert!(page.contains("<body class=\"app\">\n<div id=\"prerendered\">\n<article>"));
+        assert!(page.contains("<h1>Compactness &amp; covers</h1>"));
+        assert!(page.contains("<time datetime=\"2024-05-01T00:00:00+09:00\">2024-05-01</time>"));
+        assert!(page.contains("href=\"/portfolio-page/tag/math/topology\""));
+        assert!(page.contains("<p>Body</p>"));
+        assert!(page.contains(
+            r#"{"content":"\u003c!-- more --\u003e \u003cscript\u003e\u003c/script\u003e"#
+        ));
+        // The whole JSON stays inside the preload script
+        let preloaded = page
+            .split_once(&format!("id=\"{PRELOADED_ARTICLE_ID}\">"))
+            .and_then(|(_, rest)| rest.split_once("</script>"))
+            .map(|(json, _)| json)
+            .unwrap();
+        assert!(!preloaded.contains('<'));
+        assert!(preloaded.ends_with(r#"\u003cscript\u003ealert(1)"}"#));
+        assert!(page.contains("import init from '/portfolio-page/app.js'"));
+        assert!(!PageTemplate::new(INDEX_HTML).is_rendered());
+        assert!(PageTemplate::new(page).is_rendered());
+    }
+
+    #[test]
+    fn test_listing_body() {
+        let items = [ListingItem {
+            href: "/article/a".to_string(),
+            title: "A <b>",
+            summary: Some("Summary"),
+            created_at: Some("2024-05-01T00:00:00+09:00"),
+        }];
+        let body = listing_body("記事一覧", None, &items);
+        assert!(body.contains("<li><a href=\"/article/a\">A &lt;b&gt;</a> <time>2024-05-01</time>"));
+        assert!(body.contains("<p>Summary</p>"));
+    }
+}
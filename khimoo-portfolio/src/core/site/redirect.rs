@@ -0,0 +1,81 @@
+use super::head::escape_script_json;
+use crate::core::render::links::escape_attribute;
+
+/// `sessionStorage` key holding the URL that GitHub Pages answered with `404.html`
+pub const REDIRECT_STORAGE_KEY: &str = "khimoo-portfolio:redirect";
+
+/// `404.html` that remembers the requested URL and opens the app at `base_path`
+///
+/// GitHub Pages serves this page for every path without a file; the app reads the stored
+/// URL on startup and restores it before routing.
+pub fn spa_redirect_page(base_path: &str, site_name: &str) -> String {
+    let root = format!("{}/", base_path.trim_end_matches('/'));
+    let root_literal = serde_json::to_string(&root)
+        .map(|literal| escape_script_json(&literal))
+        .unwrap_or_else(|_| "\"/\"".to_string());
+    let key_literal = serde_json::to_string(REDIRECT_STORAGE_KEY).unwrap_or_default();
+
+    format!(
+        r#"<!doctype html>
+<html lang="ja">
+<head>
+<meta charset="utf-8">
+<meta name="robots" content="noindex">
+<title>{site_name}</title>
+<script>
+try {{
+  sessionStorage.setItem({key_literal}, location.pathname + location.search + location.hash);
+}} catch (e) {{}}
+location.replace({root_literal});
+</script>
+</head>
+<body>
+<p><a href="{root_href}">{site_name}</a></p>
+</body>
+</html>
+"#,
+        site_name = escape_attribute(site_name),
+        root_href = escape_attribute(&root),
+    )
+}
+
+/// The stored URL if the app should restore it: a path under `base_path`, never the root itself
+pub fn redirect_target(stored: &str, base_path: &str) -> Option<String> {
+    let base_path = base_path.trim_end_matches('/');
+    let rest = stored.strip_prefix(base_path)?;
+    let is_inside = rest.starts_with('/') && !rest.starts_with("//") && rest != "/";
+    is_inside.then(|| stored.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spa_redirect_page() {
+        let page = spa_redirect_page("/portfolio-page/", "Khimoo Portfolio");
+        assert!(page.contains("sessionStorage.setItem(\"khimoo-portfolio:redirect\""));
+        assert!(page.contains("location.replace(\"/portfolio-page/\")"));
+        assert!(page.contains("<a href=\"/portfolio-page/\">Khimoo Portfolio</a>"));
+
+        let root = spa_redirect_page("", "Notes");
+        assert!(root.contains("location.replace(\"/\")"));
+    }
+
+    #[test]
+    fn test_redirect_target() {
+        let base = "/portfolio-page";
+        assert_eq!(
+            redirect_target("/portfolio-page/article/about-khimoo?tags=rust#top", base).as_deref(),
+            Some("/portfolio-page/article/about-khimoo?tags=rust#top")
+        );
+        assert_eq!(redirect_target("/portfolio-page/", base), None);
+        assert_eq!(redirect_target("/other/article/a", base), None);
+        assert_eq!(redirect_target("/portfolio-pageant/a", base), None);
+        assert_eq!(redirect_target("//evil.example/a", ""), None);
+        assert_eq!(
+            redirect_target("/article/a", "").as_deref(),
+            Some("/article/a")
+        );
+    }
+}
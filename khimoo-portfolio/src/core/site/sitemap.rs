@@ -0,0 +1,109 @@
+use chrono::DateTime;
+
+use super::config::SiteConfig;
+use crate::core::render::links::escape_attribute;
+
+/// Page listed in `sitemap.xml`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapUrl {
+    /// Site path (`/article/compactness`)
+    pub path: String,
+    /// Last modification (RFC 3339), if known
+    pub lastmod: Option<String>,
+}
+
+impl SitemapUrl {
+    pub fn new(path: impl Into<String>, lastmod: Option<&str>) -> Self {
+        Self {
+            path: path.into(),
+            lastmod: lastmod.map(str::to_string),
+        }
+    }
+}
+
+/// Latest of several RFC 3339 dates, for pages that list articles
+pub fn latest_date<'a>(dates: impl IntoIterator<Item = Option<&'a str>>) -> Option<&'a str> {
+    dates
+        .into_iter()
+        .flatten()
+        .filter_map(|date| {
+            DateTime::parse_from_rfc3339(date)
+                .ok()
+                .map(|parsed| (parsed, date))
+        })
+        .max_by_key(|(parsed, _)| *parsed)
+        .map(|(_, date)| date)
+}
+
+/// `sitemap.xml` with absolute URLs; it must be served from the site root
+pub fn sitemap_xml(site: &SiteConfig, urls: &[SitemapUrl]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for url in urls {
+        xml.push_str("  <url>\n");
+        xml.push_str(&format!(
+            "    <loc>{}</loc>\n",
+            escape_attribute(&site.absolute_url(&url.path))
+        ));
+        if let Some(lastmod) = &url.lastmod {
+            xml.push_str(&format!(
+                "    <lastmod>{}</lastmod>\n",
+                escape_attribute(lastmod)
+            ));
+        }
+        xml.push_str("  </url>\n");
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// `robots.txt` pointing at the sitemap and keeping crawlers out of the admin page
+///
+/// Crawlers only read it at the origin root, so on a GitHub Pages project site it takes
+/// effect once the site moves to its own domain; the sitemap can still be submitted directly.
+pub fn robots_txt(site: &SiteConfig, base_path: &str) -> String {
+    format!(
+        "User-agent: *\nDisallow: {}/admin\n\nSitemap: {}\n",
+        base_path.trim_end_matches('/'),
+        site.absolute_url("/sitemap.xml")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sitemap_and_robots() {
+        let site = SiteConfig {
+            url: "https://example.com/blog".to_string(),
+            ..SiteConfig::default()
+        };
+        let urls = [
+            SitemapUrl::new("/", None),
+            SitemapUrl::new("/article/位相", Some("2024-05-01T00:00:00+09:00")),
+        ];
+
+        let xml = sitemap_xml(&site, &urls);
+        assert!(xml.contains("<loc>https://example.com/blog/</loc>\n  </url>"));
+        assert!(xml.contains("<loc>https://example.com/blog/article/%E4%BD%8D%E7%9B%B8</loc>"));
+        assert!(xml.contains("<lastmod>2024-05-01T00:00:00+09:00</lastmod>"));
+
+        assert_eq!(
+            robots_txt(&site, "/blog/"),
+            "User-agent: *\nDisallow: /blog/admin\n\nSitemap: https://example.com/blog/sitemap.xml\n"
+        );
+    }
+
+    #[test]
+    fn test_latest_date() {
+        let dates = [
+            Some("2024-05-01T00:00:00+09:00"),
+            None,
+            Some("2024-04-30T20:00:00+00:00"),
+            Some("not a date"),
+        ];
+        assert_eq!(latest_date(dates), Some("2024-04-30T20:00:00+00:00"));
+        assert_eq!(latest_date([None]), None);
+    }
+}
@@ -7,9 +7,13 @@ use yew_router::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
 use khimoo_portfolio::web::app::App;
+#[cfg(target_arch = "wasm32")]
+use khimoo_portfolio::web::routes::restore_redirected_route;
 
 #[cfg(target_arch = "wasm32")]
 use khimoo_portfolio::config::get_config;
+#[cfg(target_arch = "wasm32")]
+use khimoo_portfolio::core::site::PRERENDERED_ELEMENT_ID;
 
 #[cfg(target_arch = "wasm32")]
 #[function_component(Root)]
@@ -30,6 +34,14 @@ fn root() -> Html {
 
 #[cfg(target_arch = "wasm32")]
 fn main() {
+    // 静的プリレンダリングされた内容はアプリの描画で置き換える
+    if let Some(prerendered) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(PRERENDERED_ELEMENT_ID))
+    {
+        prerendered.remove();
+    }
+    restore_redirected_route();
     yew::Renderer::<Root>::new().render();
 }
 
@@ -1,6 +1,8 @@
 use crate::config::get_config;
 use crate::web::header::Header;
-use crate::web::pages::{ArticleIndexPage, ArticleViewPage, HomePage};
+use crate::web::pages::{
+    ArticleHistoryPage, ArticleIndexPage, ArticleViewPage, HomePage, SearchPage, TagViewPage,
+};
 use crate::web::routes::Route;
 use crate::web::styles::LayoutStyles;
 use yew::prelude::*;
@@ -33,5 +35,8 @@ fn switch(routes: Route) -> Html {
         Route::Admin => html! { <div>{"Admin page - Not implemented yet"}</div> },
         Route::ArticleIndex => html! { <ArticleIndexPage /> },
         Route::ArticleShow { slug } => html! { <ArticleViewPage slug={slug} /> },
+        Route::ArticleHistory { slug } => html! { <ArticleHistoryPage slug={slug} /> },
+        Route::TagShow { name } => html! { <TagViewPage name={name} /> },
+        Route::Search => html! { <SearchPage /> },
     }
 }
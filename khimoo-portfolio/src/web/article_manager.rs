@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use yew::prelude::*;
 
+use crate::core::articles::tags::tag_matches;
 use crate::web::data_loader::{
     ArticlesData, DataLoadError, DataLoader, LightweightArticle, ProcessedArticle,
 };
@@ -101,7 +102,7 @@ impl ArticleManager {
 
         // Load lightweight articles and build link graph
         for article in lightweight_articles {
-            if article.metadata.home_display {
+            if article.metadata.home_display && article.metadata.is_listed() {
                 self.home_articles.push(article.slug.clone());
             }
 
@@ -298,19 +299,19 @@ impl ArticleManager {
         }
     }
 
-    /// Get lightweight articles by tag
+    /// Get lightweight articles by tag (nested tags included)
     pub fn get_lightweight_articles_by_tag(&self, tag: &str) -> Vec<&LightweightArticle> {
         self.lightweight_articles
             .values()
-            .filter(|article| article.metadata.tags.contains(&tag.to_string()))
+            .filter(|article| article.metadata.tags.iter().any(|t| tag_matches(t, tag)))
             .collect()
     }
 
-    /// Get articles by tag (full articles only)
+    /// Get articles by tag, nested tags included (full articles only)
     pub fn get_articles_by_tag(&self, tag: &str) -> Vec<&ProcessedArticle> {
         self.articles
             .values()
-            .filter(|article| article.metadata.tags.contains(&tag.to_string()))
+            .filter(|article| article.metadata.tags.iter().any(|t| tag_matches(t, tag)))
             .collect()
     }
 
@@ -336,15 +337,6 @@ impl ArticleManager {
             .collect()
     }
 
-    /// Search articles by title (full articles only)
-    pub fn search_articles(&self, query: &str) -> Vec<&ProcessedArticle> {
-        let query_lower = query.to_lowercase();
-        self.articles
-            .values()
-            .filter(|article| article.title.to_lowercase().contains(&query_lower))
-            .collect()
-    }
-
     /// Get statistics
     pub fn get_stats(&self) -> ArticleStats {
         let total_articles = self.lightweight_articles.len();
@@ -1,6 +1,12 @@
+use crate::config::get_config;
+use crate::core::articles::links::link_base;
+use crate::core::render::{MarkdownRenderer, RenderedArticle};
+use crate::core::search::RelatedArticle;
+use crate::web::components::TableOfContents;
+use crate::web::config::StyleConfig;
 use crate::web::data_loader::ProcessedArticle;
-use crate::web::routes::Route;
-use pulldown_cmark::{html, Parser};
+use crate::web::routes::{apply_fragment, scroll_to_fragment, Route};
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
 use yew::virtual_dom::AttrValue;
 use yew_router::prelude::*;
@@ -13,31 +19,109 @@ pub struct ArticleContentProps {
 
 #[function_component(ArticleContent)]
 pub fn article_content(props: &ArticleContentProps) -> Html {
-    // Markdownを処理してHTMLに変換（WikiLink処理なし）
-    let processed_html = process_markdown_content(&props.content);
-    let rendered = Html::from_html_unchecked(AttrValue::from(processed_html));
+    // Markdownを処理してHTMLに変換（本文が変わったときだけ再レンダリング）
+    let rendered_article = use_memo(
+        (props.content.clone(), props.article.clone()),
+        |(content, article)| process_markdown_content(content, article),
+    );
+    let rendered = Html::from_html_unchecked(AttrValue::from(rendered_article.html.clone()));
+    let navigator = use_navigator();
+
+    // 記事内リンクのクリックをルーター遷移に置き換える（フルリロード防止）
+    let on_content_click = Callback::from(move |e: MouseEvent| {
+        if e.button() != 0 || e.ctrl_key() || e.meta_key() || e.shift_key() || e.alt_key() {
+            return;
+        }
+
+        let Some(anchor) = e
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            .and_then(|element| element.closest("a[data-internal]").ok().flatten())
+        else {
+            return;
+        };
+
+        let target = anchor
+            .get_attribute("href")
+            .and_then(|href| Route::from_app_href(&href));
+
+        if let (Some((route, fragment)), Some(navigator)) = (target, navigator.as_ref()) {
+            e.prevent_default();
+            navigator.push(&route);
+            // 別の記事なら本文の描画後に下のエフェクトがスクロールする
+            if let Some(fragment) = fragment {
+                apply_fragment(&fragment);
+            }
+        }
+    });
+
+    // URLの #fragment が指す見出しまでスクロールする（直接開いたときと記事間の遷移後）
+    use_effect_with(rendered_article.html.clone(), |_| {
+        if let Some(hash) = web_sys::window().and_then(|window| window.location().hash().ok()) {
+            if !hash.is_empty() {
+                scroll_to_fragment(&hash);
+            }
+        }
+    });
 
     html! {
         <>
             <style>{content_styles()}</style>
-            <div class="markdown-body">
-                {rendered}
+            <style>{body_layout_styles()}</style>
+            <div class="article-body-layout">
+                <TableOfContents entries={rendered_article.toc.clone()} />
+                <div class="article-body-main">
+                    <div class="markdown-body" onclick={on_content_click}>
+                        {rendered}
+                    </div>
+                    {render_related_articles(&props.article.related)}
+                    {render_outbound_links(&props.article)}
+                </div>
             </div>
-            {render_related_articles(&props.article)}
         </>
     }
 }
 
-/// Markdownコンテンツを処理してHTMLに変換（シンプル版）
-fn process_markdown_content(content: &str) -> String {
-    // Markdown を HTML に変換
-    let parser = Parser::new(content);
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
-    html_output
+/// Markdownコンテンツを処理してHTMLに変換（リンク書き換え・見出しアンカー・引用・サニタイズ込み）
+/// 相対リンクは記事のディレクトリから解決する
+fn process_markdown_content(content: &str, article: &ProcessedArticle) -> RenderedArticle {
+    MarkdownRenderer::new(get_config())
+        .with_references(&article.references)
+        .with_link_targets(link_base(&article.file_path), &article.link_targets)
+        .render_article(content)
+}
+
+/// 関連記事（フロントマターの related_articles と、内容・タグが近い記事）
+fn render_related_articles(related: &[RelatedArticle]) -> Html {
+    if related.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <section class="related-articles">
+            <h3>{"関連記事"}</h3>
+            <ul>
+                {related.iter().map(|article| html! {
+                    <li key={article.slug.clone()}>
+                        <Link<Route> to={Route::ArticleShow { slug: article.slug.clone() }}>
+                            {&article.title}
+                        </Link<Route>>
+                        if let Some(score) = article.score {
+                            <span class="related-score" title="内容とタグの類似度">
+                                {format!("{:.0}%", score * 100.0)}
+                            </span>
+                        }
+                        if let Some(summary) = &article.summary {
+                            <p>{summary}</p>
+                        }
+                    </li>
+                }).collect::<Html>()}
+            </ul>
+        </section>
+    }
 }
 
-fn render_related_articles(article: &ProcessedArticle) -> Html {
+fn render_outbound_links(article: &ProcessedArticle) -> Html {
     if !article.outbound_links.is_empty() {
         html! {
             <footer style="margin-top: 48px; padding-top: 24px; border-top: 1px solid #444;">
@@ -112,6 +196,162 @@ fn content_styles() -> String {
         color: #99ccff;
         text-decoration: underline;
     }
+    .markdown-body :is(h1, h2, h3, h4, h5, h6) {
+        scroll-margin-top: 24px;
+    }
+    .markdown-body a.heading-anchor {
+        margin-left: 8px;
+        color: #66b3ff;
+        opacity: 0;
+        transition: opacity 0.2s;
+    }
+    .markdown-body :is(h1, h2, h3, h4, h5, h6):hover a.heading-anchor,
+    .markdown-body a.heading-anchor:focus {
+        opacity: 1;
+        text-decoration: none;
+    }
+    .markdown-body a.external-link::after {
+        content: "↗";
+        font-size: 0.75em;
+        margin-left: 2px;
+        vertical-align: super;
+    }
+    .markdown-body table {
+        display: block;
+        max-width: 100%;
+        overflow-x: auto;
+        border-collapse: collapse;
+        margin-bottom: 16px;
+        font-size: 90%;
+    }
+    .markdown-body th, .markdown-body td {
+        border: 1px solid #444;
+        padding: 6px 12px;
+    }
+    .markdown-body th {
+        background: #2d3748;
+        font-weight: 600;
+    }
+    .markdown-body tr:nth-child(even) td {
+        background: rgba(255, 255, 255, 0.03);
+    }
+    .markdown-body del {
+        color: #888;
+    }
+    .markdown-body li:has(> input[type="checkbox"]) {
+        list-style: none;
+        margin-left: -22px;
+    }
+    .markdown-body input[type="checkbox"] {
+        margin-right: 8px;
+        accent-color: #66b3ff;
+    }
+    .markdown-body sup.footnote-reference {
+        font-size: 0.7em;
+        line-height: 0;
+    }
+    .markdown-body sup.footnote-reference a::before {
+        content: "[";
+    }
+    .markdown-body sup.footnote-reference a::after {
+        content: "]";
+    }
+    .markdown-body .footnote-definition {
+        display: flex;
+        gap: 8px;
+        font-size: 16px;
+        color: #aaa;
+        scroll-margin-top: 24px;
+    }
+    .markdown-body .footnote-definition:first-of-type {
+        margin-top: 32px;
+        padding-top: 16px;
+        border-top: 1px solid #444;
+    }
+    .markdown-body .footnote-definition p {
+        margin: 0 0 8px 0;
+        color: #aaa;
+    }
+    .markdown-body .footnote-definition-label {
+        min-width: 1.5em;
+        line-height: 1.6;
+    }
+    .markdown-body a.footnote-backref {
+        margin-left: 4px;
+    }
+    .markdown-body .citation a {
+        text-decoration: none;
+    }
+    .markdown-body .references {
+        font-size: 16px;
+        color: #aaa;
+    }
+    .markdown-body .references li {
+        margin-bottom: 8px;
+        scroll-margin-top: 24px;
+        overflow-wrap: anywhere;
+    }
+    .markdown-body ul.references {
+        list-style: none;
+        padding-left: 0;
+    }
+    .related-articles {
+        margin-top: 48px;
+        padding-top: 24px;
+        border-top: 1px solid #444;
+    }
+    .related-articles h3 {
+        color: #e0e0e0;
+    }
+    .related-articles ul {
+        list-style: none;
+        padding: 0;
+    }
+    .related-articles li {
+        margin-bottom: 16px;
+    }
+    .related-articles a {
+        color: #66b3ff;
+        text-decoration: none;
+    }
+    .related-articles p {
+        margin: 4px 0 0 0;
+        color: #aaa;
+        font-size: 16px;
+        line-height: 1.5;
+    }
+    .related-score {
+        margin-left: 8px;
+        font-size: 12px;
+        color: #888;
+    }
+    .markdown-body .references li:target,
+    .markdown-body sup.footnote-reference:target,
+    .markdown-body .footnote-definition:target {
+        background: rgba(102, 179, 255, 0.15);
+    }
     "#
     .to_string()
 }
+
+/// 本文と目次サイドバーのレイアウト（広い画面では本文の右に目次を並べる）
+fn body_layout_styles() -> String {
+    format!(
+        r#"
+    @media (min-width: {breakpoint}px) {{
+        .article-body-layout {{
+            display: grid;
+            grid-template-columns: minmax(0, 1fr) 240px;
+            grid-template-areas: "main toc";
+            column-gap: 40px;
+            margin-right: -280px;
+        }}
+        .article-body-main {{
+            grid-area: main;
+            min-width: 0;
+        }}
+    }}
+    "#,
+        breakpoint = StyleConfig::DESKTOP_BREAKPOINT
+    )
+}
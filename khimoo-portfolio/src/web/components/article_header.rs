@@ -1,7 +1,11 @@
 use crate::config::get_config;
+use crate::core::articles::metadata::{display_date, ArticleMetadata};
+use crate::core::articles::stats::ArticleStats;
+use crate::core::articles::tag_registry::TagRegistry;
+use crate::core::media::cover::CoverImage;
 use crate::web::components::{TagPill, TagStyles};
-use crate::web::data_loader::ProcessedArticle;
-use crate::web::routes::{Route, TagQuery};
+use crate::web::data_loader::{use_articles_data, ProcessedArticle};
+use crate::web::routes::Route;
 use web_sys::window;
 use yew::prelude::*;
 use yew_router::prelude::*;
@@ -15,40 +19,45 @@ pub struct ArticleHeaderProps {
 pub fn article_header(props: &ArticleHeaderProps) -> Html {
     let article = &props.article;
     let navigator = use_navigator();
+    let (articles_data, _, _) = use_articles_data();
+    // タグはそれぞれのタグページへ
     let on_tag_click = {
         let navigator = navigator.clone();
         Callback::from(move |tag: String| {
-            let query = TagQuery { tags: Some(tag.clone()) };
             if let Some(navigator) = navigator.as_ref() {
-                if navigator
-                    .push_with_query(&Route::ArticleIndex, &query)
-                    .is_ok()
-                {
-                    return;
-                }
+                navigator.push(&Route::TagShow { name: tag });
+                return;
             }
 
-            let fallback_url = format!("{}?tags={}", get_config().get_url("article"), tag);
+            let fallback_url = get_config().get_url(&format!("tag/{tag}"));
             if let Some(window) = window() {
                 let _ = window.location().set_href(&fallback_url);
             }
         })
     };
+    let tag_registry = articles_data
+        .as_ref()
+        .map(|data| data.tags.clone())
+        .unwrap_or_default();
 
     html! {
         <>
             <TagStyles />
+            {render_cover(&article.cover, &article.title)}
             <header style="margin-bottom: 32px; padding-bottom: 16px; border-bottom: 1px solid #444; display: flex; justify-content: space-between; align-items: flex-start; gap: 20px;">
                 <div style="flex: 1;">
                     <h1 style="margin: 0 0 16px 0; font-size: 2.5em; color: #e0e0e0;">
                         {&article.title}
                     </h1>
                     <div style="font-size: 14px; color: #aaa; display: flex; gap: 16px; flex-wrap: wrap;">
+                        {render_dates(&article.metadata)}
+                        {render_reading_time(&article.stats)}
                         {render_importance(Some(article.metadata.importance))}
                         {render_inbound_links_count(article.inbound_links.len())}
+                        {render_history_link(&article.slug, article.revision_count)}
                     </div>
                     <div style="font-size: 14px; color: #aaa; display: flex; gap: 16px; flex-wrap: wrap;">
-                        {render_tags(&article.metadata.tags, &on_tag_click)}
+                        {render_tags(&article.metadata.tags, &tag_registry, &on_tag_click)}
                     </div>
                 </div>
                 {render_author_image(&article.metadata.author_image)}
@@ -57,6 +66,66 @@ pub fn article_header(props: &ArticleHeaderProps) -> Html {
     }
 }
 
+/// 公開日と更新日（同じ日なら更新日は省略）
+fn render_dates(metadata: &ArticleMetadata) -> Html {
+    let created = metadata.created_at.as_deref().and_then(|value| {
+        display_date(value).map(|date| (value.to_string(), date))
+    });
+    let updated = metadata.updated_at.as_deref().and_then(|value| {
+        display_date(value).map(|date| (value.to_string(), date))
+    });
+    let updated = updated.filter(|(_, updated_date)| {
+        created.as_ref().map(|(_, created_date)| created_date) != Some(updated_date)
+    });
+
+    html! {
+        <>
+            {created.map(|(datetime, date)| html! {
+                <span>{"Published: "}<time datetime={datetime}><strong>{date}</strong></time></span>
+            }).unwrap_or_default()}
+            {updated.map(|(datetime, date)| html! {
+                <span>{"Updated: "}<time datetime={datetime}><strong>{date}</strong></time></span>
+            }).unwrap_or_default()}
+        </>
+    }
+}
+
+/// 読了目安と分量（語数・文字数、コード・数式・画像の数はツールチップに）
+fn render_reading_time(stats: &ArticleStats) -> Html {
+    if stats.reading_minutes == 0 {
+        return html! {};
+    }
+
+    html! {
+        <span title={stats_breakdown(stats)}>
+            {"Reading time: "}<strong>{stats.reading_minutes}{" min"}</strong>
+            {" ("}{format_length(stats)}{")"}
+        </span>
+    }
+}
+
+/// 英語は語数、日本語は文字数で数える（両方あれば併記）
+pub fn format_length(stats: &ArticleStats) -> String {
+    match (stats.words, stats.cjk_characters) {
+        (words, 0) => format!("{words} words"),
+        (0, characters) => format!("{characters} 文字"),
+        (words, characters) => format!("{characters} 文字 + {words} words"),
+    }
+}
+
+fn stats_breakdown(stats: &ArticleStats) -> String {
+    [
+        (stats.code_blocks, "code blocks"),
+        (stats.math_blocks, "equations"),
+        (stats.images, "images"),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, label)| format!("{count} {label}"))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
 fn render_importance(importance: Option<u8>) -> Html {
     if let Some(importance) = importance {
         html! {
@@ -73,14 +142,34 @@ fn render_inbound_links_count(count: usize) -> Html {
     }
 }
 
-fn render_tags(tags: &[String], on_tag_click: &Callback<String>) -> Html {
+/// 変更履歴ページへのリンク（履歴が出力されている記事のみ）
+fn render_history_link(slug: &str, revision_count: usize) -> Html {
+    if revision_count == 0 {
+        return html! {};
+    }
+
+    html! {
+        <span>
+            <Link<Route> to={Route::ArticleHistory { slug: slug.to_string() }}>
+                {"History ("}{revision_count}{")"}
+            </Link<Route>>
+        </span>
+    }
+}
+
+fn render_tags(tags: &[String], registry: &TagRegistry, on_tag_click: &Callback<String>) -> Html {
     if !tags.is_empty() {
         html! {
             <span class="tag-list">
                 <span class="tag-list-label">{"Tags: "}</span>
                 {tags.iter().map(|tag| {
                     html! {
-                        <TagPill label={tag.clone()} on_click={Some(on_tag_click.clone())} />
+                        <TagPill
+                            label={registry.display_name(tag).to_string()}
+                            value={Some(tag.clone())}
+                            color={registry.get(tag).and_then(|info| info.color.clone())}
+                            on_click={Some(on_tag_click.clone())}
+                        />
                     }
                 }).collect::<Html>()}
             </span>
@@ -90,6 +179,23 @@ fn render_tags(tags: &[String], on_tag_click: &Callback<String>) -> Html {
     }
 }
 
+/// カバー画像（hero サイズ）をタイトルの上に表示
+fn render_cover(cover: &Option<CoverImage>, title: &str) -> Html {
+    let Some(cover) = cover else {
+        return html! {};
+    };
+
+    html! {
+        <img
+            src={get_config().get_url(&cover.hero)}
+            alt={title.to_string()}
+            width="1200"
+            height="630"
+            style="display: block; width: 100%; height: auto; aspect-ratio: 1200 / 630; object-fit: cover; border-radius: 8px; margin-bottom: 24px;"
+        />
+    }
+}
+
 fn render_author_image(author_image: &Option<String>) -> Html {
     if let Some(author_image) = author_image {
         let resolved_image_path = get_config().get_url(author_image);
@@ -11,6 +11,8 @@ pub mod node_data_manager;
 pub mod node_graph_container;
 pub mod node_renderer;
 pub mod physics_renderer;
+pub mod section_nav;
+pub mod table_of_contents;
 pub mod tag;
 
 // Re-export commonly used components
@@ -22,4 +24,6 @@ pub use node_data_manager::*;
 pub use node_graph_container::*;
 pub use node_renderer::*;
 pub use physics_renderer::*;
+pub use section_nav::*;
+pub use table_of_contents::*;
 pub use tag::*;
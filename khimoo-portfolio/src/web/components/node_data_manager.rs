@@ -104,11 +104,11 @@ impl NodeDataManager {
             );
         }
 
-        // home_display=trueの記事のみをノードとして追加
+        // home_display=trueの記事のみをノードとして追加（限定公開の記事は除く）
         let home_articles: Vec<_> = articles_data
             .articles
             .iter()
-            .filter(|article| article.metadata.home_display)
+            .filter(|article| article.metadata.home_display && article.metadata.is_listed())
             .collect();
 
         #[cfg(target_arch = "wasm32")]
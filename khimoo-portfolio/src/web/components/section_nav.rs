@@ -0,0 +1,171 @@
+use crate::core::articles::sections::{ancestor_paths, is_section_index, section_path_of, Section};
+use crate::web::data_loader::{use_articles_data, ArticlesData};
+use crate::web::routes::Route;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct BreadcrumbsProps {
+    /// セクション自身のページではそのディレクトリ、記事ではファイルのパス
+    pub path: String,
+    /// 記事ページなら記事タイトルを末尾に表示
+    #[prop_or_default]
+    pub current_title: Option<String>,
+}
+
+/// セクション階層のパンくずリスト（トップレベルの記事では何も表示しない）
+#[function_component(Breadcrumbs)]
+pub fn breadcrumbs(props: &BreadcrumbsProps) -> Html {
+    let (articles_data, _, _) = use_articles_data();
+
+    let Some(data) = articles_data.as_ref() else {
+        return html! {};
+    };
+
+    // 記事ならそのファイルのディレクトリ、セクションページならその親までをたどる
+    // （index.md はセクション自身のページなので、そのディレクトリは含めない）
+    let section_path = if props.current_title.is_some() {
+        section_path_of(&props.path)
+    } else {
+        Some(props.path.as_str())
+    };
+    let is_section_page = props.current_title.is_none() || is_section_index(&props.path);
+    let trail: Vec<&Section> = section_path
+        .map(ancestor_paths)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|path| !(is_section_page && Some(*path) == section_path))
+        .filter_map(|path| find_section(data, path))
+        .collect();
+    let current = props
+        .current_title
+        .clone()
+        .or_else(|| find_section(data, &props.path).map(|section| section.title.clone()));
+
+    if section_path.is_none() {
+        return html! {};
+    }
+
+    html! {
+        <nav class="breadcrumbs" aria-label="パンくずリスト">
+            <style>{breadcrumb_styles()}</style>
+            <Link<Route> to={Route::ArticleIndex}>{"Articles"}</Link<Route>>
+            {trail.into_iter().map(|section| html! {
+                <>
+                    <span class="breadcrumb-separator">{"/"}</span>
+                    <Link<Route> to={Route::ArticleShow { slug: section.slug().to_string() }}>
+                        {&section.title}
+                    </Link<Route>>
+                </>
+            }).collect::<Html>()}
+            {current.map(|title| html! {
+                <>
+                    <span class="breadcrumb-separator">{"/"}</span>
+                    <span aria-current="page">{title}</span>
+                </>
+            }).unwrap_or_default()}
+        </nav>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SectionIndexProps {
+    /// セクションのディレクトリパス
+    pub path: String,
+}
+
+/// セクション直下のサブセクションと記事の一覧
+#[function_component(SectionIndex)]
+pub fn section_index(props: &SectionIndexProps) -> Html {
+    let (articles_data, _, _) = use_articles_data();
+
+    let Some(data) = articles_data.as_ref() else {
+        return html! {};
+    };
+    let Some(section) = find_section(data, &props.path) else {
+        return html! {};
+    };
+
+    let subsections: Vec<&Section> = section
+        .sections
+        .iter()
+        .filter_map(|path| find_section(data, path))
+        .collect();
+    // 限定公開の記事は一覧に出さない
+    let articles: Vec<_> = section
+        .articles
+        .iter()
+        .filter_map(|slug| data.articles.iter().find(|article| &article.slug == slug))
+        .filter(|article| article.metadata.is_listed())
+        .collect();
+
+    if subsections.is_empty() && articles.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <section class="section-index">
+            <style>{breadcrumb_styles()}</style>
+            <h2>{"このセクションの記事"}</h2>
+            <ul>
+                {subsections.into_iter().map(|subsection| html! {
+                    <li key={subsection.path.clone()} class="section-index-folder">
+                        <Link<Route> to={Route::ArticleShow { slug: subsection.slug().to_string() }}>
+                            {&subsection.title}
+                        </Link<Route>>
+                    </li>
+                }).collect::<Html>()}
+                {articles.into_iter().map(|article| html! {
+                    <li key={article.slug.clone()}>
+                        <Link<Route> to={Route::ArticleShow { slug: article.slug.clone() }}>
+                            {&article.title}
+                        </Link<Route>>
+                    </li>
+                }).collect::<Html>()}
+            </ul>
+        </section>
+    }
+}
+
+fn find_section<'a>(data: &'a ArticlesData, path: &str) -> Option<&'a Section> {
+    data.sections.iter().find(|section| section.path == path)
+}
+
+fn breadcrumb_styles() -> &'static str {
+    r#"
+    .breadcrumbs {
+        display: flex;
+        flex-wrap: wrap;
+        gap: 6px;
+        margin-bottom: 16px;
+        font-size: 14px;
+        color: #aaa;
+    }
+    .breadcrumbs a, .section-index a {
+        color: #66b3ff;
+        text-decoration: none;
+    }
+    .breadcrumbs a:hover, .section-index a:hover {
+        text-decoration: underline;
+    }
+    .section-index {
+        margin-top: 48px;
+        padding-top: 24px;
+        border-top: 1px solid #444;
+    }
+    .section-index h2 {
+        color: #e0e0e0;
+        font-size: 1.2em;
+    }
+    .section-index ul {
+        list-style: none;
+        padding: 0;
+    }
+    .section-index li {
+        margin-bottom: 8px;
+    }
+    .section-index-folder::before {
+        content: "📁 ";
+    }
+    "#
+}
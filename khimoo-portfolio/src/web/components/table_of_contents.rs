@@ -0,0 +1,166 @@
+use crate::core::render::TocEntry;
+use crate::web::config::StyleConfig;
+use yew::prelude::*;
+use yew_hooks::use_window_scroll;
+
+/// 見出しがこの位置（px）より上に来たら現在のセクションとみなす
+const ACTIVE_HEADING_OFFSET: f64 = 96.0;
+
+#[derive(Properties, PartialEq)]
+pub struct TableOfContentsProps {
+    pub entries: Vec<TocEntry>,
+}
+
+/// 目次: 広い画面ではスティッキーなサイドバー、モバイルでは折りたたみブロック
+#[function_component(TableOfContents)]
+pub fn table_of_contents(props: &TableOfContentsProps) -> Html {
+    let (_, scroll_y) = use_window_scroll();
+    let active_id = use_state(|| None::<String>);
+
+    // スクロール位置が変わるたびに表示中のセクションを再計算
+    {
+        let active_id = active_id.clone();
+        let entries = props.entries.clone();
+        use_effect_with(scroll_y, move |_| {
+            let next = find_active_heading(&entries);
+            if *active_id != next {
+                active_id.set(next);
+            }
+            || {}
+        });
+    }
+
+    if props.entries.is_empty() {
+        return html! {};
+    }
+
+    let list = render_toc_list(&props.entries, active_id.as_deref());
+
+    html! {
+        <>
+            <style>{toc_styles()}</style>
+            <details class="toc-mobile">
+                <summary>{"目次"}</summary>
+                {list.clone()}
+            </details>
+            <nav class="toc-sidebar" aria-label="目次">
+                <p class="toc-title">{"目次"}</p>
+                {list}
+            </nav>
+        </>
+    }
+}
+
+/// 画面上部を通過した最後の見出しを現在のセクションとする
+fn find_active_heading(entries: &[TocEntry]) -> Option<String> {
+    let document = web_sys::window()?.document()?;
+    let mut active = None;
+
+    for entry in entries {
+        if let Some(element) = document.get_element_by_id(&entry.id) {
+            if element.get_bounding_client_rect().top() <= ACTIVE_HEADING_OFFSET {
+                active = Some(entry.id.clone());
+            } else {
+                break;
+            }
+        }
+    }
+
+    active.or_else(|| entries.first().map(|entry| entry.id.clone()))
+}
+
+fn render_toc_list(entries: &[TocEntry], active_id: Option<&str>) -> Html {
+    html! {
+        <ul class="toc-list">
+            {entries.iter().map(|entry| {
+                let class_name = if active_id == Some(entry.id.as_str()) {
+                    format!("toc-item toc-level-{} active", entry.level)
+                } else {
+                    format!("toc-item toc-level-{}", entry.level)
+                };
+                html! {
+                    <li key={entry.id.clone()} class={class_name}>
+                        <a href={format!("#{}", entry.id)}>{&entry.text}</a>
+                    </li>
+                }
+            }).collect::<Html>()}
+        </ul>
+    }
+}
+
+fn toc_styles() -> String {
+    format!(
+        r#"
+    .toc-sidebar {{
+        display: none;
+    }}
+    .toc-mobile {{
+        margin: 0 0 24px 0;
+        padding: 12px 16px;
+        border: 1px solid #444;
+        border-radius: 8px;
+        color: #e0e0e0;
+    }}
+    .toc-mobile summary {{
+        cursor: pointer;
+        font-weight: 600;
+    }}
+    .toc-list {{
+        list-style: none;
+        margin: 8px 0 0 0;
+        padding: 0;
+        font-size: 14px;
+        line-height: 1.5;
+    }}
+    .toc-item {{
+        margin: 4px 0;
+        border-left: 2px solid transparent;
+    }}
+    .toc-item a {{
+        display: block;
+        padding-left: 8px;
+        color: #aaa;
+        text-decoration: none;
+    }}
+    .toc-item a:hover {{
+        color: #99ccff;
+    }}
+    .toc-item.active {{
+        border-left-color: #66b3ff;
+    }}
+    .toc-item.active a {{
+        color: #66b3ff;
+    }}
+    .toc-level-3 a {{
+        padding-left: 20px;
+    }}
+    .toc-level-4 a {{
+        padding-left: 32px;
+    }}
+    .toc-title {{
+        margin: 0;
+        font-size: 12px;
+        font-weight: 600;
+        color: #aaa;
+        text-transform: uppercase;
+        letter-spacing: 0.05em;
+    }}
+
+    @media (min-width: {breakpoint}px) {{
+        .toc-mobile {{
+            display: none;
+        }}
+        .toc-sidebar {{
+            display: block;
+            grid-area: toc;
+            position: sticky;
+            top: 24px;
+            align-self: start;
+            max-height: calc(100vh - 48px);
+            overflow-y: auto;
+        }}
+    }}
+    "#,
+        breakpoint = StyleConfig::DESKTOP_BREAKPOINT
+    )
+}
@@ -7,6 +7,12 @@ pub struct TagPillProps {
     pub selected: bool,
     #[prop_or_default]
     pub on_click: Option<Callback<String>>,
+    /// クリック時に渡す値（省略時は label）。階層タグで表示名とタグ全体を分けるのに使う
+    #[prop_or_default]
+    pub value: Option<String>,
+    /// tags.toml で指定された色（枠線に使う）
+    #[prop_or_default]
+    pub color: Option<String>,
 }
 
 #[function_component(TagPill)]
@@ -16,20 +22,24 @@ pub fn tag_pill(props: &TagPillProps) -> Html {
     } else {
         "tag-option"
     };
+    let style = props
+        .color
+        .as_ref()
+        .map(|color| format!("border-color: {color};"));
 
     if let Some(on_click) = props.on_click.as_ref() {
-        let tag_label = props.label.clone();
+        let tag_label = props.value.clone().unwrap_or_else(|| props.label.clone());
         let on_click = on_click.clone();
         let onclick = Callback::from(move |_| on_click.emit(tag_label.clone()));
 
         html! {
-            <button type="button" class={class_name} {onclick}>
+            <button type="button" class={class_name} {style} {onclick}>
                 {&props.label}
             </button>
         }
     } else {
         html! {
-            <span class={class_name}>
+            <span class={class_name} {style}>
                 {&props.label}
             </span>
         }
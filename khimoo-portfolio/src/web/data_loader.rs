@@ -1,13 +1,22 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{Request, RequestInit, RequestMode, Response};
 use yew::prelude::*;
 
 use crate::config::{get_config, AppConfig};
+use crate::core::articles::bibliography::Reference;
+use crate::core::articles::front_matter::strip_front_matter;
 use crate::core::articles::links::ExtractedLink;
 use crate::core::articles::metadata::ArticleMetadata;
+use crate::core::articles::revisions::ArticleRevisions;
+use crate::core::articles::sections::Section;
+use crate::core::articles::stats::ArticleStats;
+use crate::core::articles::tag_registry::TagRegistry;
+use crate::core::media::cover::CoverImage;
+use crate::core::search::{RelatedArticle, SearchIndex};
+use crate::core::site::{PreloadedArticle, PRELOADED_ARTICLE_ID};
 use crate::web::types::data_types::NodeRegistry;
 use crate::web::types::node_types::{ConnectionLineType, NodeContent, NodeId, AUTHOR_NODE_ID};
 use crate::web::types::physics_types::Position;
@@ -19,6 +28,10 @@ pub struct ArticlesData {
     pub generated_at: String,
     pub total_count: usize,
     pub home_articles: Vec<String>,
+    #[serde(default)]
+    pub sections: Vec<Section>,
+    #[serde(default)]
+    pub tags: TagRegistry,
 }
 
 /// Processed article data structure matching CLI output
@@ -29,9 +42,23 @@ pub struct ProcessedArticle {
     pub metadata: ArticleMetadata,
     pub file_path: String,
     pub summary: Option<String>,
+    #[serde(default)]
+    pub stats: ArticleStats,
     pub outbound_links: Vec<ExtractedLink>,
     pub inbound_links: Vec<ExtractedLink>,
+    #[serde(default)]
+    pub link_targets: BTreeMap<String, String>,
     pub processed_at: String,
+    #[serde(default)]
+    pub references: Vec<Reference>,
+    #[serde(default)]
+    pub revision_count: usize,
+    #[serde(default)]
+    pub cover: Option<CoverImage>,
+    #[serde(default)]
+    pub og_image: Option<String>,
+    #[serde(default)]
+    pub related: Vec<RelatedArticle>,
 }
 
 /// Lightweight article data for list display and node graph construction
@@ -42,6 +69,8 @@ pub struct LightweightArticle {
     pub metadata: ArticleMetadata,
     pub inbound_links: Vec<String>, // Pre-calculated for performance optimization
     pub summary: Option<String>,    // Article summary for list display
+    pub stats: ArticleStats,        // Length and reading time for cards and sorting
+    pub cover: Option<CoverImage>,  // Card image for list display
 }
 
 impl From<ProcessedArticle> for LightweightArticle {
@@ -56,6 +85,8 @@ impl From<ProcessedArticle> for LightweightArticle {
                 .map(|link| link.target_slug)
                 .collect(),
             summary: article.summary,
+            stats: article.stats,
+            cover: article.cover,
         }
     }
 }
@@ -123,6 +154,8 @@ impl DataLoader {
                     generated_at: "1970-01-01T00:00:00Z".to_string(),
                     total_count: 0,
                     home_articles: Vec::new(),
+                    sections: Vec::new(),
+                    tags: TagRegistry::default(),
                 })
             }
         }
@@ -144,8 +177,12 @@ impl DataLoader {
         let mut node_id_counter = 1u32; // Start after author node (0)
         let mut slug_to_node_id: HashMap<String, NodeId> = HashMap::new();
 
-        // First pass: create nodes for all articles
-        for article in &articles_data.articles {
+        // First pass: create nodes for all listed articles (unlisted ones stay off the graph)
+        for article in articles_data
+            .articles
+            .iter()
+            .filter(|article| article.metadata.is_listed())
+        {
             let node_id = NodeId(node_id_counter);
             node_id_counter += 1;
 
@@ -238,7 +275,7 @@ impl DataLoader {
         Ok(registry)
     }
 
-    /// Load article by slug (metadata only, content loaded separately)
+    /// Load article by slug or alias (metadata only, content loaded separately)
     pub async fn load_article_by_slug(
         &self,
         slug: &str,
@@ -247,12 +284,24 @@ impl DataLoader {
             &format!("DataLoader: Looking for article with slug: {slug}").into(),
         );
 
+        // 静的プリレンダリングされたページなら埋め込まれた記事を使う
+        if let Some(preloaded) = preloaded_article().filter(|p| p.article.slug == slug) {
+            return Ok(preloaded.article);
+        }
+
         let articles_data = self.load_articles().await?;
 
-        let found_article = articles_data
-            .articles
-            .into_iter()
-            .find(|article| article.slug == slug);
+        // Fall back to aliases so old URLs and alternative names still resolve
+        let mut articles = articles_data.articles;
+        let position = articles
+            .iter()
+            .position(|article| article.slug == slug)
+            .or_else(|| {
+                articles.iter().position(|article| {
+                    article.metadata.aliases.iter().any(|alias| alias == slug)
+                })
+            });
+        let found_article = position.map(|index| articles.swap_remove(index));
 
         match found_article {
             Some(article) => {
@@ -270,6 +319,25 @@ impl DataLoader {
         }
     }
 
+    /// Load the revision history exported for an article
+    pub async fn load_article_history(
+        &self,
+        slug: &str,
+    ) -> Result<ArticleRevisions, DataLoadError> {
+        let url = self.config.data_url(&ArticleRevisions::data_path(slug));
+        web_sys::console::log_1(&format!("DataLoader: Loading history from: {url}").into());
+
+        self.fetch_json::<ArticleRevisions>(&url).await
+    }
+
+    /// Load the full-text search index (generated by CLI)
+    pub async fn load_search_index(&self) -> Result<SearchIndex, DataLoadError> {
+        let url = self.config.data_url("search.json");
+        web_sys::console::log_1(&format!("DataLoader: Loading search index from: {url}").into());
+
+        self.fetch_json::<SearchIndex>(&url).await
+    }
+
     /// Load full article content from file path
     pub async fn load_article_content(&self, file_path: &str) -> Result<String, DataLoadError> {
         let url = self.config.article_url(file_path);
@@ -323,10 +391,14 @@ impl DataLoader {
         &self,
         file_path: &str,
     ) -> Result<String, DataLoadError> {
+        if let Some(preloaded) = preloaded_article().filter(|p| p.article.file_path == file_path) {
+            return Ok(preloaded.content);
+        }
+
         let full_content = self.load_article_content(file_path).await?;
 
-        // Parse and extract only the markdown content (without metadata)
-        let content_only = self.parse_content_only(&full_content);
+        // Drop the YAML, TOML or JSON front matter, as the CLI does
+        let content_only = strip_front_matter(&full_content).to_string();
 
         web_sys::console::log_1(&"DataLoader: Successfully separated content from metadata".into());
         Ok(content_only)
@@ -409,34 +481,6 @@ impl DataLoader {
             y: radius * angle.sin(),
         }
     }
-
-    /// Parse content only (remove front matter)
-    fn parse_content_only(&self, content: &str) -> String {
-        let content = content.trim();
-
-        // Check if content starts with front matter delimiter
-        if content.starts_with("---") {
-            let lines: Vec<&str> = content.lines().collect();
-
-            // Find the closing delimiter
-            let mut end_index = None;
-            for (i, line) in lines.iter().enumerate().skip(1) {
-                if line.trim() == "---" {
-                    end_index = Some(i);
-                    break;
-                }
-            }
-
-            if let Some(end_idx) = end_index {
-                // Return content after the closing delimiter
-                let remaining_lines = &lines[end_idx + 1..];
-                return remaining_lines.join("\n").trim_start().to_string();
-            }
-        }
-
-        // No front matter found, return original content
-        content.to_string()
-    }
 }
 
 impl Default for DataLoader {
@@ -536,6 +580,15 @@ pub fn use_node_registry() -> (
     (data, loading, error)
 }
 
+/// 静的プリレンダリング（`prerender` コマンド）がページに埋め込んだ記事と本文
+fn preloaded_article() -> Option<PreloadedArticle<ProcessedArticle>> {
+    let text = web_sys::window()?
+        .document()?
+        .get_element_by_id(PRELOADED_ARTICLE_ID)?
+        .text_content()?;
+    serde_json::from_str(&text).ok()
+}
+
 /// Hook for loading a specific article by slug (with caching)
 #[hook]
 pub fn use_article_content(
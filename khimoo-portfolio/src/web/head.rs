@@ -0,0 +1,86 @@
+use web_sys::{window, Document};
+use yew::prelude::*;
+
+use crate::config::get_config;
+use crate::core::site::{PageHead, PAGE_HEAD_ATTRIBUTE};
+
+/// ページの <head>（タイトル・説明・canonical・フィード・OGP・JSON-LD）を設定するフック
+///
+/// `None` の間（読み込み中など）は何もしない。アンマウント時や内容が変わるときは
+/// サイト既定の内容に戻すので、head を設定しないページに前のページの情報が残らない。
+#[hook]
+pub fn use_page_head(head: Option<PageHead>) {
+    use_effect_with(head, |head| {
+        if let Some(head) = head {
+            apply_page_head(head);
+        }
+        || apply_page_head(&PageHead::site(&get_config().site))
+    });
+}
+
+fn apply_page_head(head: &PageHead) {
+    let Some(document) = window().and_then(|window| window.document()) else {
+        return;
+    };
+    document.set_title(&head.title);
+
+    while let Ok(Some(element)) = document.query_selector(&format!("[{PAGE_HEAD_ATTRIBUTE}]")) {
+        element.remove();
+    }
+
+    for tag in head.meta_tags() {
+        append_element(
+            &document,
+            "meta",
+            &[(tag.attribute, tag.key), ("content", &tag.content)],
+            None,
+        );
+    }
+    if let Some(url) = &head.canonical_url {
+        append_element(
+            &document,
+            "link",
+            &[("rel", "canonical"), ("href", url)],
+            None,
+        );
+    }
+    for feed in &head.feeds {
+        for (kind, url) in feed.alternates() {
+            append_element(
+                &document,
+                "link",
+                &[
+                    ("rel", "alternate"),
+                    ("type", kind),
+                    ("title", &feed.title),
+                    ("href", url),
+                ],
+                None,
+            );
+        }
+    }
+    if let Some(script) = head.json_ld_script() {
+        append_element(
+            &document,
+            "script",
+            &[("type", "application/ld+json")],
+            Some(&script),
+        );
+    }
+}
+
+fn append_element(document: &Document, tag: &str, attributes: &[(&str, &str)], text: Option<&str>) {
+    let (Ok(Some(parent)), Ok(element)) = (
+        document.query_selector("head"),
+        document.create_element(tag),
+    ) else {
+        return;
+    };
+
+    let _ = element.set_attribute(PAGE_HEAD_ATTRIBUTE, "");
+    for (name, value) in attributes {
+        let _ = element.set_attribute(name, value);
+    }
+    element.set_text_content(text);
+    let _ = parent.append_child(&element);
+}
@@ -30,6 +30,9 @@ pub fn header() -> Html {
                     <Link<Route> to={Route::ArticleIndex}>
                         <span style={nav_link_style.clone()}>{"Blog"}</span>
                     </Link<Route>>
+                    <Link<Route> to={Route::Search}>
+                        <span style={nav_link_style.clone()}>{"Search"}</span>
+                    </Link<Route>>
                     <Link<Route> to={Route::ArticleShow { slug: "about-khimoo".to_string() }}>
                         <span style={nav_link_style}>{"About Me"}</span>
                     </Link<Route>>
@@ -8,6 +8,7 @@ pub mod article_manager;
 pub mod components;
 pub mod config;
 pub mod data_loader;
+pub mod head;
 pub mod header;
 pub mod pages;
 pub mod physics_sim;
@@ -0,0 +1,215 @@
+use crate::core::articles::metadata::display_date;
+use crate::core::articles::revisions::{classify_diff_line, DiffLineKind, Revision};
+use crate::web::components::ArticleStateRenderer;
+use crate::web::data_loader::{use_article_content, DataLoader};
+use crate::web::routes::Route;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ArticleHistoryProps {
+    pub slug: String,
+}
+
+#[function_component(ArticleHistoryPage)]
+pub fn article_history_page(props: &ArticleHistoryProps) -> Html {
+    let (article, loading, error) = use_article_content(Some(props.slug.clone()));
+    let revisions = use_state(|| None::<Vec<Revision>>);
+    let history_error = use_state(|| None::<String>);
+    let navigator = use_navigator();
+
+    // エイリアスで開かれた場合は正規のスラッグの履歴ページへ
+    {
+        let article = article.clone();
+        use_effect_with((article, props.slug.clone()), move |(article, slug)| {
+            if let (Some(article_data), Some(navigator)) = (article.as_ref(), navigator) {
+                if &article_data.slug != slug {
+                    navigator.replace(&Route::ArticleHistory {
+                        slug: article_data.slug.clone(),
+                    });
+                }
+            }
+            || {}
+        });
+    }
+
+    // 履歴 JSON は記事ごとに分かれているので、このページを開いたときだけ読み込む
+    {
+        let revisions = revisions.clone();
+        let history_error = history_error.clone();
+        use_effect_with(props.slug.clone(), move |slug| {
+            let slug = slug.clone();
+            revisions.set(None);
+            history_error.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match DataLoader::new().load_article_history(&slug).await {
+                    Ok(history) => revisions.set(Some(history.revisions)),
+                    Err(e) => history_error.set(Some(format!("{e}"))),
+                }
+            });
+            || {}
+        });
+    }
+
+    if *loading {
+        return ArticleStateRenderer::render_article_loading();
+    }
+
+    if let Some(err) = error.as_ref() {
+        return ArticleStateRenderer::render_article_not_found(&format!("{err}"));
+    }
+
+    let Some(article_data) = article.as_ref() else {
+        return ArticleStateRenderer::render_article_not_found("Article not found");
+    };
+
+    let body = if let Some(revisions) = revisions.as_ref() {
+        render_timeline(revisions)
+    } else if history_error.is_some() {
+        html! { <p class="history-empty">{"この記事の変更履歴はありません。"}</p> }
+    } else {
+        html! { <p class="history-empty">{"Loading history..."}</p> }
+    };
+
+    html! {
+        <>
+            <style>{history_styles()}</style>
+            <div class="history-container">
+                <header class="history-header">
+                    <Link<Route> to={Route::ArticleShow { slug: props.slug.clone() }}>
+                        {"← 記事に戻る"}
+                    </Link<Route>>
+                    <h1>{&article_data.title}{" の変更履歴"}</h1>
+                </header>
+                {body}
+            </div>
+        </>
+    }
+}
+
+/// コミットのタイムライン（新しい順）
+fn render_timeline(revisions: &[Revision]) -> Html {
+    if revisions.is_empty() {
+        return html! { <p class="history-empty">{"この記事の変更履歴はありません。"}</p> };
+    }
+
+    html! {
+        <ol class="history-timeline">
+            {revisions.iter().map(render_revision).collect::<Html>()}
+        </ol>
+    }
+}
+
+fn render_revision(revision: &Revision) -> Html {
+    let date = display_date(&revision.date).unwrap_or_else(|| revision.date.clone());
+    let message = if revision.message.is_empty() {
+        "(no message)".to_string()
+    } else {
+        revision.message.clone()
+    };
+
+    html! {
+        <li key={revision.hash.clone()} class="history-entry">
+            <div class="history-entry-meta">
+                <time datetime={revision.date.clone()}>{date}</time>
+                <code title={revision.hash.clone()}>{&revision.short_hash}</code>
+            </div>
+            <details>
+                <summary>{message}</summary>
+                {render_diff(revision)}
+            </details>
+        </li>
+    }
+}
+
+/// unified diff を行ごとに色分けして表示
+fn render_diff(revision: &Revision) -> Html {
+    if revision.diff.is_empty() {
+        return html! { <p class="history-empty">{"差分はありません。"}</p> };
+    }
+
+    html! {
+        <>
+            <pre class="history-diff">
+                {revision.diff.lines().map(|line| {
+                    let class = match classify_diff_line(line) {
+                        DiffLineKind::Header => "diff-header",
+                        DiffLineKind::Hunk => "diff-hunk",
+                        DiffLineKind::Added => "diff-added",
+                        DiffLineKind::Removed => "diff-removed",
+                        DiffLineKind::Context => "diff-context",
+                    };
+                    html! { <span class={class}>{line}</span> }
+                }).collect::<Html>()}
+            </pre>
+            if revision.diff_truncated {
+                <p class="history-empty">{"差分が大きいため途中までを表示しています。"}</p>
+            }
+        </>
+    }
+}
+
+fn history_styles() -> &'static str {
+    r#"
+    .history-container {
+        padding: 16px;
+        max-width: 800px;
+        margin: 0 auto;
+        background: #081D35;
+        color: #e0e0e0;
+        min-height: 100vh;
+    }
+    .history-header a {
+        color: #66b3ff;
+        text-decoration: none;
+        font-size: 14px;
+    }
+    .history-header h1 {
+        margin: 12px 0 24px 0;
+        font-size: 1.8em;
+    }
+    .history-timeline {
+        list-style: none;
+        padding-left: 16px;
+        border-left: 2px solid #444;
+    }
+    .history-entry {
+        margin-bottom: 20px;
+    }
+    .history-entry-meta {
+        display: flex;
+        gap: 12px;
+        font-size: 14px;
+        color: #aaa;
+    }
+    .history-entry-meta code {
+        color: #66b3ff;
+    }
+    .history-entry summary {
+        cursor: pointer;
+        margin-top: 4px;
+    }
+    .history-diff {
+        background: #2d3748;
+        padding: 12px 0;
+        border-radius: 6px;
+        overflow-x: auto;
+        font-size: 13px;
+        line-height: 1.5;
+    }
+    .history-diff span {
+        display: block;
+        padding: 0 12px;
+        white-space: pre;
+        min-height: 1.5em;
+    }
+    .history-diff .diff-header { color: #888; }
+    .history-diff .diff-hunk { color: #66b3ff; }
+    .history-diff .diff-added { background: rgba(46, 160, 67, 0.2); color: #aff5b4; }
+    .history-diff .diff-removed { background: rgba(248, 81, 73, 0.2); color: #ffdcd7; }
+    .history-empty {
+        color: #aaa;
+    }
+    "#
+}
@@ -1,14 +1,73 @@
-use crate::web::components::{ArticleStateRenderer, TagPill, TagStyles};
+use crate::config::get_config;
+use crate::core::articles::metadata::display_date;
+use crate::core::articles::tags::{build_tag_tree, tag_matches, TagNode};
+use crate::core::site::PageHead;
+use crate::web::components::{format_length, ArticleStateRenderer, TagPill, TagStyles};
 use crate::web::data_loader::{use_articles_data, LightweightArticle};
+use crate::web::head::use_page_head;
 use crate::web::routes::{Route, TagQuery};
+use chrono::DateTime;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+/// 記事一覧の並び順
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+enum SortOrder {
+    #[default]
+    Newest,
+    ShortestRead,
+    LongestRead,
+    MostText,
+}
+
+impl SortOrder {
+    const ALL: [SortOrder; 4] = [
+        SortOrder::Newest,
+        SortOrder::ShortestRead,
+        SortOrder::LongestRead,
+        SortOrder::MostText,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            SortOrder::Newest => "新しい順",
+            SortOrder::ShortestRead => "すぐ読める順",
+            SortOrder::LongestRead => "じっくり読む順",
+            SortOrder::MostText => "分量の多い順",
+        }
+    }
+
+    fn sort(self, articles: &mut [LightweightArticle]) {
+        match self {
+            // 日付のない記事は最後に
+            SortOrder::Newest => articles.sort_by_key(|article| {
+                std::cmp::Reverse(
+                    article
+                        .metadata
+                        .created_at
+                        .as_deref()
+                        .and_then(|value| DateTime::parse_from_rfc3339(value).ok()),
+                )
+            }),
+            SortOrder::ShortestRead => articles
+                .sort_by_key(|article| (article.stats.reading_minutes, article.stats.length())),
+            SortOrder::LongestRead => articles.sort_by_key(|article| {
+                std::cmp::Reverse((article.stats.reading_minutes, article.stats.length()))
+            }),
+            SortOrder::MostText => {
+                articles.sort_by_key(|article| std::cmp::Reverse(article.stats.length()))
+            }
+        }
+    }
+}
+
 #[function_component(ArticleIndexPage)]
 pub fn article_index_page() -> Html {
     let (articles_data, loading, error) = use_articles_data();
     let selected_tags = use_state(Vec::<String>::new);
+    let sort_order = use_state(SortOrder::default);
     let location = use_location();
+    use_page_head(Some(PageHead::article_index(&get_config().site)));
 
     {
         let selected_tags = selected_tags.clone();
@@ -39,13 +98,19 @@ pub fn article_index_page() -> Html {
             .iter()
             .cloned()
             .map(LightweightArticle::from)
-            .filter(|article| article.metadata.hub_tag.is_none())
+            .filter(|article| article.metadata.is_public())
             .collect::<Vec<_>>()
     });
 
-    let all_tags = articles
+    let tag_tree = articles
         .as_ref()
-        .map(|articles_list| collect_all_tags(articles_list))
+        .map(|articles_list| {
+            build_tag_tree(
+                articles_list
+                    .iter()
+                    .map(|article| article.metadata.tags.as_slice()),
+            )
+        })
         .unwrap_or_default();
 
     let on_toggle_tag = {
@@ -62,7 +127,9 @@ pub fn article_index_page() -> Html {
     };
 
     let filtered_articles = articles.as_ref().map(|articles_list| {
-        filter_articles_by_tags(articles_list, &selected_tags)
+        let mut filtered = filter_articles_by_tags(articles_list, &selected_tags);
+        sort_order.sort(&mut filtered);
+        filtered
     });
 
     html! {
@@ -72,27 +139,15 @@ pub fn article_index_page() -> Html {
             <div class="article-index-container">
                 <h1>{"記事一覧"}</h1>
 
-                {render_tag_filters(&all_tags, &selected_tags, &on_toggle_tag)}
+                {render_tag_filters(&tag_tree, &selected_tags, &on_toggle_tag)}
+                {render_tag_page_link(&selected_tags)}
+                {render_sort_options(&sort_order)}
                 {render_articles_list(&filtered_articles)}
             </div>
         </>
     }
 }
 
-fn collect_all_tags(articles_list: &[LightweightArticle]) -> Vec<String> {
-    let mut tags: Vec<String> = Vec::new();
-
-    for article in articles_list {
-        for tag in &article.metadata.tags {
-            if !tags.contains(tag) {
-                tags.push(tag.clone());
-            }
-        }
-    }
-
-    tags
-}
-
 fn filter_articles_by_tags(
     articles_list: &[LightweightArticle],
     selected_tags: &UseStateHandle<Vec<String>>,
@@ -101,41 +156,95 @@ fn filter_articles_by_tags(
         return articles_list.to_vec();
     }
 
+    // 親タグを選ぶと子タグ（math → math/topology）の記事も含める
     articles_list
         .iter().filter(|&article| {
-            article
-                .metadata
-                .tags
-                .iter()
-                .any(|tag| selected_tags.contains(tag))
+            article.metadata.tags.iter().any(|tag| {
+                selected_tags
+                    .iter()
+                    .any(|selected| tag_matches(tag, selected))
+            })
         }).cloned()
         .collect::<Vec<_>>()
 }
 
 fn render_tag_filters(
-    all_tags: &[String],
+    tag_tree: &[TagNode],
     selected_tags: &UseStateHandle<Vec<String>>,
     on_toggle_tag: &Callback<String>,
 ) -> Html {
-    if all_tags.is_empty() {
+    if tag_tree.is_empty() {
         return html! {};
     }
 
     html! {
         <div class="tag-filter">
             <span class="tag-filter-label">{"タグで絞り込み:"}</span>
-            <div class="tag-filter-options">
-                {all_tags.iter().map(|tag| {
-                    let is_selected = selected_tags.contains(tag);
-                    html! {
-                        <TagPill label={tag.clone()} selected={is_selected} on_click={Some(on_toggle_tag.clone())} />
-                    }
-                }).collect::<Html>()}
-            </div>
+            {render_tag_tree(tag_tree, selected_tags, on_toggle_tag)}
+        </div>
+    }
+}
+
+/// タグを一つだけ選んでいるときは、そのタグのページ（説明つき）へのリンクを出す
+fn render_tag_page_link(selected_tags: &UseStateHandle<Vec<String>>) -> Html {
+    let [tag] = selected_tags.as_slice() else {
+        return html! {};
+    };
+
+    html! {
+        <p class="tag-page-link">
+            <Link<Route> to={Route::TagShow { name: tag.clone() }}>
+                {format!("#{tag} のページへ →")}
+            </Link<Route>>
+        </p>
+    }
+}
+
+fn render_sort_options(sort_order: &UseStateHandle<SortOrder>) -> Html {
+    html! {
+        <div class="sort-options">
+            <span class="tag-filter-label">{"並び順:"}</span>
+            {SortOrder::ALL.iter().map(|&order| {
+                let sort_order = sort_order.clone();
+                let class = if *sort_order == order { "sort-option selected" } else { "sort-option" };
+                html! {
+                    <button {class} onclick={Callback::from(move |_| sort_order.set(order))}>
+                        {order.label()}
+                    </button>
+                }
+            }).collect::<Html>()}
         </div>
     }
 }
 
+/// 階層タグをツリー状に表示（子タグは親の下にインデント）
+fn render_tag_tree(
+    nodes: &[TagNode],
+    selected_tags: &UseStateHandle<Vec<String>>,
+    on_toggle_tag: &Callback<String>,
+) -> Html {
+    html! {
+        <ul class="tag-tree">
+            {nodes.iter().map(|node| {
+                let is_selected = selected_tags.contains(&node.path);
+                html! {
+                    <li key={node.path.clone()}>
+                        <TagPill
+                            label={format!("{} ({})", node.name, node.count)}
+                            value={Some(node.path.clone())}
+                            selected={is_selected}
+                            on_click={Some(on_toggle_tag.clone())}
+                        />
+                        if !node.children.is_empty() {
+                            {render_tag_tree(&node.children, selected_tags, on_toggle_tag)}
+                        }
+                    </li>
+                }
+            }).collect::<Html>()}
+        </ul>
+    }
+}
+
 fn render_articles_list(articles: &Option<Vec<LightweightArticle>>) -> Html {
     if let Some(articles_list) = articles.as_ref() {
         html! {
@@ -155,6 +264,7 @@ fn render_articles_list(articles: &Option<Vec<LightweightArticle>>) -> Html {
 fn render_article_item(article: &LightweightArticle) -> Html {
     html! {
         <li key={article.slug.clone()} class="article-item">
+            {render_article_cover(article)}
             <h3 class="article-title">
                 <Link<Route> to={Route::ArticleShow { slug: article.slug.clone() }}>
                     {&article.title}
@@ -166,6 +276,25 @@ fn render_article_item(article: &LightweightArticle) -> Html {
     }
 }
 
+fn render_article_cover(article: &LightweightArticle) -> Html {
+    let Some(cover) = &article.cover else {
+        return html! {};
+    };
+
+    html! {
+        <Link<Route> to={Route::ArticleShow { slug: article.slug.clone() }}>
+            <img
+                class="article-cover"
+                src={get_config().get_url(&cover.card)}
+                alt=""
+                loading="lazy"
+                width="600"
+                height="315"
+            />
+        </Link<Route>>
+    }
+}
+
 fn render_article_summary(article: &LightweightArticle) -> Html {
     if let Some(summary) = &article.summary {
         html! {
@@ -177,8 +306,21 @@ fn render_article_summary(article: &LightweightArticle) -> Html {
 }
 
 fn render_article_meta(article: &LightweightArticle) -> Html {
+    let created_at = article.metadata.created_at.as_deref();
+    let date = created_at.and_then(display_date);
+
     html! {
         <div class="article-meta">
+            {match (created_at, date) {
+                (Some(datetime), Some(date)) => html! {
+                    <time datetime={datetime.to_string()}>{date}</time>
+                },
+                _ => html! {},
+            }}
+            if article.stats.reading_minutes > 0 {
+                <span>{format!("{} min", article.stats.reading_minutes)}</span>
+                <span>{format_length(&article.stats)}</span>
+            }
             <span>{"Links: "}{article.inbound_links.len()}</span>
         </div>
     }
@@ -237,6 +379,16 @@ fn index_styles() -> &'static str {
         background: var(--bg-color);
     }
 
+    .article-cover {
+        display: block;
+        width: 100%;
+        height: auto;
+        aspect-ratio: 600 / 315;
+        object-fit: cover;
+        border-radius: 6px;
+        margin-bottom: 12px;
+    }
+
     .article-title {
         margin: 0 0 12px 0;
         font-size: 1.2em;
@@ -252,6 +404,8 @@ fn index_styles() -> &'static str {
     .article-meta {
         font-size: 12px;
         color: var(--meta-color);
+        display: flex;
+        gap: 12px;
     }
 
     .tag-filter {
@@ -266,10 +420,44 @@ fn index_styles() -> &'static str {
         color: var(--meta-color);
     }
 
-    .tag-filter-options {
+    .tag-tree {
+        list-style: none;
+        margin: 0;
+        padding: 0;
         display: flex;
         flex-wrap: wrap;
         gap: 8px;
+        align-items: flex-start;
+    }
+
+    .sort-options {
+        margin: 0 0 20px 0;
+        display: flex;
+        flex-wrap: wrap;
+        align-items: center;
+        gap: 8px;
+    }
+
+    .sort-option {
+        font-size: 12px;
+        padding: 4px 10px;
+        border-radius: 12px;
+        border: 1px solid var(--border-color);
+        background: transparent;
+        color: var(--text-color);
+        cursor: pointer;
+    }
+
+    .sort-option.selected {
+        border-color: var(--link-color);
+        color: var(--link-color);
+    }
+
+    .tag-tree .tag-tree {
+        margin-top: 6px;
+        padding-left: 12px;
+        border-left: 1px solid var(--border-color);
+        flex-direction: column;
     }
 
     "#
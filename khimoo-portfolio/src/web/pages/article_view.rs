@@ -1,6 +1,13 @@
-use crate::web::components::{ArticleContent, ArticleHeader, ArticleStateRenderer};
-use crate::web::data_loader::{use_article_content, DataLoader};
-use crate::web::routes::{Route, TagQuery};
+use crate::config::get_config;
+use crate::core::articles::sections::{is_section_index, section_path_of};
+use crate::core::site::PageHead;
+use crate::web::components::{
+    ArticleContent, ArticleHeader, ArticleStateRenderer, Breadcrumbs, SectionIndex,
+};
+use crate::web::data_loader::{use_article_content, DataLoadError, DataLoader, ProcessedArticle};
+use crate::web::head::use_page_head;
+use crate::web::pages::SectionViewPage;
+use crate::web::routes::{history_slug, Route};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -17,8 +24,42 @@ pub fn article_view_page(props: &ArticleViewProps) -> Html {
     let content_loading = use_state(|| false);
     let content_error = use_state(|| None::<String>);
     let navigator = use_navigator().expect("Navigator not found");
+    use_page_head(article.as_ref().map(article_head));
 
-    // Tags-Hub機能: hub_tagが設定されている記事の場合、タグ一覧ページへリダイレクト
+    // エイリアス（旧URL）でアクセスされた場合は正規のURLへリダイレクト
+    {
+        let article = article.clone();
+        let navigator = navigator.clone();
+        use_effect_with((article, props.slug.clone()), move |(article, slug)| {
+            if let Some(article_data) = article.as_ref() {
+                if &article_data.slug != slug {
+                    navigator.replace(&Route::ArticleShow {
+                        slug: article_data.slug.clone(),
+                    });
+                }
+            }
+            || {}
+        });
+    }
+
+    // `/article/{slug}/history` は、その名前の記事がなければ履歴ページへ振り替える
+    {
+        let navigator = navigator.clone();
+        use_effect_with(
+            ((*error).clone(), props.slug.clone()),
+            move |(error, slug)| {
+                if let (Some(DataLoadError::NotFound(_)), Some(slug)) = (error, history_slug(slug))
+                {
+                    navigator.replace(&Route::ArticleHistory {
+                        slug: slug.to_string(),
+                    });
+                }
+                || {}
+            },
+        );
+    }
+
+    // Tags-Hub機能: hub_tagが設定されている記事の場合、そのタグのページへリダイレクト
     {
         let article = article.clone();
         let navigator = navigator.clone();
@@ -27,13 +68,12 @@ pub fn article_view_page(props: &ArticleViewProps) -> Html {
                 if let Some(hub_tag) = &article_data.metadata.hub_tag {
                     #[cfg(target_arch = "wasm32")]
                     web_sys::console::log_1(
-                        &format!("Redirecting tags-hub article to tag index: {hub_tag}").into(),
+                        &format!("Redirecting tags-hub article to tag page: {hub_tag}").into(),
                     );
-                    
-                    let query = TagQuery {
-                        tags: Some(hub_tag.clone()),
-                    };
-                    let _ = navigator.replace_with_query(&Route::ArticleIndex, &query);
+
+                    navigator.replace(&Route::TagShow {
+                        name: hub_tag.clone(),
+                    });
                 }
             }
             || {}
@@ -82,6 +122,14 @@ pub fn article_view_page(props: &ArticleViewProps) -> Html {
     }
 
     if let Some(err) = error.as_ref() {
+        // 記事がなければ index.md のないセクションとして表示を試みる
+        if matches!(err, DataLoadError::NotFound(_)) {
+            // 履歴ページへの振り替え待ち
+            if history_slug(&props.slug).is_some() {
+                return ArticleStateRenderer::render_article_loading();
+            }
+            return html! { <SectionViewPage path={props.slug.clone()} /> };
+        }
         return ArticleStateRenderer::render_article_not_found(&format!("{err}"));
     }
 
@@ -103,11 +151,16 @@ pub fn article_view_page(props: &ArticleViewProps) -> Html {
                     <style>{article_styles()}</style>
                     <div class="article-container">
                         <article>
+                            <Breadcrumbs
+                                path={article_data.file_path.clone()}
+                                current_title={Some(article_data.title.clone())}
+                            />
                             <ArticleHeader article={article_data.clone()} />
                             <ArticleContent
                                 article={article_data.clone()}
                                 content={raw_content.clone()}
                             />
+                            {render_section_index(&article_data.file_path)}
                         </article>
                     </div>
                 </>
@@ -121,6 +174,30 @@ pub fn article_view_page(props: &ArticleViewProps) -> Html {
     ArticleStateRenderer::render_article_not_found("Article not found")
 }
 
+/// セクションの index.md なら、本文の後にセクション内の一覧を表示
+fn render_section_index(file_path: &str) -> Html {
+    match section_path_of(file_path) {
+        Some(path) if is_section_index(file_path) => html! { <SectionIndex path={path.to_string()} /> },
+        _ => html! {},
+    }
+}
+
+/// 記事ページの <head>（OGP 画像はカバー画像か生成した画像）
+fn article_head(article: &ProcessedArticle) -> PageHead {
+    let path = Route::ArticleShow {
+        slug: article.slug.clone(),
+    }
+    .to_path();
+    PageHead::article(
+        &get_config().site,
+        &path,
+        &article.title,
+        &article.metadata,
+        article.summary.as_deref(),
+        article.og_image.as_deref(),
+    )
+}
+
 fn article_styles() -> &'static str {
     r#"
     :root {
@@ -3,11 +3,19 @@
 //! This module contains page-level components for different routes
 //! in the web application.
 
+pub mod article_history;
 pub mod article_index;
 pub mod article_view;
 pub mod home;
+pub mod search;
+pub mod section_view;
+pub mod tag_view;
 
 // Re-export page components
+pub use article_history::ArticleHistoryPage;
 pub use article_index::ArticleIndexPage;
 pub use article_view::ArticleViewPage;
 pub use home::HomePage;
+pub use search::SearchPage;
+pub use section_view::SectionViewPage;
+pub use tag_view::TagViewPage;
@@ -0,0 +1,245 @@
+use std::rc::Rc;
+
+use crate::config::get_config;
+use crate::core::search::{SearchHit, SearchIndex, SnippetPart};
+use crate::core::site::PageHead;
+use crate::web::components::{TagPill, TagStyles};
+use crate::web::data_loader::DataLoader;
+use crate::web::head::use_page_head;
+use crate::web::routes::{Route, SearchQuery};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// 一度に表示する検索結果の数
+const RESULT_LIMIT: usize = 30;
+
+#[function_component(SearchPage)]
+pub fn search_page() -> Html {
+    let index = use_state(|| None::<Rc<SearchIndex>>);
+    let index_error = use_state(|| None::<String>);
+    let location = use_location();
+    let navigator = use_navigator();
+    use_page_head(Some(PageHead::page(
+        &get_config().site,
+        "/search",
+        "検索",
+        None,
+    )));
+
+    // 検索インデックスは記事データとは別ファイルなので、このページを開いたときだけ読み込む
+    {
+        let index = index.clone();
+        let index_error = index_error.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match DataLoader::new().load_search_index().await {
+                    Ok(loaded) => index.set(Some(Rc::new(loaded))),
+                    Err(e) => index_error.set(Some(format!("{e}"))),
+                }
+            });
+            || {}
+        });
+    }
+
+    // 検索語は URL の `?q=` に持たせ、結果ページをそのまま共有できるようにする
+    let query = location
+        .as_ref()
+        .and_then(|location| location.query::<SearchQuery>().ok())
+        .map(|query| query.q)
+        .unwrap_or_default();
+
+    let on_input = {
+        let navigator = navigator.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Some(navigator) = navigator.as_ref() {
+                let query = SearchQuery { q: input.value() };
+                let _ = navigator.replace_with_query(&Route::Search, &query);
+            }
+        })
+    };
+
+    let on_tag_click = Callback::from(move |tag: String| {
+        if let Some(navigator) = navigator.as_ref() {
+            navigator.push(&Route::TagShow { name: tag });
+        }
+    });
+
+    let results = if let Some(err) = index_error.as_ref() {
+        html! {
+            <p class="search-status">{format!("検索インデックスを読み込めませんでした: {err}")}</p>
+        }
+    } else if let Some(index) = index.as_ref() {
+        render_results(index, &query, &on_tag_click)
+    } else {
+        html! { <p class="search-status">{"Loading search index..."}</p> }
+    };
+
+    html! {
+        <>
+            <style>{search_styles()}</style>
+            <TagStyles />
+            <div class="search-container">
+                <h1>{"検索"}</h1>
+                <input
+                    class="search-input"
+                    type="search"
+                    placeholder="タイトル・タグ・本文から検索"
+                    value={query.clone()}
+                    oninput={on_input}
+                    autofocus={true}
+                />
+                {results}
+            </div>
+        </>
+    }
+}
+
+fn render_results(index: &SearchIndex, query: &str, on_tag_click: &Callback<String>) -> Html {
+    if query.trim().is_empty() {
+        return html! {};
+    }
+
+    let hits = index.search(query, RESULT_LIMIT);
+    if hits.is_empty() {
+        return html! {
+            <p class="search-status">{format!("「{query}」に一致する記事はありません。")}</p>
+        };
+    }
+
+    html! {
+        <>
+            <p class="search-status">{format!("{} 件", hits.len())}</p>
+            <ul class="search-results">
+                {hits.iter().map(|hit| render_hit(hit, on_tag_click)).collect::<Html>()}
+            </ul>
+        </>
+    }
+}
+
+fn render_hit(hit: &SearchHit, on_tag_click: &Callback<String>) -> Html {
+    let document = hit.document;
+    html! {
+        <li key={document.slug.clone()} class="search-result">
+            <h3 class="search-result-title">
+                <Link<Route> to={Route::ArticleShow { slug: document.slug.clone() }}>
+                    {render_parts(&hit.title)}
+                </Link<Route>>
+            </h3>
+            <p class="search-result-snippet">{render_parts(&hit.snippet)}</p>
+            if !document.tags.is_empty() {
+                <div class="search-result-tags">
+                    {document.tags.iter().map(|tag| html! {
+                        <TagPill label={tag.clone()} on_click={Some(on_tag_click.clone())} />
+                    }).collect::<Html>()}
+                </div>
+            }
+        </li>
+    }
+}
+
+/// 一致した部分を `<mark>` で囲む
+fn render_parts(parts: &[SnippetPart]) -> Html {
+    parts
+        .iter()
+        .map(|part| {
+            if part.highlighted {
+                html! { <mark>{&part.text}</mark> }
+            } else {
+                html! { <>{&part.text}</> }
+            }
+        })
+        .collect()
+}
+
+fn search_styles() -> &'static str {
+    r#"
+    :root {
+        --bg-color: #ffffff;
+        --text-color: #333333;
+        --link-color: #007bff;
+        --meta-color: #666;
+        --summary-color: #555;
+        --border-color: #e0e0e0;
+        --mark-color: #fff3a3;
+    }
+
+    @media (prefers-color-scheme: dark) {
+        :root {
+            --bg-color: #081D35;
+            --text-color: #e0e0e0;
+            --link-color: #66b3ff;
+            --meta-color: #aaa;
+            --summary-color: #ccc;
+            --border-color: #333;
+            --mark-color: #5c4f00;
+        }
+    }
+
+    .search-container {
+        padding: 16px;
+        background: var(--bg-color);
+        color: var(--text-color);
+        min-height: 100vh;
+        width: 100%;
+    }
+
+    .search-container a {
+        color: var(--link-color);
+        text-decoration: none;
+    }
+
+    .search-input {
+        width: 100%;
+        box-sizing: border-box;
+        padding: 10px 14px;
+        font-size: 1em;
+        border-radius: 8px;
+        border: 1px solid var(--border-color);
+        background: transparent;
+        color: var(--text-color);
+    }
+
+    .search-status {
+        font-size: 12px;
+        color: var(--meta-color);
+    }
+
+    .search-results {
+        list-style: none;
+        padding: 0;
+    }
+
+    .search-result {
+        margin-bottom: 16px;
+        padding: 16px 20px;
+        border-radius: 8px;
+        border: 1px solid var(--border-color);
+    }
+
+    .search-result-title {
+        margin: 0 0 8px 0;
+        font-size: 1.1em;
+    }
+
+    .search-result-snippet {
+        margin: 0 0 8px 0;
+        color: var(--summary-color);
+        line-height: 1.6;
+        font-size: 0.95em;
+    }
+
+    .search-result mark {
+        background: var(--mark-color);
+        color: inherit;
+        border-radius: 2px;
+    }
+
+    .search-result-tags {
+        display: flex;
+        flex-wrap: wrap;
+        gap: 6px;
+    }
+    "#
+}
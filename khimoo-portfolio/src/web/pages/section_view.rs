@@ -0,0 +1,39 @@
+use crate::web::components::{ArticleStateRenderer, Breadcrumbs, SectionIndex};
+use crate::web::data_loader::use_articles_data;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct SectionViewProps {
+    pub path: String,
+}
+
+/// index.md のないディレクトリ（セクション）のページ
+#[function_component(SectionViewPage)]
+pub fn section_view_page(props: &SectionViewProps) -> Html {
+    let (articles_data, loading, _) = use_articles_data();
+
+    if *loading {
+        return ArticleStateRenderer::render_article_loading();
+    }
+
+    let section = articles_data.as_ref().and_then(|data| {
+        data.sections
+            .iter()
+            .find(|section| section.path == props.path)
+    });
+
+    let Some(section) = section else {
+        return ArticleStateRenderer::render_article_not_found(&format!(
+            "Article not found: {}",
+            props.path
+        ));
+    };
+
+    html! {
+        <div style="padding: 16px; max-width: 800px; margin: 0 auto; min-height: 100vh; color: #e0e0e0;">
+            <Breadcrumbs path={section.path.clone()} />
+            <h1 style="margin: 0 0 16px 0; font-size: 2.5em;">{&section.title}</h1>
+            <SectionIndex path={section.path.clone()} />
+        </div>
+    }
+}
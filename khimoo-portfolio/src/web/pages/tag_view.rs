@@ -0,0 +1,277 @@
+use crate::config::get_config;
+use crate::core::articles::metadata::display_date;
+use crate::core::articles::tags::{
+    build_tag_tree, tag_ancestors, tag_matches, validate_tag, TagNode,
+};
+use crate::core::site::{encode_path, tag_feed_stem, PageHead};
+use crate::web::components::{ArticleStateRenderer, TagPill, TagStyles};
+use crate::web::data_loader::{use_articles_data, ArticlesData, ProcessedArticle};
+use crate::web::head::use_page_head;
+use crate::web::routes::{Route, TagQuery};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct TagViewProps {
+    pub name: String,
+}
+
+/// タグごとのページ（tags.toml の説明と、そのタグ・子タグの記事一覧）
+#[function_component(TagViewPage)]
+pub fn tag_view_page(props: &TagViewProps) -> Html {
+    let (articles_data, loading, _) = use_articles_data();
+    let navigator = use_navigator();
+    use_page_head(
+        articles_data
+            .as_ref()
+            .map(|data| tag_head(data, &props.name)),
+    );
+
+    // 別名や大文字小文字違いの URL は登録済みの表記へリダイレクト
+    {
+        let navigator = navigator.clone();
+        let name = props.name.clone();
+        use_effect_with(
+            (articles_data.clone(), name),
+            move |(articles_data, name)| {
+                let canonical = articles_data
+                    .as_ref()
+                    .and_then(|data| data.tags.normalize(name));
+                if let (Some(canonical), Some(navigator)) = (canonical, navigator) {
+                    if &canonical != name {
+                        navigator.replace(&Route::TagShow { name: canonical });
+                    }
+                }
+                || {}
+            },
+        );
+    }
+
+    if *loading {
+        return ArticleStateRenderer::render_article_loading();
+    }
+
+    let Some(data) = articles_data.as_ref() else {
+        return ArticleStateRenderer::render_article_not_found(&format!(
+            "Tag not found: {}",
+            props.name
+        ));
+    };
+
+    let tag = props.name.as_str();
+    let info = data.tags.get(tag);
+    let articles = tag_articles(data, tag);
+
+    if info.is_none() && articles.is_empty() {
+        return ArticleStateRenderer::render_article_not_found(&format!("Tag not found: {tag}"));
+    }
+
+    // 子タグだけのツリー（このタグ自身の節点の下）
+    let tag_tree = build_tag_tree(
+        articles
+            .iter()
+            .map(|article| article.metadata.tags.as_slice()),
+    );
+    let children = find_node(&tag_tree, tag)
+        .map(|node| node.children.clone())
+        .unwrap_or_default();
+    let color = info.and_then(|info| info.color.clone());
+
+    html! {
+        <>
+            <style>{tag_view_styles()}</style>
+            <TagStyles />
+            <div class="tag-view-container">
+                {render_parent_tags(data, tag)}
+                <h1>
+                    {color.map(|color| html! {
+                        <span class="tag-color" style={format!("background: {color};")}></span>
+                    }).unwrap_or_default()}
+                    {data.tags.display_name(tag)}
+                </h1>
+                <p class="tag-view-meta">
+                    {"#"}{tag}{" · "}{articles.len()}{" articles · "}
+                    <Link<Route, TagQuery>
+                        to={Route::ArticleIndex}
+                        query={Some(TagQuery { tags: Some(tag.to_string()) })}
+                    >
+                        {"記事一覧で絞り込む"}
+                    </Link<Route, TagQuery>>
+                    // タグごとのフィード（記事のあるタグだけ生成される）
+                    {if !articles.is_empty() && validate_tag(tag).is_ok() {
+                        html! {
+                            <>
+                                {" · "}
+                                <a href={get_config().data_url(&encode_path(&format!("{}.xml", tag_feed_stem(tag))))}>
+                                    {"フィード"}
+                                </a>
+                            </>
+                        }
+                    } else {
+                        html! {}
+                    }}
+                </p>
+                {info.and_then(|info| info.description.clone()).map(|description| html! {
+                    <p class="tag-description">{description}</p>
+                }).unwrap_or_default()}
+                {render_child_tags(data, &children)}
+                <ul class="tag-articles">
+                    {articles.iter().map(|article| render_article_item(article)).collect::<Html>()}
+                </ul>
+            </div>
+        </>
+    }
+}
+
+/// タグ（子タグを含む）が付いた一覧表示対象の記事
+fn tag_articles<'a>(data: &'a ArticlesData, tag: &str) -> Vec<&'a ProcessedArticle> {
+    data.articles
+        .iter()
+        .filter(|article| article.metadata.is_public())
+        .filter(|article| article.metadata.tags.iter().any(|t| tag_matches(t, tag)))
+        .collect()
+}
+
+/// タグページの <head>
+fn tag_head(data: &ArticlesData, tag: &str) -> PageHead {
+    PageHead::tag(
+        &get_config().site,
+        tag,
+        data.tags.display_name(tag),
+        data.tags
+            .get(tag)
+            .and_then(|info| info.description.as_deref()),
+        tag_articles(data, tag).len(),
+    )
+}
+
+fn find_node<'a>(nodes: &'a [TagNode], path: &str) -> Option<&'a TagNode> {
+    nodes.iter().find_map(|node| {
+        if node.path == path {
+            Some(node)
+        } else if tag_matches(path, &node.path) {
+            find_node(&node.children, path)
+        } else {
+            None
+        }
+    })
+}
+
+/// 親タグへのリンク（math/topology なら math）
+fn render_parent_tags(data: &ArticlesData, tag: &str) -> Html {
+    let parents: Vec<&str> = tag_ancestors(tag)
+        .into_iter()
+        .filter(|parent| *parent != tag)
+        .collect();
+    if parents.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <nav class="tag-parents">
+            {parents.into_iter().map(|parent| html! {
+                <>
+                    <Link<Route> to={Route::TagShow { name: parent.to_string() }}>
+                        {data.tags.display_name(parent)}
+                    </Link<Route>>
+                    <span>{" / "}</span>
+                </>
+            }).collect::<Html>()}
+        </nav>
+    }
+}
+
+fn render_child_tags(data: &ArticlesData, children: &[TagNode]) -> Html {
+    if children.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="tag-list">
+            <span class="tag-list-label">{"子タグ: "}</span>
+            {children.iter().map(|child| html! {
+                <Link<Route> to={Route::TagShow { name: child.path.clone() }}>
+                    <TagPill
+                        label={format!("{} ({})", data.tags.display_name(&child.path), child.count)}
+                        color={data.tags.get(&child.path).and_then(|info| info.color.clone())}
+                    />
+                </Link<Route>>
+            }).collect::<Html>()}
+        </div>
+    }
+}
+
+fn render_article_item(article: &ProcessedArticle) -> Html {
+    let created_at = article.metadata.created_at.as_deref();
+
+    html! {
+        <li key={article.slug.clone()}>
+            <Link<Route> to={Route::ArticleShow { slug: article.slug.clone() }}>
+                {&article.title}
+            </Link<Route>>
+            {match (created_at, created_at.and_then(display_date)) {
+                (Some(datetime), Some(date)) => html! {
+                    <time datetime={datetime.to_string()}>{date}</time>
+                },
+                _ => html! {},
+            }}
+            {article.summary.as_ref().map(|summary| html! {
+                <p>{summary}</p>
+            }).unwrap_or_default()}
+        </li>
+    }
+}
+
+fn tag_view_styles() -> &'static str {
+    r#"
+    .tag-view-container {
+        padding: 16px;
+        max-width: 800px;
+        margin: 0 auto;
+        min-height: 100vh;
+        color: #e0e0e0;
+    }
+    .tag-view-container a {
+        color: #66b3ff;
+        text-decoration: none;
+    }
+    .tag-view-container a:hover {
+        text-decoration: underline;
+    }
+    .tag-parents, .tag-view-meta {
+        font-size: 14px;
+        color: #aaa;
+    }
+    .tag-color {
+        display: inline-block;
+        width: 0.6em;
+        height: 0.6em;
+        margin-right: 12px;
+        border-radius: 50%;
+        vertical-align: middle;
+    }
+    .tag-description {
+        font-size: 1.1em;
+        line-height: 1.7;
+    }
+    .tag-articles {
+        list-style: none;
+        padding: 0;
+        margin-top: 32px;
+    }
+    .tag-articles li {
+        margin-bottom: 20px;
+        padding-bottom: 16px;
+        border-bottom: 1px solid #333;
+    }
+    .tag-articles time {
+        margin-left: 12px;
+        font-size: 13px;
+        color: #aaa;
+    }
+    .tag-articles p {
+        margin: 8px 0 0 0;
+        color: #ccc;
+    }
+    "#
+}
@@ -1,12 +1,23 @@
 use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
 use yew_router::prelude::*;
 
+use crate::config::get_config;
+use crate::core::site::{decode_path, redirect_target, REDIRECT_STORAGE_KEY};
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct TagQuery {
     #[serde(default)]
     pub tags: Option<String>,
 }
 
+/// 検索ページの `?q=` クエリ
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct SearchQuery {
+    #[serde(default)]
+    pub q: String,
+}
+
 #[derive(Clone, Routable, PartialEq)]
 pub enum Route {
     #[at("/")]
@@ -15,6 +26,140 @@ pub enum Route {
     Admin,
     #[at("/article")]
     ArticleIndex,
-    #[at("/article/:slug")]
+    /// Nested slugs (`notes/topology`) match as a whole
+    #[at("/article/*slug")]
     ArticleShow { slug: String },
+    /// Kept out of `/article/`, where `notes/topology/history` would read as a nested slug;
+    /// `/article/{slug}/history` still reaches it through [`history_slug`]
+    #[at("/history/*slug")]
+    ArticleHistory { slug: String },
+    /// Nested tags (`math/topology`) match as a whole
+    #[at("/tag/*name")]
+    TagShow { name: String },
+    #[at("/search")]
+    Search,
+}
+
+impl Route {
+    /// Resolve an href built with `AppConfig::get_url` back into a route and its `#fragment`
+    pub fn from_app_href(href: &str) -> Option<(Self, Option<String>)> {
+        let base_path = get_config().base_path.as_str();
+        let path = href.strip_prefix(base_path).unwrap_or(href);
+        let (path, fragment) = match path.split_once('#') {
+            Some((path, fragment)) => (path, Some(fragment.to_string())),
+            None => (path, None),
+        };
+        let path = path.split('?').next().unwrap_or(path);
+        Self::recognize(path).map(|route| (route, fragment.filter(|f| !f.is_empty())))
+    }
+}
+
+/// `/article/{slug}/history` として開かれた記事のスラッグ
+///
+/// 記事 `{slug}/history` が存在しない場合に限り、記事ページがこれを履歴ページへ振り替える。
+pub fn history_slug(slug: &str) -> Option<&str> {
+    slug.strip_suffix("/history")
+        .filter(|slug| !slug.is_empty())
+}
+
+/// 遷移後のURLに `#fragment` を付け、その要素までスクロールする
+///
+/// ルーターはフラグメントを扱わないので、`navigator.push` の後に履歴のURLだけ書き換える。
+pub fn apply_fragment(fragment: &str) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    if let Ok(history) = window.history() {
+        // ルーターが保存した state はそのまま残す
+        let state = history.state().unwrap_or(JsValue::NULL);
+        let _ = history.replace_state_with_url(&state, "", Some(&format!("#{fragment}")));
+    }
+    scroll_to_fragment(fragment);
+}
+
+/// `id` が `fragment`（パーセントエンコード済みでも可）の要素までスクロールする
+///
+/// 要素がまだ描画されていなければ何もしない。
+pub fn scroll_to_fragment(fragment: &str) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let id = decode_path(fragment.trim_start_matches('#'));
+    if let Some(element) = document.get_element_by_id(&id) {
+        element.scroll_into_view();
+    }
+}
+
+/// GitHub Pages の 404.html から転送されてきたとき、元の URL に戻す
+///
+/// ルーターが現在の URL を読む前（アプリの描画前）に呼ぶ。
+pub fn restore_redirected_route() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Ok(Some(storage)) = window.session_storage() else {
+        return;
+    };
+    let Ok(Some(stored)) = storage.get_item(REDIRECT_STORAGE_KEY) else {
+        return;
+    };
+    let _ = storage.remove_item(REDIRECT_STORAGE_KEY);
+
+    if let (Some(url), Ok(history)) = (
+        redirect_target(&stored, &get_config().base_path),
+        window.history(),
+    ) {
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_nested_slug_history_route() {
+        assert!(matches!(
+            Route::recognize("/history/notes/topology"),
+            Some(Route::ArticleHistory { slug }) if slug == "notes/topology"
+        ));
+        assert!(matches!(
+            Route::recognize("/article/notes/topology"),
+            Some(Route::ArticleShow { slug }) if slug == "notes/topology"
+        ));
+        assert_eq!(
+            Route::ArticleHistory {
+                slug: "notes/topology".to_string()
+            }
+            .to_path(),
+            "/history/notes/topology"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_history_slug_from_article_path() {
+        assert!(matches!(
+            Route::recognize("/article/notes/topology/history"),
+            Some(Route::ArticleShow { slug }) if history_slug(&slug) == Some("notes/topology")
+        ));
+        assert_eq!(history_slug("foo/history"), Some("foo"));
+        assert_eq!(history_slug("history"), None);
+        assert_eq!(history_slug("/history"), None);
+        assert_eq!(history_slug("notes/topology"), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_app_href_keeps_fragment() {
+        let href = get_config().get_url("article/notes/topology#コンパクト性");
+        assert!(matches!(
+            Route::from_app_href(&href),
+            Some((Route::ArticleShow { slug }, Some(fragment)))
+                if slug == "notes/topology" && fragment == "コンパクト性"
+        ));
+        assert!(matches!(
+            Route::from_app_href(&get_config().get_url("article/rust?x=1#")),
+            Some((Route::ArticleShow { .. }, None))
+        ));
+    }
 }